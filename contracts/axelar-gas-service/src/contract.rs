@@ -1,15 +1,21 @@
-use soroban_sdk::{contract, contractimpl, token, Address, Bytes, Env, String};
+use soroban_sdk::{contract, contractimpl, token, Address, Bytes, BytesN, Env, Map, String};
 
 use crate::error::ContractError;
-use crate::event;
+use crate::event::{
+    GasAddedEvent, GasCollectedEvent, GasInfoSetEvent, GasPaidEvent, GasRefundedEvent,
+    HubChainSetEvent,
+};
 use crate::interface::AxelarGasServiceInterface;
 use crate::storage_types::DataKey;
-use axelar_soroban_std::ttl::extend_instance_ttl;
+use crate::types::{GasInfo, GasReceiptId};
+use axelar_soroban_std::events::Event;
+use axelar_soroban_std::ttl::{extend_instance_ttl, extend_persistent_ttl};
 use axelar_soroban_std::{ensure, interfaces, types::Token};
-use axelar_soroban_std::{Ownable, Upgradable};
+use axelar_soroban_std::{when_not_paused, Ownable, Pausable, Upgradable};
 
 #[contract]
-#[derive(Ownable, Upgradable)]
+#[derive(Ownable, Upgradable, Pausable)]
+#[upgradable(wasm_registry)]
 pub struct AxelarGasService;
 
 #[contractimpl]
@@ -26,6 +32,84 @@ impl AxelarGasService {
 impl AxelarGasService {
     // Modify this function to add migration logic
     const fn run_migration(_env: &Env, _migration_data: ()) {}
+
+    fn record_gas_paid(
+        env: &Env,
+        receipt_id: GasReceiptId,
+        token: &Token,
+    ) -> Result<(), ContractError> {
+        let key = DataKey::GasReceipt(receipt_id.clone());
+        let mut gas_paid = Self::gas_paid(env, receipt_id);
+
+        let amount = gas_paid
+            .get(token.address.clone())
+            .unwrap_or(0)
+            .checked_add(token.amount)
+            .ok_or(ContractError::InvalidAmount)?;
+        gas_paid.set(token.address.clone(), amount);
+
+        env.storage().persistent().set(&key, &gas_paid);
+        extend_persistent_ttl(env, &key);
+
+        Ok(())
+    }
+
+    fn gas_fee(
+        env: &Env,
+        destination_chain: &String,
+        gas_info: &GasInfo,
+        payload: &Bytes,
+        execute_gas_limit: u64,
+    ) -> Result<i128, ContractError> {
+        let hub_fee = if Self::hub_chain(env).as_ref() == Some(destination_chain) {
+            gas_info.hub_fee
+        } else {
+            0
+        };
+
+        let payload_fee = gas_info
+            .payload_fee_per_byte
+            .checked_mul(payload.len().into())
+            .ok_or(ContractError::InvalidAmount)?;
+        let execute_fee = gas_info
+            .exchange_rate
+            .checked_mul(execute_gas_limit.into())
+            .ok_or(ContractError::InvalidAmount)?;
+
+        gas_info
+            .base_fee
+            .checked_add(payload_fee)
+            .and_then(|fee| fee.checked_add(execute_fee))
+            .and_then(|fee| fee.checked_add(hub_fee))
+            .ok_or(ContractError::InvalidAmount)
+    }
+
+    fn ensure_gas_paid_sufficient(
+        env: &Env,
+        destination_chain: &String,
+        payload: &Bytes,
+        token: &Token,
+    ) -> Result<(), ContractError> {
+        let Some(gas_info) = Self::gas_info(env, destination_chain.clone()) else {
+            return Ok(());
+        };
+
+        if !gas_info.reject_underpayment {
+            return Ok(());
+        }
+
+        ensure!(
+            token.address == gas_info.token_address,
+            ContractError::InvalidGasToken
+        );
+
+        ensure!(
+            token.amount >= Self::gas_fee(env, destination_chain, &gas_info, payload, 0)?,
+            ContractError::InsufficientGasPayment
+        );
+
+        Ok(())
+    }
 }
 
 #[contractimpl]
@@ -40,26 +124,38 @@ impl AxelarGasServiceInterface for AxelarGasService {
         token: Token,
         metadata: Bytes,
     ) -> Result<(), ContractError> {
+        when_not_paused!(&env);
+
         spender.require_auth();
 
         ensure!(token.amount > 0, ContractError::InvalidAmount);
 
+        Self::ensure_gas_paid_sufficient(&env, &destination_chain, &payload, &token)?;
+
         token::Client::new(&env, &token.address).transfer(
             &spender,
             &env.current_contract_address(),
             &token.amount,
         );
 
-        event::gas_paid(
+        let payload_hash: BytesN<32> = env.crypto().keccak256(&payload).into();
+
+        Self::record_gas_paid(
             &env,
+            GasReceiptId::Payload(sender.clone(), payload_hash.clone()),
+            &token,
+        )?;
+
+        GasPaidEvent {
             sender,
             destination_chain,
             destination_address,
-            payload,
+            payload_hash,
             spender,
             token,
             metadata,
-        );
+        }
+        .emit(&env);
 
         Ok(())
     }
@@ -81,7 +177,15 @@ impl AxelarGasServiceInterface for AxelarGasService {
             &token.amount,
         );
 
-        event::gas_added(&env, sender, message_id, spender, token);
+        Self::record_gas_paid(&env, GasReceiptId::MessageId(message_id.clone()), &token)?;
+
+        GasAddedEvent {
+            sender,
+            message_id,
+            spender,
+            token,
+        }
+        .emit(&env);
 
         Ok(())
     }
@@ -102,23 +206,66 @@ impl AxelarGasServiceInterface for AxelarGasService {
         );
         token_client.transfer(&env.current_contract_address(), &receiver, &token.amount);
 
-        event::fee_collected(&env, gas_collector, token);
+        GasCollectedEvent {
+            receiver: gas_collector,
+            token,
+        }
+        .emit(&env);
 
         extend_instance_ttl(&env);
 
         Ok(())
     }
 
-    fn refund(env: Env, message_id: String, receiver: Address, token: Token) {
+    fn refund(
+        env: Env,
+        receipt_id: GasReceiptId,
+        receiver: Address,
+        token: Token,
+    ) -> Result<(), ContractError> {
         Self::gas_collector(&env).require_auth();
 
+        ensure!(token.amount > 0, ContractError::InvalidAmount);
+
+        let key = DataKey::GasReceipt(receipt_id.clone());
+        let mut gas_paid = Self::gas_paid(&env, receipt_id.clone());
+        let remaining = gas_paid.get(token.address.clone()).unwrap_or(0) - token.amount;
+
+        ensure!(remaining >= 0, ContractError::RefundExceedsGasPaid);
+
+        if remaining > 0 {
+            gas_paid.set(token.address.clone(), remaining);
+        } else {
+            gas_paid.remove(token.address.clone());
+        }
+
+        if gas_paid.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &gas_paid);
+        }
+
         token::Client::new(&env, &token.address).transfer(
             &env.current_contract_address(),
             &receiver,
             &token.amount,
         );
 
-        event::refunded(&env, message_id, receiver, token);
+        GasRefundedEvent {
+            receipt_id,
+            receiver,
+            token,
+        }
+        .emit(&env);
+
+        Ok(())
+    }
+
+    fn gas_paid(env: &Env, receipt_id: GasReceiptId) -> Map<Address, i128> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::GasReceipt(receipt_id))
+            .unwrap_or_else(|| Map::new(env))
     }
 
     fn gas_collector(env: &Env) -> Address {
@@ -127,4 +274,91 @@ impl AxelarGasServiceInterface for AxelarGasService {
             .get(&DataKey::GasCollector)
             .expect("gas collector not found")
     }
+
+    fn set_gas_info(
+        env: Env,
+        destination_chain: String,
+        gas_info: Option<GasInfo>,
+    ) -> Result<(), ContractError> {
+        Self::gas_collector(&env).require_auth();
+
+        let key = DataKey::ChainGasInfo(destination_chain.clone());
+
+        if let Some(gas_info) = &gas_info {
+            ensure!(
+                gas_info.base_fee >= 0
+                    && gas_info.payload_fee_per_byte >= 0
+                    && gas_info.exchange_rate >= 0
+                    && gas_info.hub_fee >= 0,
+                ContractError::InvalidGasInfo
+            );
+
+            env.storage().persistent().set(&key, gas_info);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        GasInfoSetEvent {
+            destination_chain,
+            gas_info,
+        }
+        .emit(&env);
+
+        Ok(())
+    }
+
+    fn gas_info(env: &Env, destination_chain: String) -> Option<GasInfo> {
+        let key = DataKey::ChainGasInfo(destination_chain);
+        let gas_info = env.storage().persistent().get(&key);
+
+        if gas_info.is_some() {
+            extend_persistent_ttl(env, &key);
+        }
+
+        gas_info
+    }
+
+    fn hub_chain(env: &Env) -> Option<String> {
+        env.storage().instance().get(&DataKey::HubChain)
+    }
+
+    fn set_hub_chain(env: Env, hub_chain: Option<String>) -> Result<(), ContractError> {
+        Self::gas_collector(&env).require_auth();
+
+        if let Some(hub_chain) = &hub_chain {
+            env.storage().instance().set(&DataKey::HubChain, hub_chain);
+        } else {
+            env.storage().instance().remove(&DataKey::HubChain);
+        }
+
+        HubChainSetEvent { hub_chain }.emit(&env);
+
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    // the destination address doesn't affect the fee yet, but is part of the interface so that estimates can depend on it
+    #[allow(unused_variables)]
+    fn estimate_gas_fee(
+        env: &Env,
+        destination_chain: String,
+        destination_address: String,
+        payload: Bytes,
+        execute_gas_limit: u64,
+    ) -> Result<Token, ContractError> {
+        let gas_info =
+            Self::gas_info(env, destination_chain.clone()).ok_or(ContractError::GasInfoNotFound)?;
+
+        Ok(Token {
+            amount: Self::gas_fee(
+                env,
+                &destination_chain,
+                &gas_info,
+                &payload,
+                execute_gas_limit,
+            )?,
+            address: gas_info.token_address,
+        })
+    }
 }
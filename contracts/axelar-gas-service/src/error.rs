@@ -8,4 +8,11 @@ pub enum ContractError {
     InvalidAddress = 2,
     InvalidAmount = 3,
     InsufficientBalance = 4,
+    ContractPaused = 5,
+    RefundExceedsGasPaid = 6,
+    InvalidGasInfo = 7,
+    GasInfoNotFound = 8,
+    InvalidGasToken = 9,
+    InsufficientGasPayment = 10,
+    UnapprovedWasmHash = 11,
 }
@@ -1,50 +1,67 @@
 use axelar_soroban_std::types::Token;
-use soroban_sdk::{Address, Bytes, Env, String, Symbol};
+use axelar_soroban_std::IntoEvent;
+use soroban_sdk::{Address, Bytes, BytesN, String};
 
-pub fn gas_paid(
-    env: &Env,
-    sender: Address,
-    destination_chain: String,
-    destination_address: String,
-    payload: Bytes,
-    spender: Address,
-    token: Token,
-    metadata: Bytes,
-) {
-    let topics = (
-        Symbol::new(env, "gas_paid"),
-        sender,
-        destination_chain,
-        destination_address,
-        env.crypto().keccak256(&payload),
-        spender,
-        token,
-    );
-    env.events().publish(topics, (metadata,));
+use crate::types::{GasInfo, GasReceiptId};
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct GasPaidEvent {
+    #[topic]
+    pub sender: Address,
+    #[topic]
+    pub destination_chain: String,
+    #[topic]
+    pub destination_address: String,
+    #[topic]
+    pub payload_hash: BytesN<32>,
+    #[topic]
+    pub spender: Address,
+    #[topic]
+    pub token: Token,
+    #[data]
+    pub metadata: Bytes,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct GasAddedEvent {
+    #[topic]
+    pub sender: Address,
+    #[topic]
+    pub message_id: String,
+    #[topic]
+    pub spender: Address,
+    #[topic]
+    pub token: Token,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct GasRefundedEvent {
+    #[topic]
+    pub receipt_id: GasReceiptId,
+    #[topic]
+    pub receiver: Address,
+    #[topic]
+    pub token: Token,
 }
 
-pub fn gas_added(env: &Env, sender: Address, message_id: String, spender: Address, token: Token) {
-    let topics = (
-        Symbol::new(env, "gas_added"),
-        sender,
-        message_id,
-        spender,
-        token,
-    );
-    env.events().publish(topics, ());
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct GasCollectedEvent {
+    #[topic]
+    pub receiver: Address,
+    #[topic]
+    pub token: Token,
 }
 
-pub fn refunded(env: &Env, message_id: String, receiver: Address, token: Token) {
-    let topics = (
-        Symbol::new(env, "gas_refunded"),
-        message_id,
-        receiver,
-        token,
-    );
-    env.events().publish(topics, ());
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct GasInfoSetEvent {
+    #[topic]
+    pub destination_chain: String,
+    #[data]
+    pub gas_info: Option<GasInfo>,
 }
 
-pub fn fee_collected(env: &Env, receiver: Address, token: Token) {
-    let topics = (Symbol::new(env, "gas_collected"), receiver, token);
-    env.events().publish(topics, ());
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct HubChainSetEvent {
+    #[data]
+    pub hub_chain: Option<String>,
 }
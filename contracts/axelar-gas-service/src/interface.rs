@@ -1,7 +1,8 @@
 use axelar_soroban_std::types::Token;
-use soroban_sdk::{contractclient, Address, Bytes, Env, String};
+use soroban_sdk::{contractclient, Address, Bytes, Env, Map, String};
 
 use crate::error::ContractError;
+use crate::types::{GasInfo, GasReceiptId};
 
 #[contractclient(name = "AxelarGasServiceClient")]
 pub trait AxelarGasServiceInterface {
@@ -12,6 +13,13 @@ pub trait AxelarGasServiceInterface {
     /// `sender` refers to the address that sent the cross-chain message via the `axelar_gateway`.
     /// The `spender` pays the gas but might differ from the `sender`,
     /// e.g. the `sender` is a contract, but the `spender` can be the user signing the transaction.
+    ///
+    /// The payment is recorded under [`GasReceiptId::Payload`] for the `sender` and the hash of the `payload`.
+    ///
+    /// If the gas info of the `destination_chain` rejects underpayment, fails if the payment is not in the configured
+    /// gas token, or doesn't cover the fees estimated by [`estimate_gas_fee`] without execution gas.
+    ///
+    /// Fails if the gas service is paused.
     fn pay_gas(
         env: Env,
         sender: Address,
@@ -28,6 +36,8 @@ pub trait AxelarGasServiceInterface {
     /// `sender` refers to the address that sent the cross-chain message via the `axelar_gateway`.
     /// The `spender` pays the gas but might differ from the `sender`,
     /// e.g. the `sender` is a contract, but the `spender` can be the user signing the transaction.
+    ///
+    /// The payment is recorded under [`GasReceiptId::MessageId`] for the `message_id`.
     fn add_gas(
         env: Env,
         sender: Address,
@@ -43,9 +53,57 @@ pub trait AxelarGasServiceInterface {
 
     /// Refunds gas payment to the receiver in relation to a specific cross-chain message.
     ///
+    /// The refunded amount is deducted from the gas recorded for the message in the given token,
+    /// and fails if it exceeds the recorded amount.
+    ///
     /// Only callable by the `gas_collector`.
-    fn refund(env: Env, message_id: String, receiver: Address, token: Token);
+    fn refund(
+        env: Env,
+        receipt_id: GasReceiptId,
+        receiver: Address,
+        token: Token,
+    ) -> Result<(), ContractError>;
+
+    /// Returns the gas paid for a cross-chain message that hasn't been refunded, by token address.
+    fn gas_paid(env: &Env, receipt_id: GasReceiptId) -> Map<Address, i128>;
 
     /// Returns the address of the `gas_collector`.
     fn gas_collector(env: &Env) -> Address;
+
+    /// Sets the gas info used to estimate fees for messages to the `destination_chain`.
+    /// Setting it to `None` removes the gas info.
+    ///
+    /// Only callable by the `gas_collector`.
+    fn set_gas_info(
+        env: Env,
+        destination_chain: String,
+        gas_info: Option<GasInfo>,
+    ) -> Result<(), ContractError>;
+
+    /// Returns the gas info of the `destination_chain`, if set.
+    fn gas_info(env: &Env, destination_chain: String) -> Option<GasInfo>;
+
+    /// Returns the ITS hub chain, if set.
+    fn hub_chain(env: &Env) -> Option<String>;
+
+    /// Sets the ITS hub chain, whose gas info provides the fee for the second hop of messages routed via the hub.
+    /// Setting it to `None` stops charging the hub fee.
+    ///
+    /// Only callable by the `gas_collector`.
+    fn set_hub_chain(env: Env, hub_chain: Option<String>) -> Result<(), ContractError>;
+
+    /// Estimates the gas fee for sending a message to the `destination_address` on the `destination_chain`
+    /// and executing it with `execute_gas_limit` units of gas.
+    ///
+    /// The hub fee of the gas info is only included if the `destination_chain` is the hub chain.
+    /// The `destination_address` doesn't affect the estimate yet.
+    ///
+    /// Fails if no gas info is set for the `destination_chain`.
+    fn estimate_gas_fee(
+        env: &Env,
+        destination_chain: String,
+        destination_address: String,
+        payload: Bytes,
+        execute_gas_limit: u64,
+    ) -> Result<Token, ContractError>;
 }
@@ -1,6 +1,10 @@
 #![no_std]
 
+#[cfg(any(test, feature = "testutils"))]
+extern crate std;
+
 pub mod error;
+pub mod types;
 
 mod interface;
 
@@ -11,7 +15,7 @@ cfg_if::cfg_if! {
     if #[cfg(all(feature = "library", not(feature = "testutils")))] {
         pub use interface::{AxelarGasServiceClient, AxelarGasServiceInterface};
     } else {
-        mod event;
+        pub mod event;
         mod storage_types;
         mod contract;
 
@@ -1,7 +1,12 @@
-use soroban_sdk::contracttype;
+use soroban_sdk::{contracttype, String};
+
+use crate::types::GasReceiptId;
 
 #[contracttype]
 #[derive(Clone, Debug)]
 pub enum DataKey {
     GasCollector,
+    GasReceipt(GasReceiptId),
+    ChainGasInfo(String),
+    HubChain,
 }
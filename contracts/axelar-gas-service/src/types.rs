@@ -0,0 +1,37 @@
+use soroban_sdk::{contracttype, Address, BytesN, String};
+
+/// Identifies the cross-chain message that gas was paid for.
+///
+/// Gas paid via `pay_gas` is paid before the message is sent via the gateway, so its message ID isn't known yet.
+/// Such payments are identified by the sender and the payload hash of the message instead.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GasReceiptId {
+    /// The ID of the message, used by `add_gas`.
+    MessageId(String),
+    /// The sender and the payload hash of the message, used by `pay_gas`.
+    Payload(Address, BytesN<32>),
+}
+
+/// The gas fee configuration for a destination chain, set by the `gas_collector`.
+///
+/// All fees are denominated in the token at `token_address`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasInfo {
+    /// The token that the fees are denominated in.
+    pub token_address: Address,
+    /// Flat fee charged for every message.
+    pub base_fee: i128,
+    /// Fee charged per byte of the message payload.
+    pub payload_fee_per_byte: i128,
+    /// Amount of the gas token charged per unit of execution gas on the destination chain,
+    /// i.e. the destination chain gas price converted into the gas token.
+    pub exchange_rate: i128,
+    /// Flat fee for the second hop of messages routed via the ITS hub.
+    /// Only charged for messages to the hub chain set via `set_hub_chain`, and ignored for other chains.
+    pub hub_fee: i128,
+    /// Whether `pay_gas` rejects payments that don't cover the fees of the message,
+    /// excluding the execution gas which isn't known to `pay_gas`.
+    pub reject_underpayment: bool,
+}
@@ -4,17 +4,18 @@ extern crate std;
 use std::format;
 
 use axelar_gas_service::error::ContractError;
+use axelar_gas_service::types::{GasInfo, GasReceiptId};
 use axelar_gas_service::{AxelarGasService, AxelarGasServiceClient};
 use axelar_soroban_std::{
     assert_contract_err, assert_invoke_auth_err, assert_last_emitted_event, types::Token,
 };
-use soroban_sdk::Bytes;
 use soroban_sdk::{
     bytes,
     testutils::Address as _,
     token::{StellarAssetClient, TokenClient},
     Address, Env, String, Symbol,
 };
+use soroban_sdk::{Bytes, BytesN, Map};
 
 fn setup_env<'a>() -> (Env, Address, Address, AxelarGasServiceClient<'a>) {
     let env = Env::default();
@@ -151,6 +152,12 @@ fn pay_gas() {
     assert_eq!(0, token_client.balance(&spender));
     assert_eq!(gas_amount, token_client.balance(&contract_id));
 
+    let payload_hash: BytesN<32> = env.crypto().keccak256(&payload).into();
+    assert_eq!(
+        client.gas_paid(&GasReceiptId::Payload(sender.clone(), payload_hash)),
+        Map::from_array(&env, [(asset.address(), gas_amount)])
+    );
+
     assert_last_emitted_event(
         &env,
         &contract_id,
@@ -167,6 +174,54 @@ fn pay_gas() {
     );
 }
 
+#[test]
+fn fail_pay_gas_when_paused() {
+    let (env, _, _, client) = setup_env();
+
+    let asset = &env.register_stellar_asset_contract_v2(Address::generate(&env));
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let gas_amount: i128 = 1;
+    let token = Token {
+        address: asset.address(),
+        amount: gas_amount,
+    };
+    let payload = bytes!(&env, 0x1234);
+    let destination_chain: String = String::from_str(&env, "ethereum");
+    let destination_address: String =
+        String::from_str(&env, "0x4EFE356BEDeCC817cb89B4E9b796dB8bC188DC59");
+
+    StellarAssetClient::new(&env, &asset.address()).mint(&spender, &gas_amount);
+
+    client.pause();
+
+    assert_contract_err!(
+        client.try_pay_gas(
+            &sender,
+            &destination_chain,
+            &destination_address,
+            &payload,
+            &spender,
+            &token,
+            &Bytes::new(&env),
+        ),
+        ContractError::ContractPaused
+    );
+
+    client.unpause();
+
+    client.pay_gas(
+        &sender,
+        &destination_chain,
+        &destination_address,
+        &payload,
+        &spender,
+        &token,
+        &Bytes::new(&env),
+    );
+}
+
 #[test]
 fn fail_add_gas_zero_gas_amount() {
     let (env, _, _, client) = setup_env();
@@ -230,6 +285,10 @@ fn add_gas() {
 
     assert_eq!(0, token_client.balance(&spender));
     assert_eq!(gas_amount, token_client.balance(&contract_id));
+    assert_eq!(
+        client.gas_paid(&GasReceiptId::MessageId(message_id.clone())),
+        Map::from_array(&env, [(asset.address(), gas_amount)])
+    );
 
     assert_last_emitted_event(
         &env,
@@ -245,6 +304,39 @@ fn add_gas() {
     );
 }
 
+#[test]
+fn fail_add_gas_overflow() {
+    let (env, _, gas_collector, client) = setup_env();
+
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let message_id = message_id(&env);
+
+    add_gas_for_message(&env, &client, &asset.address(), &message_id, i128::MAX);
+    client.collect_fees(
+        &gas_collector,
+        &Token {
+            address: asset.address(),
+            amount: i128::MAX,
+        },
+    );
+
+    let spender: Address = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset.address()).mint(&spender, &1);
+
+    assert_contract_err!(
+        client.try_add_gas(
+            &Address::generate(&env),
+            &message_id,
+            &spender,
+            &Token {
+                address: asset.address(),
+                amount: 1,
+            },
+        ),
+        ContractError::InvalidAmount
+    );
+}
+
 #[test]
 fn fail_collect_fees_zero_refund_amount() {
     let (env, contract_id, gas_collector, client) = setup_env();
@@ -353,10 +445,10 @@ fn fail_refund_unauthorized() {
         address: asset.address(),
         amount: refund_amount,
     };
-    let message_id = message_id(&env);
+    let receipt_id = GasReceiptId::MessageId(message_id(&env));
     let user: Address = Address::generate(&env);
 
-    assert_invoke_auth_err!(user, client.try_refund(&message_id, &receiver, &token));
+    assert_invoke_auth_err!(user, client.try_refund(&receipt_id, &receiver, &token));
 }
 
 #[test]
@@ -375,9 +467,30 @@ fn fail_refund_not_enough_balance() {
         amount: refund_amount,
     };
 
-    let message_id = message_id(&env);
+    let receipt_id = GasReceiptId::MessageId(message_id(&env));
+
+    assert!(client.try_refund(&receipt_id, &receiver, &token).is_err());
+}
 
-    assert!(client.try_refund(&message_id, &receiver, &token).is_err());
+fn add_gas_for_message(
+    env: &Env,
+    client: &AxelarGasServiceClient,
+    asset: &Address,
+    message_id: &String,
+    amount: i128,
+) {
+    let spender: Address = Address::generate(env);
+    StellarAssetClient::new(env, asset).mint(&spender, &amount);
+
+    client.add_gas(
+        &Address::generate(env),
+        message_id,
+        &spender,
+        &Token {
+            address: asset.clone(),
+            amount,
+        },
+    );
 }
 
 #[test]
@@ -388,7 +501,8 @@ fn refund() {
 
     let token_client = TokenClient::new(&env, &asset.address());
     let supply: i128 = 1000;
-    StellarAssetClient::new(&env, &asset.address()).mint(&contract_id, &supply);
+    let message_id = message_id(&env);
+    add_gas_for_message(&env, &client, &asset.address(), &message_id, supply);
 
     let receiver: Address = Address::generate(&env);
     let refund_amount: i128 = 1;
@@ -397,22 +511,386 @@ fn refund() {
         amount: refund_amount,
     };
 
-    let message_id = message_id(&env);
+    let receipt_id = GasReceiptId::MessageId(message_id);
 
-    client.refund(&message_id, &receiver, &token);
+    client.refund(&receipt_id, &receiver, &token);
 
     assert_eq!(refund_amount, token_client.balance(&receiver));
     assert_eq!(supply - refund_amount, token_client.balance(&contract_id));
+    assert_eq!(
+        client.gas_paid(&receipt_id),
+        Map::from_array(&env, [(asset.address(), supply - refund_amount)])
+    );
 
     assert_last_emitted_event(
         &env,
         &contract_id,
         (
             Symbol::new(&env, "gas_refunded"),
-            message_id,
+            receipt_id,
             receiver,
             token,
         ),
         (),
     );
 }
+
+#[test]
+fn refund_pay_gas_payment() {
+    let (env, contract_id, _, client) = setup_env();
+
+    let asset = &env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let token_client = TokenClient::new(&env, &asset.address());
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let gas_amount: i128 = 10;
+    let token = Token {
+        address: asset.address(),
+        amount: gas_amount,
+    };
+    let payload = bytes!(&env, 0x1234);
+    StellarAssetClient::new(&env, &asset.address()).mint(&spender, &gas_amount);
+
+    client.pay_gas(
+        &sender,
+        &String::from_str(&env, "ethereum"),
+        &String::from_str(&env, "0x4EFE356BEDeCC817cb89B4E9b796dB8bC188DC59"),
+        &payload,
+        &spender,
+        &token,
+        &Bytes::new(&env),
+    );
+
+    let receipt_id = GasReceiptId::Payload(sender, env.crypto().keccak256(&payload).into());
+
+    client.refund(&receipt_id, &spender, &token);
+
+    assert_eq!(gas_amount, token_client.balance(&spender));
+    assert_eq!(0, token_client.balance(&contract_id));
+    assert_eq!(client.gas_paid(&receipt_id), Map::new(&env));
+}
+
+#[test]
+fn fail_refund_exceeds_gas_paid() {
+    let (env, _, _, client) = setup_env();
+
+    let asset = &env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let other_asset = &env.register_stellar_asset_contract_v2(Address::generate(&env));
+
+    let gas_amount: i128 = 10;
+    let message_id = message_id(&env);
+    add_gas_for_message(&env, &client, &asset.address(), &message_id, gas_amount);
+    StellarAssetClient::new(&env, &other_asset.address()).mint(&client.address, &gas_amount);
+
+    let receiver: Address = Address::generate(&env);
+    let receipt_id = GasReceiptId::MessageId(message_id);
+
+    assert_contract_err!(
+        client.try_refund(
+            &receipt_id,
+            &receiver,
+            &Token {
+                address: asset.address(),
+                amount: gas_amount + 1,
+            }
+        ),
+        ContractError::RefundExceedsGasPaid
+    );
+
+    // gas paid in one token can't be refunded in another token
+    assert_contract_err!(
+        client.try_refund(
+            &receipt_id,
+            &receiver,
+            &Token {
+                address: other_asset.address(),
+                amount: 1,
+            }
+        ),
+        ContractError::RefundExceedsGasPaid
+    );
+
+    // the recorded amount can't be refunded twice
+    let token = Token {
+        address: asset.address(),
+        amount: gas_amount,
+    };
+    client.refund(&receipt_id, &receiver, &token);
+    assert_contract_err!(
+        client.try_refund(&receipt_id, &receiver, &token),
+        ContractError::RefundExceedsGasPaid
+    );
+}
+
+#[test]
+fn fail_refund_zero_amount() {
+    let (env, _, _, client) = setup_env();
+
+    let asset = &env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let message_id = message_id(&env);
+    add_gas_for_message(&env, &client, &asset.address(), &message_id, 10);
+
+    assert_contract_err!(
+        client.try_refund(
+            &GasReceiptId::MessageId(message_id),
+            &Address::generate(&env),
+            &Token {
+                address: asset.address(),
+                amount: 0,
+            }
+        ),
+        ContractError::InvalidAmount
+    );
+}
+
+fn gas_info(token_address: &Address, reject_underpayment: bool) -> GasInfo {
+    GasInfo {
+        token_address: token_address.clone(),
+        base_fee: 100,
+        payload_fee_per_byte: 2,
+        exchange_rate: 3,
+        hub_fee: 50,
+        reject_underpayment,
+    }
+}
+
+fn pay_gas_amount(
+    env: &Env,
+    client: &AxelarGasServiceClient,
+    destination_chain: &String,
+    token_address: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let spender: Address = Address::generate(env);
+    StellarAssetClient::new(env, token_address).mint(&spender, &amount);
+
+    client
+        .try_pay_gas(
+            &Address::generate(env),
+            destination_chain,
+            &String::from_str(env, "0x4EFE356BEDeCC817cb89B4E9b796dB8bC188DC59"),
+            &bytes!(env, 0x1234),
+            &spender,
+            &Token {
+                address: token_address.clone(),
+                amount,
+            },
+            &Bytes::new(env),
+        )
+        .map(|_| ())
+        .map_err(|err| err.expect("unexpected host error"))
+}
+
+#[test]
+fn set_gas_info() {
+    let (env, contract_id, _, client) = setup_env();
+
+    let destination_chain = String::from_str(&env, "ethereum");
+    let gas_info = gas_info(&Address::generate(&env), false);
+
+    assert_eq!(client.gas_info(&destination_chain), None);
+
+    client.set_gas_info(&destination_chain, &Some(gas_info.clone()));
+
+    assert_last_emitted_event(
+        &env,
+        &contract_id,
+        (Symbol::new(&env, "gas_info_set"), destination_chain.clone()),
+        (Some(gas_info.clone()),),
+    );
+    assert_eq!(client.gas_info(&destination_chain), Some(gas_info));
+
+    client.set_gas_info(&destination_chain, &None);
+    assert_eq!(client.gas_info(&destination_chain), None);
+}
+
+#[test]
+fn fail_set_gas_info_unauthorized() {
+    let (env, _, _, client) = setup_env();
+
+    let user: Address = Address::generate(&env);
+    let gas_info = Some(gas_info(&Address::generate(&env), false));
+
+    assert_invoke_auth_err!(
+        user,
+        client.try_set_gas_info(&String::from_str(&env, "ethereum"), &gas_info)
+    );
+}
+
+#[test]
+fn fail_set_gas_info_negative_fee() {
+    let (env, _, _, client) = setup_env();
+
+    let gas_info = GasInfo {
+        base_fee: -1,
+        ..gas_info(&Address::generate(&env), false)
+    };
+
+    assert_contract_err!(
+        client.try_set_gas_info(&String::from_str(&env, "ethereum"), &Some(gas_info)),
+        ContractError::InvalidGasInfo
+    );
+}
+
+#[test]
+fn estimate_gas_fee() {
+    let (env, _, _, client) = setup_env();
+
+    let destination_chain = String::from_str(&env, "ethereum");
+    let token_address = Address::generate(&env);
+    client.set_gas_info(&destination_chain, &Some(gas_info(&token_address, false)));
+
+    // base fee + 2 payload bytes * 2 + 10 gas * 3, without the hub fee of a non-hub chain
+    let expected_fee = 100 + 2 * 2 + 10 * 3;
+
+    assert_eq!(
+        client.estimate_gas_fee(
+            &destination_chain,
+            &String::from_str(&env, "destination_address"),
+            &bytes!(&env, 0x1234),
+            &10,
+        ),
+        Token {
+            address: token_address,
+            amount: expected_fee,
+        }
+    );
+}
+
+#[test]
+fn estimate_gas_fee_includes_hub_fee_for_hub_chain() {
+    let (env, _, _, client) = setup_env();
+
+    let hub_chain = String::from_str(&env, "axelar");
+    let token_address = Address::generate(&env);
+    client.set_gas_info(&hub_chain, &Some(gas_info(&token_address, false)));
+    client.set_hub_chain(&Some(hub_chain.clone()));
+
+    // base fee + 2 payload bytes * 2 + 10 gas * 3 + hub fee
+    let expected_fee = 100 + 2 * 2 + 10 * 3 + 50;
+
+    assert_eq!(
+        client.estimate_gas_fee(
+            &hub_chain,
+            &String::from_str(&env, "destination_address"),
+            &bytes!(&env, 0x1234),
+            &10,
+        ),
+        Token {
+            address: token_address,
+            amount: expected_fee,
+        }
+    );
+}
+
+#[test]
+fn set_hub_chain() {
+    let (env, contract_id, _, client) = setup_env();
+
+    let hub_chain = String::from_str(&env, "axelar");
+
+    assert_eq!(client.hub_chain(), None);
+
+    client.set_hub_chain(&Some(hub_chain.clone()));
+
+    assert_last_emitted_event(
+        &env,
+        &contract_id,
+        (Symbol::new(&env, "hub_chain_set"),),
+        (Some(hub_chain.clone()),),
+    );
+    assert_eq!(client.hub_chain(), Some(hub_chain));
+
+    client.set_hub_chain(&None);
+    assert_eq!(client.hub_chain(), None);
+}
+
+#[test]
+fn fail_set_hub_chain_unauthorized() {
+    let (env, _, _, client) = setup_env();
+
+    let user: Address = Address::generate(&env);
+
+    assert_invoke_auth_err!(
+        user,
+        client.try_set_hub_chain(&Some(String::from_str(&env, "axelar")))
+    );
+}
+
+#[test]
+fn fail_estimate_gas_fee_gas_info_not_found() {
+    let (env, _, _, client) = setup_env();
+
+    assert_contract_err!(
+        client.try_estimate_gas_fee(
+            &String::from_str(&env, "ethereum"),
+            &String::from_str(&env, "destination_address"),
+            &bytes!(&env, 0x1234),
+            &10,
+        ),
+        ContractError::GasInfoNotFound
+    );
+}
+
+#[test]
+fn pay_gas_underpayment_allowed_if_not_rejected() {
+    let (env, _, _, client) = setup_env();
+
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let destination_chain = String::from_str(&env, "ethereum");
+    client.set_gas_info(&destination_chain, &Some(gas_info(&asset.address(), false)));
+
+    assert_eq!(
+        pay_gas_amount(&env, &client, &destination_chain, &asset.address(), 1),
+        Ok(())
+    );
+}
+
+#[test]
+fn fail_pay_gas_underpayment() {
+    let (env, _, _, client) = setup_env();
+
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let destination_chain = String::from_str(&env, "ethereum");
+    client.set_gas_info(&destination_chain, &Some(gas_info(&asset.address(), true)));
+
+    // base fee + 2 payload bytes * 2, without execution gas
+    let min_fee = 100 + 2 * 2;
+
+    assert_eq!(
+        pay_gas_amount(
+            &env,
+            &client,
+            &destination_chain,
+            &asset.address(),
+            min_fee - 1
+        ),
+        Err(ContractError::InsufficientGasPayment)
+    );
+    assert_eq!(
+        pay_gas_amount(&env, &client, &destination_chain, &asset.address(), min_fee),
+        Ok(())
+    );
+}
+
+#[test]
+fn fail_pay_gas_invalid_gas_token() {
+    let (env, _, _, client) = setup_env();
+
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let other_asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let destination_chain = String::from_str(&env, "ethereum");
+    client.set_gas_info(&destination_chain, &Some(gas_info(&asset.address(), true)));
+
+    assert_eq!(
+        pay_gas_amount(
+            &env,
+            &client,
+            &destination_chain,
+            &other_asset.address(),
+            1000
+        ),
+        Err(ContractError::InvalidGasToken)
+    );
+}
@@ -1,10 +1,11 @@
 use crate::error::ContractError;
-use crate::types::{ProofSignature, ProofSigner, WeightedSigner};
+use crate::types::{ProofSignature, ProofSigner, PublicKey, Signature, WeightedSigner};
 use axelar_soroban_std::ensure;
+use axelar_soroban_std::events::Event;
 use soroban_sdk::{crypto::Hash, Bytes, BytesN, Env, Vec};
 
-use crate::event;
-use crate::storage_types::DataKey;
+use crate::event::SignersRotatedEvent;
+use crate::storage_types;
 use crate::types::{Proof, WeightedSigners};
 
 pub fn initialize_auth(
@@ -14,21 +15,14 @@ pub fn initialize_auth(
     previous_signer_retention: u64,
     initial_signers: Vec<WeightedSigners>,
 ) -> Result<(), ContractError> {
-    env.storage().instance().set(&DataKey::Epoch, &0_u64);
+    storage_types::set_epoch(&env, &0_u64);
 
     // TODO: Do we need to manually expose these in a query, or can it be read directly off of storage in Stellar?
-    env.storage().instance().set(
-        &DataKey::PreviousSignerRetention,
-        &previous_signer_retention,
-    );
+    storage_types::set_previous_signer_retention(&env, &previous_signer_retention);
 
-    env.storage()
-        .instance()
-        .set(&DataKey::DomainSeparator, &domain_separator);
+    storage_types::set_domain_separator(&env, &domain_separator);
 
-    env.storage()
-        .instance()
-        .set(&DataKey::MinimumRotationDelay, &minimum_rotation_delay);
+    storage_types::set_minimum_rotation_delay(&env, &minimum_rotation_delay);
 
     ensure!(!initial_signers.is_empty(), ContractError::EmptySigners);
 
@@ -54,11 +48,7 @@ pub fn validate_proof(
 
     let is_latest_signers: bool = signers_epoch == current_epoch;
 
-    let previous_signers_retention: u64 = env
-        .storage()
-        .instance()
-        .get(&DataKey::PreviousSignerRetention)
-        .expect("previous_signers_retention not found");
+    let previous_signers_retention = storage_types::previous_signer_retention(env);
 
     ensure!(
         current_epoch - signers_epoch <= previous_signers_retention,
@@ -80,7 +70,7 @@ pub fn rotate_signers(
     new_signers: &WeightedSigners,
     enforce_rotation_delay: bool,
 ) -> Result<(), ContractError> {
-    validate_signers(env, new_signers)?;
+    validate_signers(new_signers)?;
 
     update_rotation_timestamp(env, enforce_rotation_delay)?;
 
@@ -88,54 +78,41 @@ pub fn rotate_signers(
 
     let new_epoch: u64 = epoch(env) + 1;
 
-    env.storage().instance().set(&DataKey::Epoch, &new_epoch);
+    storage_types::set_epoch(env, &new_epoch);
 
-    env.storage()
-        .persistent()
-        .set(&DataKey::SignersHashByEpoch(new_epoch), &new_signers_hash);
+    storage_types::set_signers_hash_by_epoch(env, &new_epoch, &new_signers_hash);
 
     ensure!(
         epoch_by_signers_hash(env, new_signers_hash.clone()).is_err(),
         ContractError::DuplicateSigners
     );
 
-    env.storage().persistent().set(
-        &DataKey::EpochBySignersHash(new_signers_hash.clone()),
-        &new_epoch,
-    );
+    storage_types::set_epoch_by_signers_hash(env, &new_signers_hash, &new_epoch);
 
-    event::rotate_signers(env, new_epoch, new_signers_hash);
+    SignersRotatedEvent {
+        epoch: new_epoch,
+        signers_hash: new_signers_hash,
+    }
+    .emit(env);
 
     Ok(())
 }
 
 pub fn epoch(env: &Env) -> u64 {
-    env.storage()
-        .instance()
-        .get(&DataKey::Epoch)
-        .expect("epoch not found")
+    storage_types::epoch(env)
 }
 
 pub fn epoch_by_signers_hash(env: &Env, signers_hash: BytesN<32>) -> Result<u64, ContractError> {
-    env.storage()
-        .persistent()
-        .get(&DataKey::EpochBySignersHash(signers_hash))
+    storage_types::try_epoch_by_signers_hash(env, &signers_hash)
         .ok_or(ContractError::InvalidSignersHash)
 }
 
 pub fn signers_hash_by_epoch(env: &Env, epoch: u64) -> Result<BytesN<32>, ContractError> {
-    env.storage()
-        .persistent()
-        .get(&DataKey::SignersHashByEpoch(epoch))
-        .ok_or(ContractError::InvalidEpoch)
+    storage_types::try_signers_hash_by_epoch(env, &epoch).ok_or(ContractError::InvalidEpoch)
 }
 
 fn message_hash_to_sign(env: &Env, signers_hash: BytesN<32>, data_hash: &BytesN<32>) -> Hash<32> {
-    let domain_separator: BytesN<32> = env
-        .storage()
-        .instance()
-        .get(&DataKey::DomainSeparator)
-        .unwrap();
+    let domain_separator = storage_types::domain_separator(env);
 
     let mut msg: Bytes = domain_separator.into();
     msg.extend_from_array(&signers_hash.to_array());
@@ -146,17 +123,9 @@ fn message_hash_to_sign(env: &Env, signers_hash: BytesN<32>, data_hash: &BytesN<
 }
 
 fn update_rotation_timestamp(env: &Env, enforce_rotation_delay: bool) -> Result<(), ContractError> {
-    let minimum_rotation_delay: u64 = env
-        .storage()
-        .instance()
-        .get(&DataKey::MinimumRotationDelay)
-        .expect("minimum_rotation_delay not found");
-
-    let last_rotation_timestamp: u64 = env
-        .storage()
-        .instance()
-        .get(&DataKey::LastRotationTimestamp)
-        .unwrap_or(0);
+    let minimum_rotation_delay = storage_types::minimum_rotation_delay(env);
+
+    let last_rotation_timestamp = storage_types::try_last_rotation_timestamp(env).unwrap_or(0);
 
     let current_timestamp = env.ledger().timestamp();
 
@@ -167,9 +136,7 @@ fn update_rotation_timestamp(env: &Env, enforce_rotation_delay: bool) -> Result<
         );
     }
 
-    env.storage()
-        .instance()
-        .set(&DataKey::LastRotationTimestamp, &current_timestamp);
+    storage_types::set_last_rotation_timestamp(env, &current_timestamp);
 
     Ok(())
 }
@@ -186,8 +153,9 @@ fn validate_signatures(env: &Env, msg_hash: Hash<32>, proof: Proof) -> bool {
     } in proof.signers.iter()
     {
         if let ProofSignature::Signed(signature) = signature {
-            env.crypto()
-                .ed25519_verify(&public_key, msg_hash.to_bytes().as_ref(), &signature);
+            if !verify_signature(env, &msg_hash, public_key, signature) {
+                return false;
+            }
 
             total_weight = total_weight.checked_add(weight).unwrap();
 
@@ -200,27 +168,82 @@ fn validate_signatures(env: &Env, msg_hash: Hash<32>, proof: Proof) -> bool {
     false
 }
 
+/// Verify the signature of `msg_hash` with the given public key.
+///
+/// An invalid Ed25519 signature panics in the host function.
+/// For secp256k1, the public key is recovered from the signature and compared against the signer.
+/// Returns false if the signature scheme doesn't match the scheme of the public key.
+fn verify_signature(
+    env: &Env,
+    msg_hash: &Hash<32>,
+    public_key: PublicKey,
+    signature: Signature,
+) -> bool {
+    match (public_key, signature) {
+        (PublicKey::Ed25519(public_key), Signature::Ed25519(signature)) => {
+            env.crypto()
+                .ed25519_verify(&public_key, msg_hash.to_bytes().as_ref(), &signature);
+
+            true
+        }
+        (PublicKey::Secp256k1(public_key), Signature::Secp256k1(signature)) => {
+            let signature = signature.to_array();
+
+            let mut rs = [0u8; 64];
+            rs.copy_from_slice(&signature[..64]);
+
+            // accept both the raw recovery id and the Ethereum style `v` value
+            let recovery_id = match signature[64] {
+                v @ 0..=1 => v,
+                v @ 27..=28 => v - 27,
+                _ => return false,
+            };
+
+            let recovered_key = env.crypto().secp256k1_recover(
+                msg_hash,
+                &BytesN::from_array(env, &rs),
+                recovery_id as u32,
+            );
+
+            compress_secp256k1_key(env, &recovered_key) == public_key
+        }
+        _ => false,
+    }
+}
+
+/// Convert an uncompressed SEC1 secp256k1 public key into its compressed form.
+fn compress_secp256k1_key(env: &Env, public_key: &BytesN<65>) -> BytesN<33> {
+    let public_key = public_key.to_array();
+
+    let mut compressed = [0u8; 33];
+    compressed[0] = if public_key[64] % 2 == 0 { 0x02 } else { 0x03 };
+    compressed[1..].copy_from_slice(&public_key[1..33]);
+
+    BytesN::from_array(env, &compressed)
+}
+
 /// Check if signer set is valid, i.e signer/pub key hash are in sorted order,
 /// weights are non-zero and sum to at least threshold
-fn validate_signers(env: &Env, weighted_signers: &WeightedSigners) -> Result<(), ContractError> {
+///
+/// Signers are sorted by signature scheme first and by public key second (see [`PublicKey`]).
+fn validate_signers(weighted_signers: &WeightedSigners) -> Result<(), ContractError> {
     ensure!(
         !weighted_signers.signers.is_empty(),
         ContractError::EmptySigners
     );
 
-    // TODO: what's the min address/hash?
-    let mut previous_signer = BytesN::<32>::from_array(env, &[0; 32]);
+    let mut previous_signer: Option<PublicKey> = None;
     let mut total_weight = 0u128;
 
     for signer in weighted_signers.signers.iter() {
         ensure!(
-            previous_signer < signer.signer,
+            previous_signer.map_or(true, |previous_signer| previous_signer < signer.signer),
             ContractError::InvalidSigners
         );
 
         ensure!(signer.weight != 0, ContractError::InvalidWeight);
 
-        previous_signer = signer.signer;
+        previous_signer = Some(signer.signer);
         total_weight = total_weight
             .checked_add(signer.weight)
             .ok_or(ContractError::WeightOverflow)?;
@@ -1,16 +1,25 @@
 use crate::error::ContractError;
+use crate::event::{
+    ContractCalledEvent, MessageApprovedEvent, MessageExecutedEvent,
+    MessagesMerkleRootApprovedEvent,
+};
 use crate::interface::AxelarGatewayInterface;
 use crate::messaging_interface::AxelarGatewayMessagingInterface;
-use crate::storage_types::{DataKey, MessageApprovalKey, MessageApprovalValue};
+use crate::storage_types::{self, MessageApprovalKey, MessageApprovalValue};
 use crate::types::{CommandType, Message, Proof, WeightedSigners};
-use crate::{auth, event};
+use crate::{auth, merkle};
+use axelar_soroban_std::events::Event;
 use axelar_soroban_std::ttl::extend_instance_ttl;
-use axelar_soroban_std::{ensure, interfaces, Operatable, Ownable, Upgradable};
+use axelar_soroban_std::{
+    ensure, interfaces, when_not_paused, Operatable, Ownable, Pausable, TtlConfigurable, Upgradable,
+};
 use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String, Vec};
 
 #[contract]
-#[derive(Ownable, Upgradable, Operatable)]
+#[derive(Ownable, Upgradable, Operatable, Pausable, TtlConfigurable)]
+#[upgradable(wasm_registry)]
+#[pausable(by = operator)]
 pub struct AxelarGateway;
 
 #[contractimpl]
@@ -46,19 +55,23 @@ impl AxelarGatewayMessagingInterface for AxelarGateway {
         destination_chain: String,
         destination_address: String,
         payload: Bytes,
-    ) {
+    ) -> Result<(), ContractError> {
+        when_not_paused!(&env);
+
         caller.require_auth();
 
         let payload_hash = env.crypto().keccak256(&payload).into();
 
-        event::call_contract(
-            &env,
+        ContractCalledEvent {
             caller,
             destination_chain,
             destination_address,
-            payload,
             payload_hash,
-        );
+            payload,
+        }
+        .emit(&env);
+
+        Ok(())
     }
 
     fn is_message_approved(
@@ -115,12 +128,52 @@ impl AxelarGatewayMessagingInterface for AxelarGateway {
         };
 
         if message_approval == Self::message_approval_hash(&env, message.clone()) {
-            env.storage().persistent().set(
-                &DataKey::MessageApproval(key),
-                &MessageApprovalValue::Executed,
-            );
+            storage_types::set_message_approval(&env, &key, &MessageApprovalValue::Executed);
+
+            MessageExecutedEvent { message }.emit(&env);
+
+            return true;
+        }
+
+        false
+    }
+
+    fn validate_message_with_proof(
+        env: Env,
+        caller: Address,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload_hash: BytesN<32>,
+        merkle_proof: Vec<BytesN<32>>,
+    ) -> bool {
+        caller.require_auth();
+
+        let key = MessageApprovalKey {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+        };
 
-            event::execute_message(&env, message);
+        // Prevent replay if message is already executed
+        if Self::message_approval_by_key(&env, key.clone()) == MessageApprovalValue::Executed {
+            return false;
+        }
+
+        let message = Message {
+            source_chain,
+            message_id,
+            source_address,
+            contract_address: caller,
+            payload_hash,
+        };
+
+        let merkle_root =
+            merkle::process_proof(&env, merkle::leaf_hash(&env, &message), &merkle_proof);
+
+        if Self::is_messages_merkle_root_approved(&env, merkle_root) {
+            storage_types::set_message_approval(&env, &key, &MessageApprovalValue::Executed);
+
+            MessageExecutedEvent { message }.emit(&env);
 
             return true;
         }
@@ -128,6 +181,7 @@ impl AxelarGatewayMessagingInterface for AxelarGateway {
         false
     }
 }
+
 #[contractimpl]
 impl AxelarGatewayInterface for AxelarGateway {
     fn approve_messages(
@@ -156,19 +210,50 @@ impl AxelarGatewayInterface for AxelarGateway {
                 continue;
             }
 
-            env.storage().persistent().set(
-                &DataKey::MessageApproval(key),
+            storage_types::set_message_approval(
+                &env,
+                &key,
                 &Self::message_approval_hash(&env, message.clone()),
             );
 
-            event::approve_message(&env, message);
+            MessageApprovedEvent { message }.emit(&env);
+        }
+
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    fn approve_messages_merkle_root(
+        env: Env,
+        merkle_root: BytesN<32>,
+        proof: Proof,
+    ) -> Result<(), ContractError> {
+        let data_hash: BytesN<32> = env
+            .crypto()
+            .keccak256(&(CommandType::ApproveMessagesMerkleRoot, merkle_root.clone()).to_xdr(&env))
+            .into();
+
+        auth::validate_proof(&env, &data_hash, proof)?;
+
+        // Prevent duplicate events if the batch is already approved
+        if storage_types::is_messages_merkle_root(&env, &merkle_root) {
+            return Ok(());
         }
 
+        storage_types::set_messages_merkle_root(&env, &merkle_root);
+
+        MessagesMerkleRootApprovedEvent { merkle_root }.emit(&env);
+
         extend_instance_ttl(&env);
 
         Ok(())
     }
 
+    fn is_messages_merkle_root_approved(env: &Env, merkle_root: BytesN<32>) -> bool {
+        storage_types::is_messages_merkle_root(env, &merkle_root)
+    }
+
     fn rotate_signers(
         env: Env,
         signers: WeightedSigners,
@@ -211,6 +296,50 @@ impl AxelarGatewayInterface for AxelarGateway {
     ) -> Result<bool, ContractError> {
         auth::validate_proof(env, &data_hash, proof)
     }
+
+    fn extend_message_approval_ttl(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+    ) -> Result<(), ContractError> {
+        let key = MessageApprovalKey {
+            source_chain,
+            message_id,
+        };
+
+        // reading the approval extends its TTL
+        storage_types::try_message_approval(env, &key)
+            .ok_or(ContractError::MessageApprovalNotFound)?;
+
+        extend_instance_ttl(env);
+
+        Ok(())
+    }
+
+    fn extend_messages_merkle_root_ttl(
+        env: &Env,
+        merkle_root: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        // reading the Merkle root extends its TTL
+        ensure!(
+            storage_types::is_messages_merkle_root(env, &merkle_root),
+            ContractError::MessagesMerkleRootNotFound
+        );
+
+        extend_instance_ttl(env);
+
+        Ok(())
+    }
+
+    fn extend_signers_ttl(env: &Env, epoch: u64) -> Result<(), ContractError> {
+        // reading both directions of the epoch mapping extends their TTL
+        let signers_hash = auth::signers_hash_by_epoch(env, epoch)?;
+        auth::epoch_by_signers_hash(env, signers_hash)?;
+
+        extend_instance_ttl(env);
+
+        Ok(())
+    }
 }
 
 impl AxelarGateway {
@@ -230,10 +359,7 @@ impl AxelarGateway {
 
     /// Get the message approval value by key, defaulting to `MessageNotApproved`
     fn message_approval_by_key(env: &Env, key: MessageApprovalKey) -> MessageApprovalValue {
-        env.storage()
-            .persistent()
-            .get(&DataKey::MessageApproval(key))
-            .unwrap_or(MessageApprovalValue::NotApproved)
+        storage_types::try_message_approval(env, &key).unwrap_or(MessageApprovalValue::NotApproved)
     }
 
     fn message_approval_hash(env: &Env, message: Message) -> MessageApprovalValue {
@@ -22,4 +22,12 @@ pub enum ContractError {
     OutdatedSigners = 14,
     /// Messages
     EmptyMessages = 15,
+    MessageApprovalNotFound = 18,
+    MessagesMerkleRootNotFound = 19,
+    /// Pausable
+    ContractPaused = 16,
+    /// TTL
+    InvalidTtlConfig = 17,
+    /// Upgrades
+    UnapprovedWasmHash = 20,
 }
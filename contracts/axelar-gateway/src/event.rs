@@ -1,35 +1,44 @@
 use crate::types::Message;
-use soroban_sdk::{Address, Bytes, BytesN, Env, String, Symbol};
+use axelar_soroban_std::IntoEvent;
+use soroban_sdk::{Address, Bytes, BytesN, String};
 
-pub fn call_contract(
-    env: &Env,
-    caller: Address,
-    destination_chain: String,
-    destination_address: String,
-    payload: Bytes,
-    payload_hash: BytesN<32>,
-) {
-    let topics = (
-        Symbol::new(env, "contract_called"),
-        caller,
-        destination_chain,
-        destination_address,
-        payload_hash,
-    );
-    env.events().publish(topics, payload);
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(bare)]
+pub struct ContractCalledEvent {
+    #[topic]
+    pub caller: Address,
+    #[topic]
+    pub destination_chain: String,
+    #[topic]
+    pub destination_address: String,
+    #[topic]
+    pub payload_hash: BytesN<32>,
+    #[data]
+    pub payload: Bytes,
 }
 
-pub fn approve_message(env: &Env, message: Message) {
-    let topics = (Symbol::new(env, "message_approved"), message);
-    env.events().publish(topics, ());
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct MessageApprovedEvent {
+    #[topic]
+    pub message: Message,
 }
 
-pub fn execute_message(env: &Env, message: Message) {
-    let topics = (Symbol::new(env, "message_executed"), message);
-    env.events().publish(topics, ());
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct MessagesMerkleRootApprovedEvent {
+    #[topic]
+    pub merkle_root: BytesN<32>,
 }
 
-pub fn rotate_signers(env: &Env, epoch: u64, signers_hash: BytesN<32>) {
-    let topics = (Symbol::new(env, "signers_rotated"), epoch, signers_hash);
-    env.events().publish(topics, ());
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct MessageExecutedEvent {
+    #[topic]
+    pub message: Message,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct SignersRotatedEvent {
+    #[topic]
+    pub epoch: u64,
+    #[topic]
+    pub signers_hash: BytesN<32>,
 }
@@ -4,6 +4,9 @@ use soroban_sdk::{contractclient, Address, Bytes, Env, String};
 use crate::AxelarGatewayMessagingClient;
 use soroban_sdk::contracterror;
 
+#[doc(hidden)]
+pub use axelar_soroban_std::executable::execute_validated;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -28,6 +31,7 @@ pub trait AxelarExecutableInterface {
 
     /// Validate if a gateway has approved a message.
     /// This should be called from an implementation of `execute` before executing custom app logic.
+    /// Implementing [CustomAxelarExecutable] and [crate::impl_axelar_executable] takes care of this.
     /// This method doesn't get exposed from the contract, as Soroban SDK's contractimpl macro ignores default trait methods.
     fn validate_message(
         env: &Env,
@@ -53,3 +57,103 @@ pub trait AxelarExecutableInterface {
         Ok(())
     }
 }
+
+/// The app logic of an Axelar Executable app.
+///
+/// [crate::impl_axelar_executable] implements [AxelarExecutableInterface] on top of this trait,
+/// so the exposed `execute` entrypoint always validates the message before running [CustomAxelarExecutable::execute_payload].
+pub trait CustomAxelarExecutable {
+    /// Error type returned if the payload can't be executed.
+    type Error: Into<soroban_sdk::Error>;
+
+    /// Return the trusted gateway contract id.
+    /// Prefixed to avoid clashing with the `gateway` entrypoint generated by [crate::impl_axelar_executable].
+    fn __gateway(env: &Env) -> Address;
+
+    /// Execute the payload of a cross-chain message. Only called once the gateway has approved the message.
+    fn execute_payload(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload: Bytes,
+    ) -> Result<(), Self::Error>;
+}
+
+/// Implements the [AxelarExecutableInterface] for a Soroban contract that implements [CustomAxelarExecutable].
+///
+/// The generated `execute` entrypoint validates the message with the gateway and panics with [ExecutableError::NotApproved]
+/// if it hasn't been approved, and only then runs [CustomAxelarExecutable::execute_payload].
+///
+/// # Example
+/// ```rust
+/// # mod test {
+/// use axelar_gateway::executable::CustomAxelarExecutable;
+/// use axelar_gateway::impl_axelar_executable;
+/// use soroban_sdk::{contract, symbol_short, Address, Bytes, Env, String};
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// impl_axelar_executable!(Contract);
+///
+/// impl CustomAxelarExecutable for Contract {
+///     type Error = soroban_sdk::Error;
+///
+///     fn __gateway(env: &Env) -> Address {
+///         env.storage().instance().get(&symbol_short!("gateway")).unwrap()
+///     }
+///
+///     fn execute_payload(
+///         env: &Env,
+///         source_chain: String,
+///         message_id: String,
+///         source_address: String,
+///         payload: Bytes,
+///     ) -> Result<(), Self::Error> {
+///         // only reached for messages approved by the gateway
+///         Ok(())
+///     }
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_axelar_executable {
+    ($contract:ident) => {
+        use $crate::executable::AxelarExecutableInterface as _;
+
+        #[soroban_sdk::contractimpl]
+        impl $crate::executable::AxelarExecutableInterface for $contract {
+            fn gateway(env: &soroban_sdk::Env) -> soroban_sdk::Address {
+                <Self as $crate::executable::CustomAxelarExecutable>::__gateway(env)
+            }
+
+            fn execute(
+                env: soroban_sdk::Env,
+                source_chain: soroban_sdk::String,
+                message_id: soroban_sdk::String,
+                source_address: soroban_sdk::String,
+                payload: soroban_sdk::Bytes,
+            ) {
+                let validation =
+                    <Self as $crate::executable::AxelarExecutableInterface>::validate_message(
+                        &env,
+                        &source_chain,
+                        &message_id,
+                        &source_address,
+                        &payload,
+                    );
+
+                $crate::executable::execute_validated(&env, validation, || {
+                    <Self as $crate::executable::CustomAxelarExecutable>::execute_payload(
+                        &env,
+                        source_chain,
+                        message_id,
+                        source_address,
+                        payload,
+                    )
+                });
+            }
+        }
+    };
+}
@@ -3,12 +3,18 @@ use crate::{
     types::{Message, Proof, WeightedSigners},
     AxelarGatewayMessagingInterface,
 };
-use axelar_soroban_std::interfaces::{OperatableInterface, OwnableInterface, UpgradableInterface};
-use soroban_sdk::{contractclient, BytesN, Env, Vec};
+use axelar_soroban_std::interfaces::{
+    OperatableInterface, OwnableInterface, PausableInterface, UpgradableInterface,
+};
+use soroban_sdk::{contractclient, BytesN, Env, String, Vec};
 
 #[contractclient(name = "AxelarGatewayClient")]
 pub trait AxelarGatewayInterface:
-    AxelarGatewayMessagingInterface + UpgradableInterface + OwnableInterface + OperatableInterface
+    AxelarGatewayMessagingInterface
+    + UpgradableInterface
+    + OwnableInterface
+    + OperatableInterface
+    + PausableInterface
 {
     /// Approves a collection of messages.
     fn approve_messages(
@@ -17,6 +23,20 @@ pub trait AxelarGatewayInterface:
         proof: Proof,
     ) -> Result<(), ContractError>;
 
+    /// Approves a batch of messages by the root of a Merkle tree over the message hashes.
+    ///
+    /// Messages approved this way are not stored individually. Instead, the executing contract
+    /// validates a message via `validate_message_with_proof` with a Merkle inclusion proof,
+    /// so the cost of the approval doesn't grow with the number of messages in the batch.
+    fn approve_messages_merkle_root(
+        env: Env,
+        merkle_root: BytesN<32>,
+        proof: Proof,
+    ) -> Result<(), ContractError>;
+
+    /// Returns true if the given Merkle root of a batch of messages has been approved.
+    fn is_messages_merkle_root_approved(env: &Env, merkle_root: BytesN<32>) -> bool;
+
     // TODO: add docstring about how bypass_rotation_delay supposed to be used.
     fn rotate_signers(
         env: Env,
@@ -40,4 +60,26 @@ pub trait AxelarGatewayInterface:
         data_hash: BytesN<32>,
         proof: Proof,
     ) -> Result<bool, ContractError>;
+
+    /// Extends the TTL of the approval of the message with the given `source_chain` and `message_id`.
+    ///
+    /// Anyone can call this to keep an approved message from being archived before it is executed.
+    fn extend_message_approval_ttl(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+    ) -> Result<(), ContractError>;
+
+    /// Extends the TTL of the approved Merkle root of a batch of messages.
+    ///
+    /// Anyone can call this to keep a batch from being archived before all of its messages are executed.
+    fn extend_messages_merkle_root_ttl(
+        env: &Env,
+        merkle_root: BytesN<32>,
+    ) -> Result<(), ContractError>;
+
+    /// Extends the TTL of the signers of the given `epoch`, so proofs by these signers remain verifiable.
+    ///
+    /// Anyone can call this.
+    fn extend_signers_ttl(env: &Env, epoch: u64) -> Result<(), ContractError>;
 }
@@ -0,0 +1,39 @@
+//! Merkle tree helpers for approving a batch of messages with a single root.
+//!
+//! Leaves are the keccak256 hashes of the XDR encoded messages.
+//! Inner nodes hash the concatenation of both children in ascending order,
+//! so inclusion proofs only consist of the sibling hashes and don't need to encode positions.
+//! Leaves and inner nodes are hashed with distinct prefixes, so an inner node can't be passed off as a leaf.
+
+use soroban_sdk::{xdr::ToXdr, Bytes, BytesN, Env, Vec};
+
+use crate::types::Message;
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Returns the leaf hash of a message within a Merkle tree of messages.
+pub fn leaf_hash(env: &Env, message: &Message) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &[LEAF_PREFIX]);
+    data.append(&message.clone().to_xdr(env));
+
+    env.crypto().keccak256(&data).into()
+}
+
+/// Hashes two sibling nodes into their parent node.
+pub fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+
+    let mut data = Bytes::from_array(env, &[NODE_PREFIX]);
+    data.extend_from_array(&first.to_array());
+    data.extend_from_array(&second.to_array());
+
+    env.crypto().keccak256(&data).into()
+}
+
+/// Computes the Merkle root from a leaf and its inclusion proof.
+pub fn process_proof(env: &Env, leaf: BytesN<32>, proof: &Vec<BytesN<32>>) -> BytesN<32> {
+    proof
+        .iter()
+        .fold(leaf, |node, sibling| hash_pair(env, &node, &sibling))
+}
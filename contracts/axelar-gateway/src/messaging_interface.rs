@@ -1,4 +1,5 @@
-use soroban_sdk::{contractclient, Address, Bytes, BytesN, Env, String};
+use crate::error::ContractError;
+use soroban_sdk::{contractclient, Address, Bytes, BytesN, Env, String, Vec};
 
 #[contractclient(name = "AxelarGatewayMessagingClient")]
 pub trait AxelarGatewayMessagingInterface {
@@ -7,13 +8,15 @@ pub trait AxelarGatewayMessagingInterface {
     /// This function is the entry point for general message passing between chains.
     ///
     /// A registered chain name on Axelar must be used for `destination_chain`.
+    ///
+    /// Fails if the gateway is paused.
     fn call_contract(
         env: Env,
         caller: Address,
         destination_chain: String,
         destination_address: String,
         payload: Bytes,
-    );
+    ) -> Result<(), ContractError>;
 
     /// Checks if a message is approved
     ///
@@ -46,4 +49,20 @@ pub trait AxelarGatewayMessagingInterface {
         source_address: String,
         payload_hash: BytesN<32>,
     ) -> bool;
+
+    /// Validates if a message is part of an approved batch of messages. If the message was not executed yet,
+    /// status is updated to executed to avoid replay.
+    ///
+    /// `merkle_proof` contains the sibling hashes from the message leaf up to an approved Merkle root.
+    ///
+    /// `caller` must be the intended `destination_address` of the contract call for validation to succeed.
+    fn validate_message_with_proof(
+        env: Env,
+        caller: Address,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload_hash: BytesN<32>,
+        merkle_proof: Vec<BytesN<32>>,
+    ) -> bool;
 }
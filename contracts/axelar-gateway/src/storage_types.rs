@@ -1,3 +1,4 @@
+use axelar_soroban_std::contractstorage;
 use soroban_sdk::{contracttype, BytesN, String};
 
 #[contracttype]
@@ -15,17 +16,46 @@ pub enum MessageApprovalValue {
     Executed,
 }
 
-#[contracttype]
+#[contractstorage]
 #[derive(Clone, Debug)]
 pub enum DataKey {
     /// Gateway
-    MessageApproval(MessageApprovalKey),
+    #[persistent]
+    #[value(MessageApprovalValue)]
+    MessageApproval {
+        message_approval_key: MessageApprovalKey,
+    },
+
+    #[persistent]
+    #[status]
+    MessagesMerkleRoot { merkle_root: BytesN<32> },
+
     /// Auth Module
+    #[instance]
+    #[value(u64)]
     PreviousSignerRetention,
+
+    #[instance]
+    #[value(BytesN<32>)]
     DomainSeparator,
+
+    #[instance]
+    #[value(u64)]
     MinimumRotationDelay,
+
+    #[instance]
+    #[value(u64)]
     Epoch,
+
+    #[instance]
+    #[value(u64)]
     LastRotationTimestamp,
-    SignersHashByEpoch(u64),
-    EpochBySignersHash(BytesN<32>),
+
+    #[persistent]
+    #[value(BytesN<32>)]
+    SignersHashByEpoch { epoch: u64 },
+
+    #[persistent]
+    #[value(u64)]
+    EpochBySignersHash { signers_hash: BytesN<32> },
 }
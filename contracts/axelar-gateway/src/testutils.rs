@@ -1,9 +1,11 @@
 extern crate std;
 
 use crate::auth::{self, epoch};
+use crate::merkle;
 use crate::{AxelarGateway, AxelarGatewayClient};
 use axelar_soroban_std::{assert_last_emitted_event, assert_ok};
-use ed25519_dalek::{Signature, Signer, SigningKey};
+use ed25519_dalek::Signer as _;
+use rand::rngs::ThreadRng;
 use rand::Rng;
 
 use soroban_sdk::Symbol;
@@ -11,7 +13,8 @@ use soroban_sdk::{testutils::Address as _, Address};
 use soroban_sdk::{testutils::BytesN as _, vec, xdr::ToXdr, Bytes, BytesN, Env, String, Vec};
 
 use crate::types::{
-    CommandType, Message, Proof, ProofSignature, ProofSigner, WeightedSigner, WeightedSigners,
+    CommandType, Message, Proof, ProofSignature, ProofSigner, PublicKey, Signature, WeightedSigner,
+    WeightedSigners,
 };
 
 use axelar_soroban_std::traits::IntoVec;
@@ -19,9 +22,64 @@ use axelar_soroban_std::traits::IntoVec;
 const DESTINATION_CHAIN: &str = "ethereum";
 const DESTINATION_ADDRESS: &str = "0x4EFE356BEDeCC817cb89B4E9b796dB8bC188DC59";
 
+#[derive(Clone, Debug)]
+pub enum TestSigningKey {
+    Ed25519(ed25519_dalek::SigningKey),
+    Secp256k1(k256::ecdsa::SigningKey),
+}
+
+impl TestSigningKey {
+    pub fn generate_ed25519(rng: &mut ThreadRng) -> Self {
+        Self::Ed25519(ed25519_dalek::SigningKey::generate(rng))
+    }
+
+    pub fn generate_secp256k1(rng: &mut ThreadRng) -> Self {
+        Self::Secp256k1(k256::ecdsa::SigningKey::random(rng))
+    }
+
+    pub fn public_key(&self, env: &Env) -> PublicKey {
+        match self {
+            Self::Ed25519(signing_key) => PublicKey::Ed25519(BytesN::from_array(
+                env,
+                &signing_key.verifying_key().to_bytes(),
+            )),
+            Self::Secp256k1(signing_key) => {
+                let encoded_point = signing_key.verifying_key().to_encoded_point(true);
+                PublicKey::Secp256k1(BytesN::from_array(
+                    env,
+                    encoded_point
+                        .as_bytes()
+                        .try_into()
+                        .expect("compressed secp256k1 key must be 33 bytes"),
+                ))
+            }
+        }
+    }
+
+    pub fn sign(&self, env: &Env, msg_hash: &[u8; 32]) -> Signature {
+        match self {
+            Self::Ed25519(signing_key) => {
+                let signature: ed25519_dalek::Signature = signing_key.sign(msg_hash);
+                Signature::Ed25519(BytesN::from_array(env, &signature.to_bytes()))
+            }
+            Self::Secp256k1(signing_key) => {
+                let (signature, recovery_id) = signing_key
+                    .sign_prehash_recoverable(msg_hash)
+                    .expect("failed to sign with secp256k1 key");
+
+                let mut recoverable_signature = [0u8; 65];
+                recoverable_signature[..64].copy_from_slice(&signature.to_bytes());
+                recoverable_signature[64] = recovery_id.to_byte();
+
+                Signature::Secp256k1(BytesN::from_array(env, &recoverable_signature))
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct TestSignerSet {
-    pub signer_keys: std::vec::Vec<SigningKey>,
+    pub signer_keys: std::vec::Vec<TestSigningKey>,
     pub signers: WeightedSigners,
     pub domain_separator: BytesN<32>,
 }
@@ -59,6 +117,48 @@ pub fn get_approve_hash(env: &Env, messages: Vec<Message>) -> BytesN<32> {
         .into()
 }
 
+pub fn get_merkle_root_approve_hash(env: &Env, merkle_root: BytesN<32>) -> BytesN<32> {
+    env.crypto()
+        .keccak256(&(CommandType::ApproveMessagesMerkleRoot, merkle_root).to_xdr(env))
+        .into()
+}
+
+/// Builds a Merkle tree over the given messages.
+/// Returns the root along with the inclusion proof for each message, in the same order as the messages.
+pub fn generate_merkle_tree(
+    env: &Env,
+    messages: &[Message],
+) -> (BytesN<32>, std::vec::Vec<Vec<BytesN<32>>>) {
+    let mut level: std::vec::Vec<BytesN<32>> = messages
+        .iter()
+        .map(|message| merkle::leaf_hash(env, message))
+        .collect();
+    let mut positions: std::vec::Vec<usize> = (0..level.len()).collect();
+    let mut proofs: std::vec::Vec<Vec<BytesN<32>>> =
+        messages.iter().map(|_| Vec::new(env)).collect();
+
+    while level.len() > 1 {
+        for (position, proof) in positions.iter_mut().zip(proofs.iter_mut()) {
+            if let Some(sibling) = level.get(*position ^ 1) {
+                proof.push_back(sibling.clone());
+            }
+            *position /= 2;
+        }
+
+        // an unpaired node is promoted to the next level as is
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => merkle::hash_pair(env, a, b),
+                [a] => a.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+
+    (level.pop().expect("no messages provided"), proofs)
+}
+
 pub fn generate_test_message(env: &Env) -> (Message, Bytes) {
     let mut rng = rand::thread_rng();
     let len = rng.gen_range(0..20);
@@ -87,30 +187,52 @@ pub fn generate_signers_set(
     env: &Env,
     num_signers: u32,
     domain_separator: BytesN<32>,
+) -> TestSignerSet {
+    generate_signers_set_with_keys(env, num_signers, domain_separator, |rng| {
+        TestSigningKey::generate_ed25519(rng)
+    })
+}
+
+/// Generate a signer set where each signer randomly uses either an Ed25519 or a secp256k1 key.
+pub fn generate_mixed_signers_set(
+    env: &Env,
+    num_signers: u32,
+    domain_separator: BytesN<32>,
+) -> TestSignerSet {
+    generate_signers_set_with_keys(env, num_signers, domain_separator, |rng| {
+        if rng.gen_bool(0.5) {
+            TestSigningKey::generate_ed25519(rng)
+        } else {
+            TestSigningKey::generate_secp256k1(rng)
+        }
+    })
+}
+
+pub fn generate_signers_set_with_keys(
+    env: &Env,
+    num_signers: u32,
+    domain_separator: BytesN<32>,
+    generate_key: impl Fn(&mut ThreadRng) -> TestSigningKey,
 ) -> TestSignerSet {
     let mut rng = rand::thread_rng();
 
     let mut signer_keypair: std::vec::Vec<_> = (0..num_signers)
         .map(|_| {
-            let signing_key = SigningKey::generate(&mut rng);
+            let signing_key = generate_key(&mut rng);
             let weight = rng.gen_range(1..10) as u128;
             (signing_key, weight)
         })
         .collect();
 
-    // Sort signers by public key
-    signer_keypair.sort_by(|a, b| {
-        a.0.verifying_key()
-            .to_bytes()
-            .cmp(&b.0.verifying_key().to_bytes())
-    });
+    // Sort signers by signature scheme and public key
+    signer_keypair.sort_by_key(|(signing_key, _)| signing_key.public_key(env));
 
     let total_weight = signer_keypair.iter().map(|(_, w)| w).sum::<u128>();
 
     let signer_vec: std::vec::Vec<WeightedSigner> = signer_keypair
         .iter()
         .map(|(signing_key, w)| WeightedSigner {
-            signer: BytesN::<32>::from_array(env, &signing_key.verifying_key().to_bytes()),
+            signer: signing_key.public_key(env),
             weight: *w,
         })
         .collect();
@@ -158,13 +280,9 @@ pub fn generate_proof(env: &Env, data_hash: BytesN<32>, signer_set: TestSignerSe
                 };
             }
 
-            let signature: Signature = signing_key.sign(&msg_hash.to_array());
             ProofSigner {
                 signer: weighted_signer,
-                signature: ProofSignature::Signed(BytesN::<64>::from_array(
-                    env,
-                    &signature.to_bytes(),
-                )),
+                signature: ProofSignature::Signed(signing_key.sign(env, &msg_hash.to_array())),
             }
         })
         .collect();
@@ -1,9 +1,21 @@
 use soroban_sdk::{contracttype, xdr::ToXdr, Address, BytesN, Env, String, Vec};
 
+/// `PublicKey` represents the public key of a signer, tagged with its signature scheme.
+///
+/// The derived ordering compares the scheme first and the key bytes second,
+/// i.e. all Ed25519 keys are ordered before all secp256k1 keys.
+/// Signers within a [`WeightedSigners`] set must be sorted in this order.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PublicKey {
+    Ed25519(BytesN<32>),
+    Secp256k1(BytesN<33>), // SEC1 compressed secp256k1 public key
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct WeightedSigner {
-    pub signer: BytesN<32>, // Ed25519 public key
+    pub signer: PublicKey,
     pub weight: u128,
 }
 
@@ -21,10 +33,18 @@ pub struct WeightedSigners {
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ProofSignature {
-    Signed(BytesN<64>), // Ed25519 signature
+    Signed(Signature),
     Unsigned,
 }
 
+/// `Signature` represents a signature created with the scheme of the corresponding [`PublicKey`].
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Signature {
+    Ed25519(BytesN<64>),
+    Secp256k1(BytesN<65>), // Recoverable ECDSA signature in `r || s || v` format
+}
+
 /// `ProofSigner` represents a signer in a proof.
 ///
 /// If the signer submitted a signature, and if it is being included in the proof to meet the threshold,
@@ -52,6 +72,7 @@ pub struct Proof {
 pub enum CommandType {
     ApproveMessages,
     RotateSigners,
+    ApproveMessagesMerkleRoot,
 }
 
 #[contracttype]
@@ -65,6 +86,10 @@ pub struct Message {
 }
 
 impl WeightedSigners {
+    /// Returns the hash of the signer set.
+    ///
+    /// Every signer is hashed together with its scheme tag, so identical key bytes
+    /// under different schemes result in different signer set hashes.
     pub fn hash(&self, env: &Env) -> BytesN<32> {
         env.crypto().keccak256(&self.clone().to_xdr(env)).into()
     }
@@ -95,7 +120,7 @@ impl Proof {
 
 #[cfg(test)]
 mod test {
-    use crate::types::{CommandType, Message, WeightedSigner, WeightedSigners};
+    use crate::types::{CommandType, Message, PublicKey, WeightedSigner, WeightedSigners};
     use hex_literal::hex;
     use soroban_sdk::{xdr::ToXdr, Address, BytesN, Env, String, Vec};
 
@@ -105,38 +130,38 @@ mod test {
 
         let signers = [
             WeightedSigner {
-                signer: BytesN::<32>::from_array(
+                signer: PublicKey::Ed25519(BytesN::<32>::from_array(
                     &env,
                     &hex!("0a245a2a2a5e8ec439d1377579a08fc78ea55647ba6fcb1f5d8a360218e8a985"),
-                ),
+                )),
                 weight: 3,
             },
             WeightedSigner {
-                signer: BytesN::<32>::from_array(
+                signer: PublicKey::Ed25519(BytesN::<32>::from_array(
                     &env,
                     &hex!("0b422cf449d900f6f8eb97f62e35811c62eb75feb84dfccef44a5c1c3dbac2ad"),
-                ),
+                )),
                 weight: 2,
             },
             WeightedSigner {
-                signer: BytesN::<32>::from_array(
+                signer: PublicKey::Ed25519(BytesN::<32>::from_array(
                     &env,
                     &hex!("18c34bf01a11b5ba21ea11b1678f3035ef753f0bdb1d5014ec21037e8f99e2a2"),
-                ),
+                )),
                 weight: 4,
             },
             WeightedSigner {
-                signer: BytesN::<32>::from_array(
+                signer: PublicKey::Ed25519(BytesN::<32>::from_array(
                     &env,
                     &hex!("f683ca8a6d7fe55f25599bb64b01edcc5eeb85fe5b63d3a4f0b3c32405005518"),
-                ),
+                )),
                 weight: 4,
             },
             WeightedSigner {
-                signer: BytesN::<32>::from_array(
+                signer: PublicKey::Ed25519(BytesN::<32>::from_array(
                     &env,
                     &hex!("fbb4b870e800038f1379697fae3058938c59b696f38dd0fdf2659c0cf3a5b663"),
-                ),
+                )),
                 weight: 2,
             },
         ];
@@ -156,6 +181,56 @@ mod test {
         goldie::assert_json!(vec![hex::encode(hash), hex::encode(signers_rotation_hash)]);
     }
 
+    #[test]
+    fn weighted_signers_hash_mixed_schemes() {
+        let env = Env::default();
+
+        let signers = [
+            WeightedSigner {
+                signer: PublicKey::Ed25519(BytesN::<32>::from_array(
+                    &env,
+                    &hex!("0a245a2a2a5e8ec439d1377579a08fc78ea55647ba6fcb1f5d8a360218e8a985"),
+                )),
+                weight: 3,
+            },
+            WeightedSigner {
+                signer: PublicKey::Ed25519(BytesN::<32>::from_array(
+                    &env,
+                    &hex!("fbb4b870e800038f1379697fae3058938c59b696f38dd0fdf2659c0cf3a5b663"),
+                )),
+                weight: 2,
+            },
+            WeightedSigner {
+                signer: PublicKey::Secp256k1(BytesN::<33>::from_array(
+                    &env,
+                    &hex!("02d1e0cff63aa3e7988e4070242fa37871a9abc79ecf851cce9877297d1316a090"),
+                )),
+                weight: 4,
+            },
+            WeightedSigner {
+                signer: PublicKey::Secp256k1(BytesN::<33>::from_array(
+                    &env,
+                    &hex!("03a59cff7d3b8ac2f8d5f3d8b3a9b7a11ea98d1c32d6e2cc9c2c8b8b5e9f1d1a04"),
+                )),
+                weight: 4,
+            },
+        ];
+
+        let weighted_signers = WeightedSigners {
+            signers: Vec::from_array(&env, signers),
+            threshold: 8u128,
+            nonce: BytesN::<32>::from_array(
+                &env,
+                &hex!("8784bf7be5a9baaeea47e12d9e8ad0dec29afcbc3617d97f771e3c24fa945dce"),
+            ),
+        };
+
+        let hash = weighted_signers.hash(&env).to_array();
+        let signers_rotation_hash = weighted_signers.signers_rotation_hash(&env).to_array();
+
+        goldie::assert_json!(vec![hex::encode(hash), hex::encode(signers_rotation_hash)]);
+    }
+
     #[test]
     fn messages_approval_hash() {
         let env = Env::default();
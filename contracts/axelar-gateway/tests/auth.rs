@@ -1,6 +1,11 @@
 use axelar_gateway::error::ContractError;
-use axelar_gateway::testutils::{generate_proof, generate_signers_set, randint};
-use axelar_gateway::types::{ProofSignature, ProofSigner, WeightedSigner, WeightedSigners};
+use axelar_gateway::testutils::{
+    generate_mixed_signers_set, generate_proof, generate_signers_set,
+    generate_signers_set_with_keys, randint, TestSigningKey,
+};
+use axelar_gateway::types::{
+    ProofSignature, ProofSigner, PublicKey, Signature, WeightedSigner, WeightedSigners,
+};
 use axelar_gateway::AxelarGateway;
 use axelar_soroban_std::{assert_contract_err, assert_invoke_auth_ok};
 use soroban_sdk::{
@@ -135,6 +140,80 @@ fn fail_validate_proof_threshold_overflow() {
     );
 }
 
+#[test]
+fn validate_proof_with_mixed_signers() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let new_signers =
+        generate_mixed_signers_set(&env, randint(1, 10), signers.domain_separator.clone());
+
+    let data_hash = new_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+    assert_invoke_auth_ok!(
+        client.operator(),
+        client.try_rotate_signers(&new_signers.signers, &proof, &true)
+    );
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let proof = generate_proof(&env, msg_hash.clone(), new_signers);
+
+    assert!(client.validate_proof(&msg_hash, &proof));
+}
+
+#[test]
+fn fail_validate_proof_invalid_secp256k1_signature() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let new_signers = generate_signers_set_with_keys(
+        &env,
+        randint(1, 10),
+        signers.domain_separator.clone(),
+        TestSigningKey::generate_secp256k1,
+    );
+
+    let data_hash = new_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+    assert_invoke_auth_ok!(
+        client.operator(),
+        client.try_rotate_signers(&new_signers.signers, &proof, &true)
+    );
+
+    let proof_hash: BytesN<32> = BytesN::random(&env);
+    let proof = generate_proof(&env, proof_hash, new_signers);
+    let random_hash: BytesN<32> = BytesN::random(&env);
+
+    assert_contract_err!(
+        client.try_validate_proof(&random_hash, &proof),
+        ContractError::InvalidSignatures
+    );
+}
+
+#[test]
+fn fail_validate_proof_signature_scheme_mismatch() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let mut proof = generate_proof(&env, msg_hash.clone(), signers);
+
+    let mut new_signers = Vec::new(&env);
+    for ProofSigner { signer, signature } in proof.signers.iter() {
+        let signature = match signature {
+            ProofSignature::Signed(_) => {
+                ProofSignature::Signed(Signature::Secp256k1(BytesN::from_array(&env, &[1u8; 65])))
+            }
+            ProofSignature::Unsigned => ProofSignature::Unsigned,
+        };
+
+        new_signers.push_back(ProofSigner { signer, signature });
+    }
+    proof.signers = new_signers;
+
+    assert_contract_err!(
+        client.try_validate_proof(&msg_hash, &proof),
+        ContractError::InvalidSignatures
+    );
+}
+
 #[test]
 fn rotate_signers_fail_empty_signers() {
     let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
@@ -278,6 +357,28 @@ fn rotate_signers_fail_wrong_signer_order() {
     )
 }
 
+#[test]
+fn rotate_signers_fail_secp256k1_signer_before_ed25519_signer() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+
+    let mut new_signers = generate_signers_set(&env, randint(1, 10), BytesN::random(&env));
+
+    new_signers.signers.signers.push_front(WeightedSigner {
+        signer: PublicKey::Secp256k1(BytesN::from_array(&env, &[2u8; 33])),
+        weight: 1,
+    });
+
+    let data_hash = new_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_rotate_signers(&new_signers.signers, &proof, &true),
+        ContractError::InvalidSigners
+    )
+}
+
 #[test]
 fn rotate_signers_fail_duplicated_signers() {
     let (env, signers, client) = setup_env(1, randint(1, 10));
@@ -1,17 +1,20 @@
 use axelar_gateway::error::ContractError;
 #[cfg(any(test, feature = "testutils"))]
 use axelar_gateway::testutils::{
-    generate_proof, generate_signers_set, generate_test_message, get_approve_hash, randint,
+    generate_merkle_tree, generate_proof, generate_signers_set, generate_test_message,
+    get_approve_hash, get_merkle_root_approve_hash, randint,
 };
 use axelar_gateway::types::Message;
+use axelar_soroban_std::interfaces::{ApprovedWasm, WasmRegistryInterface};
+use axelar_soroban_std::ttl::LEDGERS_PER_DAY;
 use axelar_soroban_std::{
     assert_contract_err, assert_invocation, assert_invoke_auth_err, assert_invoke_auth_ok,
     assert_last_emitted_event,
 };
 use soroban_sdk::{
-    bytes,
-    testutils::{Address as _, Events},
-    vec, Address, BytesN, String, Symbol,
+    bytes, contract, contractimpl,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, BytesN, Env, String, Symbol,
 };
 
 mod utils;
@@ -61,6 +64,39 @@ fn call_contract() {
     );
 }
 
+#[test]
+fn call_contract_fails_when_paused() {
+    let (env, _signers, client) = setup_env(1, 5);
+
+    let user: Address = Address::generate(&env);
+    let destination_chain = String::from_str(&env, DESTINATION_CHAIN);
+    let destination_address = String::from_str(&env, DESTINATION_ADDRESS);
+    let payload = bytes!(&env, 0x1234);
+
+    assert_invoke_auth_ok!(client.operator(), client.try_pause());
+    assert!(client.paused());
+
+    assert_contract_err!(
+        client.try_call_contract(&user, &destination_chain, &destination_address, &payload),
+        ContractError::ContractPaused
+    );
+
+    assert_invoke_auth_ok!(client.operator(), client.try_unpause());
+
+    assert_invoke_auth_ok!(
+        user,
+        client.try_call_contract(&user, &destination_chain, &destination_address, &payload)
+    );
+}
+
+#[test]
+fn pause_unauthorized() {
+    let (_env, _, client) = setup_env(1, randint(1, 10));
+
+    assert_invoke_auth_err!(client.owner(), client.try_pause());
+    assert!(!client.paused());
+}
+
 #[test]
 fn validate_message() {
     let (env, _signers, client) = setup_env(1, 5);
@@ -173,6 +209,99 @@ fn approve_message() {
     assert!(is_executed);
 }
 
+#[test]
+fn approved_message_outlives_minimum_ttl() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+
+    let messages = vec![&env, message.clone()];
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers.clone());
+
+    client.approve_messages(&messages, &proof);
+
+    env.ledger()
+        .with_mut(|ledger| ledger.sequence_number += 30 * LEDGERS_PER_DAY);
+
+    assert!(client.is_message_approved(
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.contract_address,
+        &message.payload_hash,
+    ));
+    assert_eq!(
+        client.epoch_by_signers_hash(&signers.signers.hash(&env)),
+        client.epoch()
+    );
+}
+
+#[test]
+fn extend_message_approval_ttl_keeps_message_approved() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+
+    let messages = vec![&env, message.clone()];
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+
+    client.approve_messages(&messages, &proof);
+
+    for _ in 0..2 {
+        env.ledger()
+            .with_mut(|ledger| ledger.sequence_number += 50 * LEDGERS_PER_DAY);
+
+        client.extend_message_approval_ttl(&message.source_chain, &message.message_id);
+    }
+
+    assert!(client.is_message_approved(
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.contract_address,
+        &message.payload_hash,
+    ));
+}
+
+#[test]
+fn extend_message_approval_ttl_fails_if_message_not_approved() {
+    let (env, _, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+
+    assert_contract_err!(
+        client.try_extend_message_approval_ttl(&message.source_chain, &message.message_id),
+        ContractError::MessageApprovalNotFound
+    );
+}
+
+#[test]
+fn extend_signers_ttl_keeps_signers_valid() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let epoch = client.epoch();
+
+    for _ in 0..2 {
+        env.ledger()
+            .with_mut(|ledger| ledger.sequence_number += 50 * LEDGERS_PER_DAY);
+
+        client.extend_signers_ttl(&epoch);
+    }
+
+    assert_eq!(
+        client.epoch_by_signers_hash(&signers.signers.hash(&env)),
+        epoch
+    );
+}
+
+#[test]
+fn extend_signers_ttl_fails_with_invalid_epoch() {
+    let (_, _, client) = setup_env(1, randint(1, 10));
+
+    assert_contract_err!(
+        client.try_extend_signers_ttl(&(client.epoch() + 1)),
+        ContractError::InvalidEpoch
+    );
+}
+
 #[test]
 fn fail_execute_invalid_proof() {
     let (env, signers, client) = setup_env(1, randint(1, 10));
@@ -220,6 +349,217 @@ fn approve_messages_skip_duplicate_message() {
     assert_eq!(env.events().all().len(), prev_event_count);
 }
 
+fn generate_test_messages(env: &soroban_sdk::Env, num_messages: u32) -> std::vec::Vec<Message> {
+    (0..num_messages)
+        .map(|i| {
+            let (mut message, _) = generate_test_message(env);
+            message.message_id = String::from_str(env, &std::format!("test-{}", i));
+            message
+        })
+        .collect()
+}
+
+#[test]
+fn approve_messages_merkle_root() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let messages = generate_test_messages(&env, randint(1, 20));
+    let (merkle_root, merkle_proofs) = generate_merkle_tree(&env, &messages);
+
+    let data_hash = get_merkle_root_approve_hash(&env, merkle_root.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+
+    client.approve_messages_merkle_root(&merkle_root, &proof);
+
+    assert_last_emitted_event(
+        &env,
+        &client.address,
+        (
+            Symbol::new(&env, "messages_merkle_root_approved"),
+            merkle_root.clone(),
+        ),
+        (),
+    );
+    assert!(client.is_messages_merkle_root_approved(&merkle_root));
+
+    for (message, merkle_proof) in messages.into_iter().zip(merkle_proofs) {
+        let Message {
+            source_chain,
+            message_id,
+            source_address,
+            contract_address,
+            payload_hash,
+        } = message.clone();
+
+        let approved = assert_invoke_auth_ok!(
+            contract_address,
+            client.try_validate_message_with_proof(
+                &contract_address,
+                &source_chain,
+                &message_id,
+                &source_address,
+                &payload_hash,
+                &merkle_proof,
+            )
+        );
+        assert!(approved);
+
+        assert_last_emitted_event(
+            &env,
+            &client.address,
+            (Symbol::new(&env, "message_executed"), message),
+            (),
+        );
+        assert!(client.is_message_executed(&source_chain, &message_id));
+
+        // replaying the same message fails
+        let approved = assert_invoke_auth_ok!(
+            contract_address,
+            client.try_validate_message_with_proof(
+                &contract_address,
+                &source_chain,
+                &message_id,
+                &source_address,
+                &payload_hash,
+                &merkle_proof,
+            )
+        );
+        assert!(!approved);
+    }
+}
+
+#[test]
+fn extend_messages_merkle_root_ttl_keeps_root_approved() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let messages = generate_test_messages(&env, randint(1, 20));
+    let (merkle_root, _) = generate_merkle_tree(&env, &messages);
+
+    let data_hash = get_merkle_root_approve_hash(&env, merkle_root.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+    client.approve_messages_merkle_root(&merkle_root, &proof);
+
+    for _ in 0..2 {
+        env.ledger()
+            .with_mut(|ledger| ledger.sequence_number += 50 * LEDGERS_PER_DAY);
+
+        client.extend_messages_merkle_root_ttl(&merkle_root);
+    }
+
+    assert!(client.is_messages_merkle_root_approved(&merkle_root));
+}
+
+#[test]
+fn extend_messages_merkle_root_ttl_fails_if_root_not_approved() {
+    let (env, _, client) = setup_env(1, randint(1, 10));
+    let (merkle_root, _) = generate_merkle_tree(&env, &generate_test_messages(&env, 2));
+
+    assert_contract_err!(
+        client.try_extend_messages_merkle_root_ttl(&merkle_root),
+        ContractError::MessagesMerkleRootNotFound
+    );
+}
+
+#[test]
+fn approve_messages_merkle_root_skip_duplicate_root() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let messages = generate_test_messages(&env, randint(1, 20));
+    let (merkle_root, _) = generate_merkle_tree(&env, &messages);
+
+    let data_hash = get_merkle_root_approve_hash(&env, merkle_root.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+    client.approve_messages_merkle_root(&merkle_root, &proof);
+
+    let prev_event_count = env.events().all().len();
+    assert!(client
+        .try_approve_messages_merkle_root(&merkle_root, &proof)
+        .is_ok());
+
+    assert_eq!(env.events().all().len(), prev_event_count);
+}
+
+#[test]
+fn approve_messages_merkle_root_fail_invalid_proof() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let messages = generate_test_messages(&env, randint(1, 20));
+    let (merkle_root, _) = generate_merkle_tree(&env, &messages);
+
+    let invalid_signers = generate_signers_set(&env, randint(1, 10), signers.domain_separator);
+
+    let data_hash = get_merkle_root_approve_hash(&env, merkle_root.clone());
+    let proof = generate_proof(&env, data_hash, invalid_signers);
+
+    assert_contract_err!(
+        client.try_approve_messages_merkle_root(&merkle_root, &proof),
+        ContractError::InvalidSignersHash
+    );
+}
+
+#[test]
+fn validate_message_with_proof_fails_invalid_merkle_proof() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let messages = generate_test_messages(&env, randint(2, 20));
+    let (merkle_root, merkle_proofs) = generate_merkle_tree(&env, &messages);
+
+    let data_hash = get_merkle_root_approve_hash(&env, merkle_root.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+    client.approve_messages_merkle_root(&merkle_root, &proof);
+
+    let Message {
+        source_chain,
+        message_id,
+        source_address,
+        contract_address,
+        payload_hash,
+    } = messages[0].clone();
+
+    // proof of a different message
+    let merkle_proof = merkle_proofs[1].clone();
+
+    let approved = assert_invoke_auth_ok!(
+        contract_address,
+        client.try_validate_message_with_proof(
+            &contract_address,
+            &source_chain,
+            &message_id,
+            &source_address,
+            &payload_hash,
+            &merkle_proof,
+        )
+    );
+    assert!(!approved);
+    assert!(!client.is_message_executed(&source_chain, &message_id));
+}
+
+#[test]
+fn validate_message_with_proof_fails_unapproved_merkle_root() {
+    let (env, _, client) = setup_env(1, randint(1, 10));
+    let messages = generate_test_messages(&env, randint(1, 20));
+    let (_, merkle_proofs) = generate_merkle_tree(&env, &messages);
+
+    let Message {
+        source_chain,
+        message_id,
+        source_address,
+        contract_address,
+        payload_hash,
+    } = messages[0].clone();
+
+    let prev_event_count = env.events().all().len();
+
+    let approved = assert_invoke_auth_ok!(
+        contract_address,
+        client.try_validate_message_with_proof(
+            &contract_address,
+            &source_chain,
+            &message_id,
+            &source_address,
+            &payload_hash,
+            &merkle_proofs[0],
+        )
+    );
+    assert!(!approved);
+    assert_eq!(env.events().all().len(), prev_event_count);
+}
+
 #[test]
 fn rotate_signers() {
     let (env, signers, client) = setup_env(1, 5);
@@ -327,30 +667,27 @@ fn rotate_signers_fail_not_latest_signers() {
 }
 
 #[test]
-fn transfer_operatorship_unauthorized() {
+fn propose_operatorship_unauthorized() {
     let (env, _, client) = setup_env(1, randint(1, 10));
     let not_operator = Address::generate(&env);
 
     assert_invoke_auth_err!(
         client.owner(),
-        client.try_transfer_operatorship(&client.owner())
-    );
-    assert_invoke_auth_err!(
-        not_operator,
-        client.try_transfer_operatorship(&not_operator)
+        client.try_propose_operatorship(&client.owner())
     );
+    assert_invoke_auth_err!(not_operator, client.try_propose_operatorship(&not_operator));
 }
 
 #[test]
-fn transfer_ownership_unauthorized() {
+fn propose_ownership_unauthorized() {
     let (env, _, client) = setup_env(1, randint(1, 10));
 
     let new_owner = Address::generate(&env);
 
-    assert_invoke_auth_err!(new_owner, client.try_transfer_ownership(&new_owner));
+    assert_invoke_auth_err!(new_owner, client.try_propose_ownership(&new_owner));
     assert_invoke_auth_err!(
         client.operator(),
-        client.try_transfer_ownership(&client.operator())
+        client.try_propose_ownership(&client.operator())
     );
 }
 
@@ -423,11 +760,39 @@ fn version() {
     );
 }
 
+/// Registry that approves every WASM hash for the gateway.
+#[contract]
+struct WasmRegistry;
+
+#[contractimpl]
+impl WasmRegistryInterface for WasmRegistry {
+    fn approved_wasm(env: &Env, _wasm_hash: BytesN<32>) -> Option<ApprovedWasm> {
+        Some(ApprovedWasm {
+            contract_kind: String::from_str(env, "axelar-gateway"),
+            version: String::from_str(env, "0.2.0"),
+        })
+    }
+}
+
+#[test]
+fn upgrade_fails_if_wasm_hash_not_approved() {
+    let (env, _, client) = setup_env(1, randint(1, 10));
+
+    let new_wasm_hash = BytesN::<32>::from_array(&env, &[0; 32]);
+    assert_eq!(
+        client.mock_all_auths().try_upgrade(&new_wasm_hash),
+        Err(Ok(ContractError::UnapprovedWasmHash.into()))
+    );
+}
+
 #[test]
 #[should_panic(expected = "HostError: Error(Storage, MissingValue)")]
 fn upgrade_invalid_wasm_hash() {
     let (env, _, client) = setup_env(1, randint(1, 10));
 
+    let registry = env.register(WasmRegistry, ());
+    client.mock_all_auths().set_wasm_registry(&registry);
+
     let new_wasm_hash = BytesN::<32>::from_array(&env, &[0; 32]);
     client.mock_all_auths().upgrade(&new_wasm_hash);
 }
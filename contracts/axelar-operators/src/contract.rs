@@ -1,12 +1,14 @@
 use crate::error::ContractError;
-use crate::event;
+use crate::event::{OperatorAddedEvent, OperatorRemovedEvent};
 use crate::storage_types::DataKey;
+use axelar_soroban_std::events::Event;
 use axelar_soroban_std::ttl::extend_instance_ttl;
 use axelar_soroban_std::{ensure, interfaces, Ownable, Upgradable};
 use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Val, Vec};
 
 #[contract]
 #[derive(Ownable, Upgradable)]
+#[upgradable(wasm_registry)]
 pub struct AxelarOperators;
 
 #[contractimpl]
@@ -39,7 +41,7 @@ impl AxelarOperators {
 
         extend_instance_ttl(&env);
 
-        event::add_operator(&env, account);
+        OperatorAddedEvent { operator: account }.emit(&env);
         Ok(())
     }
 
@@ -58,7 +60,7 @@ impl AxelarOperators {
 
         env.storage().instance().remove(&key);
 
-        event::remove_operator(&env, account);
+        OperatorRemovedEvent { operator: account }.emit(&env);
         Ok(())
     }
 
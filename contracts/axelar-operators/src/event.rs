@@ -1,11 +1,14 @@
-use soroban_sdk::{Address, Env, Symbol};
+use axelar_soroban_std::IntoEvent;
+use soroban_sdk::Address;
 
-pub fn add_operator(env: &Env, operator: Address) {
-    let topics = (Symbol::new(env, "operator_added"), operator);
-    env.events().publish(topics, ());
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct OperatorAddedEvent {
+    #[topic]
+    pub operator: Address,
 }
 
-pub fn remove_operator(env: &Env, operator: Address) {
-    let topics = (Symbol::new(env, "operator_removed"), operator);
-    env.events().publish(topics, ());
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct OperatorRemovedEvent {
+    #[topic]
+    pub operator: Address,
 }
@@ -1,32 +1,43 @@
-use crate::event;
+use crate::event::ExecutedEvent;
 use axelar_gas_service::AxelarGasServiceClient;
 use axelar_gateway::AxelarGatewayMessagingClient;
+use axelar_soroban_std::events::Event;
 use axelar_soroban_std::types::Token;
 use soroban_sdk::{contract, contractimpl, Address, Bytes, Env, String};
 
 use crate::storage_types::DataKey;
 
-use axelar_gateway::executable::AxelarExecutableInterface;
+use axelar_gateway::executable::CustomAxelarExecutable;
+use axelar_gateway::impl_axelar_executable;
 
 #[contract]
 pub struct Example;
 
-#[contractimpl]
-impl AxelarExecutableInterface for Example {
-    fn gateway(env: &Env) -> Address {
+impl_axelar_executable!(Example);
+
+impl CustomAxelarExecutable for Example {
+    type Error = soroban_sdk::Error;
+
+    fn __gateway(env: &Env) -> Address {
         env.storage().instance().get(&DataKey::Gateway).unwrap()
     }
 
-    fn execute(
-        env: Env,
+    fn execute_payload(
+        env: &Env,
         source_chain: String,
         message_id: String,
         source_address: String,
         payload: Bytes,
-    ) {
-        let _ = Self::validate_message(&env, &source_chain, &message_id, &source_address, &payload);
+    ) -> Result<(), Self::Error> {
+        ExecutedEvent {
+            source_chain,
+            message_id,
+            source_address,
+            payload,
+        }
+        .emit(env);
 
-        event::executed(&env, source_chain, message_id, source_address, payload);
+        Ok(())
     }
 }
 
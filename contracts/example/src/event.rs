@@ -1,17 +1,14 @@
-use soroban_sdk::{Bytes, Env, String, Symbol};
+use axelar_soroban_std::IntoEvent;
+use soroban_sdk::{Bytes, String};
 
-pub fn executed(
-    env: &Env,
-    source_chain: String,
-    message_id: String,
-    source_address: String,
-    payload: Bytes,
-) {
-    let topics = (
-        Symbol::new(env, "executed"),
-        source_chain,
-        message_id,
-        source_address,
-    );
-    env.events().publish(topics, (payload,));
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct ExecutedEvent {
+    #[topic]
+    pub source_chain: String,
+    #[topic]
+    pub message_id: String,
+    #[topic]
+    pub source_address: String,
+    #[data]
+    pub payload: Bytes,
 }
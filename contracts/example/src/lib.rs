@@ -1,7 +1,10 @@
 #![no_std]
 
+#[cfg(any(test, feature = "testutils"))]
+extern crate std;
+
 mod contract;
-mod event;
+pub mod event;
 mod storage_types;
 
 pub use contract::{Example, ExampleClient};
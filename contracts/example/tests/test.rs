@@ -3,6 +3,7 @@ extern crate std;
 
 use axelar_gas_service::{AxelarGasService, AxelarGasServiceClient};
 use axelar_gateway::{
+    executable::ExecutableError,
     testutils::{self, generate_proof, get_approve_hash, TestSignerSet},
     types::Message,
     AxelarGatewayClient,
@@ -171,3 +172,22 @@ fn gmp_example() {
         (payload,),
     );
 }
+
+#[test]
+fn execute_fails_for_unapproved_message() {
+    let env = Env::default();
+
+    let (_, gateway_client) = setup_gateway(&env);
+    let (_, _, gas_service_id) = setup_gas_service(&env);
+    let app = setup_app(&env, &gateway_client.address, &gas_service_id);
+
+    let source_chain = String::from_str(&env, "source");
+    let message_id = String::from_str(&env, "test");
+    let source_address = Address::generate(&env).to_string();
+    let payload: Bytes = BytesN::<20>::random(&env).into();
+
+    assert_eq!(
+        app.try_execute(&source_chain, &message_id, &source_address, &payload),
+        Err(Ok(ExecutableError::NotApproved.into()))
+    );
+}
@@ -6,7 +6,7 @@ use soroban_sdk::{Bytes, BytesN, Env, String};
 // alloc needed for converting to alloy types
 use crate::abi::alloc::{string::String as StdString, vec};
 use crate::error::ContractError;
-use crate::types::{self, HubMessage, Message};
+use crate::types::{self, HubMessage, Message, TokenManagerType};
 extern crate alloc;
 
 sol! {
@@ -16,7 +16,9 @@ sol! {
         DeployInterchainToken,
         DeployTokenManager, // note, this case is not supported by the ITS hub
         SendToHub,
-        ReceiveFromHub
+        ReceiveFromHub,
+        LinkToken,
+        RegisterTokenMetadata
     }
 
     struct InterchainTransfer {
@@ -37,6 +39,21 @@ sol! {
         bytes minter;
     }
 
+    struct LinkToken {
+        uint256 messageType;
+        bytes32 tokenId;
+        uint256 tokenManagerType;
+        bytes sourceToken;
+        bytes destinationToken;
+        bytes params;
+    }
+
+    struct RegisterTokenMetadata {
+        uint256 messageType;
+        bytes tokenAddress;
+        uint8 decimals;
+    }
+
     struct SendToHub {
         uint256 messageType;
         string destination_chain;
@@ -83,6 +100,21 @@ impl Message {
                 minter: into_vec(minter).into(),
             }
             .abi_encode_params(),
+            Self::LinkToken(types::LinkToken {
+                token_id,
+                token_manager_type,
+                source_token_address,
+                destination_token_address,
+                params,
+            }) => LinkToken {
+                messageType: MessageType::LinkToken.into(),
+                tokenId: FixedBytes::<32>::new(token_id.into()),
+                tokenManagerType: token_manager_type.into(),
+                sourceToken: source_token_address.to_alloc_vec().into(),
+                destinationToken: destination_token_address.to_alloc_vec().into(),
+                params: into_vec(params).into(),
+            }
+            .abi_encode_params(),
         };
         Ok(Bytes::from_slice(env, &msg))
     }
@@ -120,6 +152,21 @@ impl Message {
                     minter: from_vec(env, decoded.minter.as_ref()),
                 }))
             }
+            MessageType::LinkToken => {
+                let decoded = LinkToken::abi_decode_params(&payload, true)
+                    .map_err(|_| ContractError::AbiDecodeFailed)?;
+
+                Ok(Self::LinkToken(types::LinkToken {
+                    token_id: BytesN::from_array(env, &decoded.tokenId.into()),
+                    token_manager_type: to_token_manager_type(decoded.tokenManagerType)?,
+                    source_token_address: Bytes::from_slice(env, decoded.sourceToken.as_ref()),
+                    destination_token_address: Bytes::from_slice(
+                        env,
+                        decoded.destinationToken.as_ref(),
+                    ),
+                    params: from_vec(env, decoded.params.as_ref()),
+                }))
+            }
             _ => Err(ContractError::InvalidMessageType),
         }
     }
@@ -146,6 +193,15 @@ impl HubMessage {
                 message: message.abi_encode(env)?.to_alloc_vec().into(),
             }
             .abi_encode_params(),
+            Self::RegisterTokenMetadata(types::RegisterTokenMetadata {
+                token_address,
+                decimals,
+            }) => RegisterTokenMetadata {
+                messageType: MessageType::RegisterTokenMetadata.into(),
+                tokenAddress: token_address.to_alloc_vec().into(),
+                decimals,
+            }
+            .abi_encode_params(),
         };
         Ok(Bytes::from_slice(env, &msg))
     }
@@ -180,6 +236,15 @@ impl HubMessage {
                     )?,
                 })
             }
+            MessageType::RegisterTokenMetadata => {
+                let decoded = RegisterTokenMetadata::abi_decode_params(&payload, true)
+                    .map_err(|_| ContractError::AbiDecodeFailed)?;
+
+                Ok(Self::RegisterTokenMetadata(types::RegisterTokenMetadata {
+                    token_address: Bytes::from_slice(env, decoded.tokenAddress.as_ref()),
+                    decimals: decoded.decimals,
+                }))
+            }
             _ => Err(ContractError::InvalidMessageType),
         }
     }
@@ -224,6 +289,21 @@ fn to_i128(value: Uint<256, 4>) -> Result<i128, ContractError> {
     Ok(i128_value)
 }
 
+fn to_token_manager_type(value: U256) -> Result<TokenManagerType, ContractError> {
+    let value: u32 = value
+        .try_into()
+        .map_err(|_| ContractError::InvalidTokenManagerType)?;
+
+    match value {
+        0 => Ok(TokenManagerType::NativeInterchainToken),
+        1 => Ok(TokenManagerType::MintBurnFrom),
+        2 => Ok(TokenManagerType::LockUnlock),
+        3 => Ok(TokenManagerType::LockUnlockFee),
+        4 => Ok(TokenManagerType::MintBurn),
+        _ => Err(ContractError::InvalidTokenManagerType),
+    }
+}
+
 fn into_vec(value: Option<Bytes>) -> alloc::vec::Vec<u8> {
     value.map(|d| d.to_alloc_vec()).unwrap_or_default()
 }
@@ -242,6 +322,12 @@ impl From<MessageType> for U256 {
     }
 }
 
+impl From<TokenManagerType> for U256 {
+    fn from(value: TokenManagerType) -> Self {
+        Self::from(value as u32)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -514,6 +600,142 @@ mod tests {
         }
     }
 
+    #[test]
+    fn link_token_encode_decode() {
+        let env = Env::default();
+        let remote_chain = String::from_str(&env, "chain");
+
+        let cases = vec![
+            types::HubMessage::SendToHub {
+                destination_chain: remote_chain.clone(),
+                message: types::Message::LinkToken(types::LinkToken {
+                    token_id: BytesN::from_array(&env, &[0u8; 32]),
+                    token_manager_type: TokenManagerType::NativeInterchainToken,
+                    source_token_address: Bytes::from_hex(&env, "00"),
+                    destination_token_address: Bytes::from_hex(&env, "00"),
+                    params: None,
+                }),
+            },
+            types::HubMessage::SendToHub {
+                destination_chain: remote_chain.clone(),
+                message: types::Message::LinkToken(types::LinkToken {
+                    token_id: BytesN::from_array(&env, &[255u8; 32]),
+                    token_manager_type: TokenManagerType::LockUnlock,
+                    source_token_address: Bytes::from_hex(
+                        &env,
+                        "4F4495243837681061C4743b74B3eEdf548D56A5",
+                    ),
+                    destination_token_address: Bytes::from_hex(
+                        &env,
+                        "4F4495243837681061C4743b74B3eEdf548D56A5",
+                    ),
+                    params: Some(Bytes::from_hex(&env, "abcd")),
+                }),
+            },
+            types::HubMessage::ReceiveFromHub {
+                source_chain: remote_chain.clone(),
+                message: types::Message::LinkToken(types::LinkToken {
+                    token_id: BytesN::from_array(&env, &[0u8; 32]),
+                    token_manager_type: TokenManagerType::MintBurn,
+                    source_token_address: Bytes::from_hex(&env, "00"),
+                    destination_token_address: Bytes::from_hex(&env, "00"),
+                    params: None,
+                }),
+            },
+            types::HubMessage::ReceiveFromHub {
+                source_chain: remote_chain,
+                message: types::Message::LinkToken(types::LinkToken {
+                    token_id: BytesN::from_array(&env, &[255u8; 32]),
+                    token_manager_type: TokenManagerType::MintBurnFrom,
+                    source_token_address: Bytes::from_hex(
+                        &env,
+                        "4F4495243837681061C4743b74B3eEdf548D56A5",
+                    ),
+                    destination_token_address: Bytes::from_hex(
+                        &env,
+                        "4F4495243837681061C4743b74B3eEdf548D56A5",
+                    ),
+                    params: Some(Bytes::from_hex(&env, "abcd")),
+                }),
+            },
+        ];
+
+        let encoded: Vec<_> = cases
+            .iter()
+            .map(|original| {
+                hex::encode(
+                    assert_ok!(original.clone().abi_encode(&env))
+                        .to_buffer::<1024>()
+                        .as_slice(),
+                )
+            })
+            .collect();
+
+        goldie::assert_json!(encoded);
+
+        for original in cases {
+            let encoded = assert_ok!(original.clone().abi_encode(&env));
+            let decoded = HubMessage::abi_decode(&env, &encoded);
+            assert_eq!(original, decoded.unwrap());
+        }
+    }
+
+    #[test]
+    fn register_token_metadata_encode_decode() {
+        let env = Env::default();
+
+        let cases = vec![
+            types::HubMessage::RegisterTokenMetadata(types::RegisterTokenMetadata {
+                token_address: Bytes::from_hex(&env, "00"),
+                decimals: 0,
+            }),
+            types::HubMessage::RegisterTokenMetadata(types::RegisterTokenMetadata {
+                token_address: Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+                decimals: 255,
+            }),
+        ];
+
+        let encoded: Vec<_> = cases
+            .iter()
+            .map(|original| {
+                hex::encode(
+                    assert_ok!(original.clone().abi_encode(&env))
+                        .to_buffer::<1024>()
+                        .as_slice(),
+                )
+            })
+            .collect();
+
+        goldie::assert_json!(encoded);
+
+        for original in cases {
+            let encoded = assert_ok!(original.clone().abi_encode(&env));
+            let decoded = HubMessage::abi_decode(&env, &encoded);
+            assert_eq!(original, decoded.unwrap());
+        }
+    }
+
+    #[test]
+    fn abi_decode_fails_invalid_token_manager_type() {
+        let env = Env::default();
+
+        let payload = LinkToken {
+            messageType: MessageType::LinkToken.into(),
+            tokenId: FixedBytes::<32>::new([0u8; 32]),
+            tokenManagerType: U256::from(5),
+            sourceToken: vec![0u8].into(),
+            destinationToken: vec![0u8].into(),
+            params: vec![].into(),
+        }
+        .abi_encode_params();
+
+        let result = Message::abi_decode(&env, &Bytes::from_slice(&env, &payload));
+        assert!(matches!(
+            result,
+            Err(ContractError::InvalidTokenManagerType)
+        ));
+    }
+
     #[test]
     fn abi_decode_fails_invalid_message_type() {
         let env = Env::default();
@@ -1,46 +1,57 @@
 use axelar_gas_service::AxelarGasServiceClient;
-use axelar_gateway::{executable::AxelarExecutableInterface, AxelarGatewayMessagingClient};
+use axelar_gateway::{
+    executable::CustomAxelarExecutable, impl_axelar_executable, AxelarGatewayMessagingClient,
+};
 use axelar_soroban_std::events::Event;
 use axelar_soroban_std::token::validate_token_metadata;
-use axelar_soroban_std::ttl::{extend_instance_ttl, extend_persistent_ttl};
+use axelar_soroban_std::ttl::{extend_instance_ttl, extend_persistent_ttl, TtlConfig};
 use axelar_soroban_std::{
-    address::AddressExt, ensure, interfaces, types::Token, Ownable, Upgradable,
+    address::AddressExt, ensure, interfaces, only_role, types::Token, when_not_paused,
+    AccessControl, Operatable, Ownable, TtlConfigurable, Upgradable,
 };
 use interchain_token::InterchainTokenClient;
 use soroban_sdk::token::{self, StellarAssetClient};
 use soroban_sdk::xdr::{FromXdr, ToXdr};
-use soroban_sdk::{contract, contractimpl, panic_with_error, Address, Bytes, BytesN, Env, String};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String};
 use soroban_token_sdk::metadata::TokenMetadata;
 
 use crate::abi::{get_message_type, MessageType as EncodedMessageType};
 use crate::error::ContractError;
 use crate::event::{
-    InterchainTokenDeployedEvent, InterchainTokenDeploymentStartedEvent,
-    InterchainTokenIdClaimedEvent, InterchainTransferReceivedEvent, InterchainTransferSentEvent,
-    TrustedChainRemovedEvent, TrustedChainSetEvent,
+    ExecutionFailedEvent, ExpressExecutedEvent, ExpressExecutionFulfilledEvent,
+    FailedExecutionClaimedEvent, InterchainTokenDeployedEvent, InterchainTokenIdClaimedEvent,
+    InterchainTransferReceivedEvent, InterchainTransferSentEvent, LinkTokenStartedEvent,
+    RecoveryAddressSetEvent, TokenDeploymentStartedEvent, TokenLinkedEvent,
+    TokenMetadataRegisteredEvent, TrustedChainRemovedEvent, TrustedChainSetEvent,
 };
 use crate::executable::InterchainTokenExecutableClient;
 use crate::interface::InterchainTokenServiceInterface;
-use crate::storage_types::{DataKey, TokenIdConfigValue};
-use crate::token_handler;
+use crate::storage_types::{DataKey, ExpressReceiptKey, FailedExecutionKey, TokenIdConfigValue};
 use crate::types::{
-    DeployInterchainToken, HubMessage, InterchainTransfer, Message, TokenManagerType,
+    DeployInterchainToken, FailedExecution, HubMessage, InterchainTransfer, LinkToken, Message,
+    RegisterTokenMetadata, TokenManagerType, CHAIN_MANAGER_ROLE, FLOW_LIMITER_ROLE, PAUSER_ROLE,
 };
+use crate::{flow_limit, token_decimals, token_handler};
 
 const ITS_HUB_CHAIN_NAME: &str = "axelar";
 const PREFIX_INTERCHAIN_TOKEN_ID: &str = "its-interchain-token-id";
 const PREFIX_INTERCHAIN_TOKEN_SALT: &str = "interchain-token-salt";
 const PREFIX_CANONICAL_TOKEN_SALT: &str = "canonical-token-salt";
+const PREFIX_CUSTOM_TOKEN_SALT: &str = "custom-token-salt";
 
 #[contract]
-#[derive(Ownable, Upgradable)]
+#[derive(AccessControl, Operatable, Ownable, Upgradable, TtlConfigurable)]
+#[upgradable(wasm_registry)]
 pub struct InterchainTokenService;
 
+impl_axelar_executable!(InterchainTokenService);
+
 #[contractimpl]
 impl InterchainTokenService {
     pub fn __constructor(
         env: Env,
         owner: Address,
+        operator: Address,
         gateway: Address,
         gas_service: Address,
         its_hub_address: String,
@@ -48,6 +59,10 @@ impl InterchainTokenService {
         interchain_token_wasm_hash: BytesN<32>,
     ) {
         interfaces::set_owner(&env, &owner);
+        interfaces::set_operator(&env, &operator);
+        interfaces::set_role(&env, &CHAIN_MANAGER_ROLE, &owner);
+        interfaces::set_role(&env, &PAUSER_ROLE, &owner);
+        interfaces::set_role(&env, &FLOW_LIMITER_ROLE, &operator);
         env.storage().instance().set(&DataKey::Gateway, &gateway);
         env.storage()
             .instance()
@@ -105,8 +120,8 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
             .has(&DataKey::TrustedChain(chain))
     }
 
-    fn set_trusted_chain(env: &Env, chain: String) -> Result<(), ContractError> {
-        Self::owner(env).require_auth();
+    fn set_trusted_chain(env: &Env, caller: Address, chain: String) -> Result<(), ContractError> {
+        only_role!(env, caller, CHAIN_MANAGER_ROLE);
 
         let key = DataKey::TrustedChain(chain.clone());
 
@@ -122,8 +137,12 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         Ok(())
     }
 
-    fn remove_trusted_chain(env: &Env, chain: String) -> Result<(), ContractError> {
-        Self::owner(env).require_auth();
+    fn remove_trusted_chain(
+        env: &Env,
+        caller: Address,
+        chain: String,
+    ) -> Result<(), ContractError> {
+        only_role!(env, caller, CHAIN_MANAGER_ROLE);
 
         let key = DataKey::TrustedChain(chain.clone());
 
@@ -139,6 +158,34 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         Ok(())
     }
 
+    fn paused(env: &Env) -> bool {
+        interfaces::paused(env)
+    }
+
+    fn pause(env: &Env, caller: Address) -> Result<(), ContractError> {
+        // the authorization of the caller is ensured by `interfaces::pause`
+        ensure!(
+            interfaces::has_role(env, &PAUSER_ROLE, &caller),
+            ContractError::MissingRole
+        );
+
+        interfaces::pause(env, caller);
+
+        Ok(())
+    }
+
+    fn unpause(env: &Env, caller: Address) -> Result<(), ContractError> {
+        // the authorization of the caller is ensured by `interfaces::unpause`
+        ensure!(
+            interfaces::has_role(env, &PAUSER_ROLE, &caller),
+            ContractError::MissingRole
+        );
+
+        interfaces::unpause(env, caller);
+
+        Ok(())
+    }
+
     fn interchain_token_deploy_salt(env: &Env, deployer: Address, salt: BytesN<32>) -> BytesN<32> {
         let chain_name_hash = Self::chain_name_hash(env);
         env.crypto()
@@ -194,6 +241,111 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
             .token_manager_type
     }
 
+    fn extend_token_config_ttl(env: &Env, token_id: BytesN<32>) -> Result<(), ContractError> {
+        Self::token_id_config(env, token_id.clone())?;
+
+        let ttl = TtlConfig::load(env);
+        ttl.extend_persistent_ttl(env, &DataKey::TokenIdConfigKey(token_id));
+        ttl.extend_instance_ttl(env);
+
+        Ok(())
+    }
+
+    fn extend_trusted_chain_ttl(env: &Env, chain: String) -> Result<(), ContractError> {
+        ensure!(
+            Self::is_trusted_chain(env, chain.clone()),
+            ContractError::UntrustedChain
+        );
+
+        let ttl = TtlConfig::load(env);
+        ttl.extend_persistent_ttl(env, &DataKey::TrustedChain(chain));
+        ttl.extend_instance_ttl(env);
+
+        Ok(())
+    }
+
+    fn flow_limit(env: &Env, token_id: BytesN<32>) -> Option<i128> {
+        flow_limit::flow_limit(env, token_id)
+    }
+
+    fn flow_out_amount(env: &Env, token_id: BytesN<32>) -> i128 {
+        flow_limit::flow_out_amount(env, token_id)
+    }
+
+    fn flow_in_amount(env: &Env, token_id: BytesN<32>) -> i128 {
+        flow_limit::flow_in_amount(env, token_id)
+    }
+
+    fn set_flow_limit(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        flow_limit: Option<i128>,
+    ) -> Result<(), ContractError> {
+        only_role!(env, caller, FLOW_LIMITER_ROLE);
+
+        Self::token_id_config(env, token_id.clone())?;
+
+        flow_limit::set_flow_limit(env, token_id, flow_limit)
+    }
+
+    fn set_token_authorized(
+        env: &Env,
+        token_id: BytesN<32>,
+        account: Address,
+        authorize: bool,
+    ) -> Result<(), ContractError> {
+        Self::operator(env).require_auth();
+
+        let token_address = Self::native_interchain_token_address(env, token_id)?;
+
+        StellarAssetClient::new(env, &token_address).set_authorized(&account, &authorize);
+
+        extend_instance_ttl(env);
+
+        Ok(())
+    }
+
+    fn clawback_token(
+        env: &Env,
+        token_id: BytesN<32>,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        Self::operator(env).require_auth();
+
+        let token_address = Self::native_interchain_token_address(env, token_id)?;
+
+        StellarAssetClient::new(env, &token_address).clawback(&from, &amount);
+
+        extend_instance_ttl(env);
+
+        Ok(())
+    }
+
+    fn token_decimals(env: &Env, token_id: BytesN<32>, chain: String) -> Option<u32> {
+        token_decimals::token_decimals(env, token_id, chain)
+    }
+
+    fn set_token_decimals(
+        env: &Env,
+        token_id: BytesN<32>,
+        chain: String,
+        decimals: u32,
+    ) -> Result<(), ContractError> {
+        Self::owner(env).require_auth();
+
+        Self::token_id_config(env, token_id.clone())?;
+
+        token_decimals::set_token_decimals(env, token_id, chain, decimals);
+
+        Ok(())
+    }
+
+    fn dust(env: &Env, token_id: BytesN<32>, chain: String) -> i128 {
+        token_decimals::dust(env, token_id, chain)
+    }
+
     fn deploy_interchain_token(
         env: &Env,
         caller: Address,
@@ -326,23 +478,35 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         data: Option<Bytes>,
         gas_token: Token,
     ) -> Result<(), ContractError> {
+        when_not_paused!(env);
+
         ensure!(amount > 0, ContractError::InvalidAmount);
 
         caller.require_auth();
 
-        token_handler::take_token(
+        let token_config_value = Self::token_id_config_with_extended_ttl(env, token_id.clone())?;
+
+        let amount = token_handler::take_token(
             env,
             &caller,
-            Self::token_id_config_with_extended_ttl(env, token_id.clone())?,
+            token_id.clone(),
+            token_config_value.clone(),
             amount,
         )?;
 
+        // Only the part of the received amount that can be represented on the destination chain is sent, the dust is returned to the caller
+        let (scaled_amount, dust) =
+            Self::scale_outbound_amount(env, token_id.clone(), destination_chain.clone(), amount)?;
+        if dust > 0 {
+            token_handler::give_token(env, &caller, token_id.clone(), token_config_value, dust)?;
+        }
+
         InterchainTransferSentEvent {
             token_id: token_id.clone(),
             source_address: caller.clone(),
             destination_chain: destination_chain.clone(),
             destination_address: destination_address.clone(),
-            amount,
+            amount: amount - dust,
             data: data.clone(),
         }
         .emit(env);
@@ -351,7 +515,7 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
             token_id,
             source_address: caller.clone().to_xdr(env),
             destination_address,
-            amount,
+            amount: scaled_amount,
             data,
         });
 
@@ -403,137 +567,730 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
 
         Ok(token_id)
     }
-}
 
-#[contractimpl]
-impl AxelarExecutableInterface for InterchainTokenService {
-    fn gateway(env: &Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Gateway)
-            .expect("gateway not found")
-    }
+    /// Registers an existing token as an interchain token under the given token manager type.
+    ///
+    /// The token ID is derived from the `deployer` and `salt`, so the same deployer can register the token on other chains
+    /// under the same token ID.
+    ///
+    /// # Arguments
+    /// * `env` - A reference to the environment in which the function operates.
+    /// * `deployer` - The address registering the token. The deployer must authenticate.
+    /// * `salt` - A 32-byte unique salt used to derive the token ID.
+    /// * `token_address` - The address of the existing token.
+    /// * `token_manager_type` - The token manager type used to take and give the token.
+    ///   For mint/burn based types, this contract must be the admin of the token.
+    ///
+    /// # Returns
+    /// * `Result<BytesN<32>, ContractError>` - The token ID assigned to this token if successful.
+    ///
+    /// # Errors
+    /// * `ContractError::TokenAlreadyRegistered` - If the token ID or the token address is already registered.
+    /// * Any error propagated from `ensure_token_registrable`.
+    fn register_custom_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        token_address: Address,
+        token_manager_type: TokenManagerType,
+    ) -> Result<BytesN<32>, ContractError> {
+        deployer.require_auth();
 
-    fn execute(
-        env: Env,
-        source_chain: String,
-        message_id: String,
-        source_address: String,
-        payload: Bytes,
-    ) {
-        Self::validate_message(&env, &source_chain, &message_id, &source_address, &payload)
-            .unwrap_or_else(|err| panic_with_error!(env, err));
+        let deploy_salt = Self::custom_token_deploy_salt(env, deployer.clone(), salt);
+        let token_id = Self::interchain_token_id(env, Address::zero(env), deploy_salt.clone());
 
-        Self::execute_message(&env, source_chain, message_id, source_address, payload)
-            .unwrap_or_else(|err| panic_with_error!(env, err));
-    }
-}
+        ensure!(
+            !env.storage()
+                .persistent()
+                .has(&DataKey::TokenIdConfigKey(token_id.clone())),
+            ContractError::TokenAlreadyRegistered
+        );
 
-impl InterchainTokenService {
-    // Modify this function to add migration logic
-    const fn run_migration(_env: &Env, _migration_data: ()) {}
+        Self::ensure_token_registrable(env, &token_address, token_manager_type)?;
 
-    fn pay_gas_and_call_contract(
+        InterchainTokenIdClaimedEvent {
+            token_id: token_id.clone(),
+            deployer,
+            salt: deploy_salt,
+        }
+        .emit(env);
+
+        Self::set_token_id_config(
+            env,
+            token_id.clone(),
+            TokenIdConfigValue {
+                token_address,
+                token_manager_type,
+            },
+        );
+
+        Ok(token_id)
+    }
+
+    /// Registers the decimals of a token with the ITS hub.
+    ///
+    /// The ITS hub requires the metadata of a token to be registered before it can be linked to a token on another chain,
+    /// so this must be called for the local token before calling `link_token`.
+    ///
+    /// # Arguments
+    /// * `env` - A reference to the environment in which the function operates.
+    /// * `token_address` - The address of the token whose metadata is registered.
+    /// * `spender` - The spender of the cross-chain gas. The spender must authenticate.
+    /// * `gas_token` - The token used to pay for the gas cost of the cross-chain call.
+    ///
+    /// # Errors
+    /// * `ContractError::InvalidTokenMetaData` - If the decimals of the token don't fit into a `u8`.
+    fn register_token_metadata(
         env: &Env,
-        caller: Address,
-        destination_chain: String,
-        message: Message,
+        token_address: Address,
+        spender: Address,
         gas_token: Token,
     ) -> Result<(), ContractError> {
-        // Note: ITS Hub chain as the actual destination chain for the messsage isn't supported
-        ensure!(
-            Self::is_trusted_chain(env, destination_chain.clone()),
-            ContractError::UntrustedChain
-        );
+        spender.require_auth();
 
-        let gateway = AxelarGatewayMessagingClient::new(env, &Self::gateway(env));
-        let gas_service = AxelarGasServiceClient::new(env, &Self::gas_service(env));
+        let decimals = token::Client::new(env, &token_address).decimals();
 
-        let payload = HubMessage::SendToHub {
-            destination_chain: destination_chain.clone(),
-            message,
+        if let Some(token_id) = env
+            .storage()
+            .persistent()
+            .get::<_, BytesN<32>>(&DataKey::RegisteredTokenAddress(token_address.clone()))
+        {
+            Self::record_local_decimals(env, token_id, decimals);
         }
+
+        let payload = HubMessage::RegisterTokenMetadata(RegisterTokenMetadata {
+            token_address: token_address.clone().to_xdr(env),
+            decimals: u8::try_from(decimals).map_err(|_| ContractError::InvalidTokenMetaData)?,
+        })
         .abi_encode(env)?;
 
-        let hub_chain = Self::its_hub_chain_name(env);
-        let hub_address = Self::its_hub_address(env);
+        TokenMetadataRegisteredEvent {
+            token_address,
+            decimals,
+        }
+        .emit(env);
 
-        gas_service.pay_gas(
-            &env.current_contract_address(),
-            &hub_chain,
-            &hub_address,
-            &payload,
-            &caller,
-            &gas_token,
-            &Bytes::new(env),
+        Self::pay_gas_and_call_hub(env, &TtlConfig::load(env), spender, payload, gas_token);
+
+        Ok(())
+    }
+
+    /// Links a custom token to an existing token on the destination chain.
+    ///
+    /// The token must have been registered on this chain via `register_custom_token` by the same `deployer` and `salt`.
+    /// The linked tokens share the token ID, and the destination chain manages its token with `token_manager_type`.
+    ///
+    /// # Arguments
+    /// * `env` - A reference to the environment in which the function operates.
+    /// * `deployer` - The address that registered the token. The deployer must authenticate.
+    /// * `salt` - The salt used when registering the token.
+    /// * `destination_chain` - The name of the chain of the token to link to.
+    /// * `destination_token_address` - The address of the token on the destination chain.
+    /// * `token_manager_type` - The token manager type used for the token on the destination chain.
+    /// * `link_params` - Additional parameters for the token manager on the destination chain, such as its operator.
+    /// * `gas_token` - The token used to pay for the gas cost of the cross-chain call.
+    ///
+    /// # Returns
+    /// * `Result<BytesN<32>, ContractError>` - The token ID of the linked token if successful.
+    ///
+    /// # Errors
+    /// * `ContractError::InvalidTokenManagerType` - If the token manager type is `NativeInterchainToken`.
+    /// * `ContractError::InvalidDestinationAddress` - If the destination token address is empty.
+    /// * `ContractError::InvalidTokenId` - If the token has not been registered.
+    /// * Any error propagated from `pay_gas_and_call_contract`.
+    fn link_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        destination_chain: String,
+        destination_token_address: Bytes,
+        token_manager_type: TokenManagerType,
+        link_params: Option<Bytes>,
+        gas_token: Token,
+    ) -> Result<BytesN<32>, ContractError> {
+        deployer.require_auth();
+
+        ensure!(
+            token_manager_type != TokenManagerType::NativeInterchainToken,
+            ContractError::InvalidTokenManagerType
         );
 
-        gateway.call_contract(
-            &env.current_contract_address(),
-            &hub_chain,
-            &hub_address,
-            &payload,
+        ensure!(
+            !destination_token_address.is_empty(),
+            ContractError::InvalidDestinationAddress
         );
 
-        extend_persistent_ttl(env, &DataKey::TrustedChain(destination_chain));
-        extend_instance_ttl(env);
+        let deploy_salt = Self::custom_token_deploy_salt(env, deployer.clone(), salt);
+        let token_id = Self::interchain_token_id(env, Address::zero(env), deploy_salt);
+        let token_address =
+            Self::token_id_config_with_extended_ttl(env, token_id.clone())?.token_address;
 
-        Ok(())
+        Self::record_local_decimals(
+            env,
+            token_id.clone(),
+            token::Client::new(env, &token_address).decimals(),
+        );
+
+        LinkTokenStartedEvent {
+            token_id: token_id.clone(),
+            destination_chain: destination_chain.clone(),
+            source_token_address: token_address.clone(),
+            destination_token_address: destination_token_address.clone(),
+            token_manager_type,
+            params: link_params.clone(),
+        }
+        .emit(env);
+
+        let message = Message::LinkToken(LinkToken {
+            token_id: token_id.clone(),
+            token_manager_type,
+            source_token_address: token_address.to_xdr(env),
+            destination_token_address,
+            params: link_params,
+        });
+
+        Self::pay_gas_and_call_contract(env, deployer, destination_chain, message, gas_token)?;
+
+        Ok(token_id)
     }
 
-    fn execute_message(
+    fn express_executor(
+        env: &Env,
+        message_id: String,
+        payload_hash: BytesN<32>,
+    ) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ExpressReceipt(ExpressReceiptKey {
+                message_id,
+                payload_hash,
+            }))
+    }
+
+    /// Executes an inbound interchain transfer ahead of its approval by the gateway.
+    ///
+    /// The `express_executor` transfers the tokens to the recipient directly, and an express receipt is stored
+    /// for the message. When the message is later executed via `execute`, the tokens are given to the
+    /// `express_executor` instead of the recipient.
+    ///
+    /// # Arguments
+    /// * `env` - A reference to the environment in which the function operates.
+    /// * `express_executor` - The address fronting the tokens. The express executor must authenticate.
+    /// * `source_chain` - The source chain of the gateway message, i.e. the ITS hub chain.
+    /// * `message_id` - The ID of the gateway message.
+    /// * `source_address` - The source address of the gateway message, i.e. the ITS hub address.
+    /// * `payload` - The payload of the gateway message.
+    ///
+    /// # Errors
+    /// * `ContractError::MessageAlreadyApproved` - If the message is already approved by the gateway.
+    /// * `ContractError::MessageAlreadyExecuted` - If the message is already executed.
+    /// * `ContractError::InvalidMessageType` - If the message is not an interchain transfer.
+    /// * `ContractError::ExpressReceiptAlreadySet` - If the message has already been express executed.
+    fn express_execute(
         env: &Env,
+        express_executor: Address,
         source_chain: String,
         message_id: String,
-        _source_address: String,
+        source_address: String,
         payload: Bytes,
     ) -> Result<(), ContractError> {
-        let (source_chain, message) = Self::get_execute_params(env, source_chain, &payload)?;
+        when_not_paused!(env);
 
-        match message {
-            Message::InterchainTransfer(InterchainTransfer {
-                token_id,
-                source_address,
-                destination_address,
-                amount,
-                data,
-            }) => {
-                let destination_address = Address::from_xdr(env, &destination_address)
-                    .map_err(|_| ContractError::InvalidDestinationAddress)?;
+        express_executor.require_auth();
 
-                let token_config_value =
-                    Self::token_id_config_with_extended_ttl(env, token_id.clone())?;
+        let payload_hash: BytesN<32> = env.crypto().keccak256(&payload).into();
+        let gateway = AxelarGatewayMessagingClient::new(env, &Self::gateway(env));
 
-                token_handler::give_token(
-                    env,
-                    &destination_address,
-                    token_config_value.clone(),
-                    amount,
-                )?;
+        ensure!(
+            !gateway.is_message_approved(
+                &source_chain,
+                &message_id,
+                &source_address,
+                &env.current_contract_address(),
+                &payload_hash,
+            ),
+            ContractError::MessageAlreadyApproved
+        );
 
-                InterchainTransferReceivedEvent {
-                    source_chain: source_chain.clone(),
-                    token_id: token_id.clone(),
-                    source_address: source_address.clone(),
-                    destination_address: destination_address.clone(),
-                    amount,
-                    data: data.clone(),
-                }
-                .emit(env);
+        ensure!(
+            !gateway.is_message_executed(&source_chain, &message_id),
+            ContractError::MessageAlreadyExecuted
+        );
+
+        let (source_chain, message) = Self::get_execute_params(env, source_chain, &payload)?;
+
+        let Message::InterchainTransfer(message) = message else {
+            return Err(ContractError::InvalidMessageType);
+        };
+
+        let key = DataKey::ExpressReceipt(ExpressReceiptKey {
+            message_id: message_id.clone(),
+            payload_hash: payload_hash.clone(),
+        });
+
+        ensure!(
+            !env.storage().persistent().has(&key),
+            ContractError::ExpressReceiptAlreadySet
+        );
+
+        env.storage().persistent().set(&key, &express_executor);
+
+        let destination_address = Address::from_xdr(env, &message.destination_address)
+            .map_err(|_| ContractError::InvalidDestinationAddress)?;
+
+        let token_address =
+            Self::token_id_config_with_extended_ttl(env, message.token_id.clone())?.token_address;
+
+        // The dust is recorded once the message is executed
+        let (amount, _) = Self::scale_inbound_amount(
+            env,
+            message.token_id.clone(),
+            source_chain.clone(),
+            message.amount,
+        );
+        let message = InterchainTransfer { amount, ..message };
+
+        token::Client::new(env, &token_address).transfer(
+            &express_executor,
+            &destination_address,
+            &message.amount,
+        );
+
+        ExpressExecutedEvent {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+            source_address: message.source_address.clone(),
+            payload_hash,
+            express_executor,
+        }
+        .emit(env);
+
+        Self::complete_interchain_transfer(
+            env,
+            source_chain,
+            message_id,
+            message,
+            destination_address,
+            token_address,
+        );
+
+        extend_instance_ttl(env);
+
+        Ok(())
+    }
+
+    fn recovery_address(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::RecoveryAddress)
+    }
+
+    fn set_recovery_address(env: &Env, recovery_address: Address) {
+        Self::owner(env).require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RecoveryAddress, &recovery_address);
+
+        RecoveryAddressSetEvent { recovery_address }.emit(env);
+    }
+
+    fn failed_execution(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+    ) -> Option<FailedExecution> {
+        let key = DataKey::FailedExecution(FailedExecutionKey {
+            source_chain,
+            message_id,
+        });
+
+        let failed_execution = env.storage().persistent().get(&key);
+        if failed_execution.is_some() {
+            extend_persistent_ttl(env, &key);
+        }
+
+        failed_execution
+    }
+
+    /// Executes an approved inbound interchain transfer with data without calling its destination contract,
+    /// and holds the tokens in escrow until they're claimed or the execution is retried.
+    ///
+    /// The escrow is keyed by the original source chain of the transfer and the message ID.
+    ///
+    /// # Arguments
+    /// * `env` - A reference to the environment in which the function operates.
+    /// * `caller` - The destination contract of the transfer or the recovery address. The caller must authenticate.
+    /// * `source_chain` - The source chain of the gateway message, i.e. the ITS hub chain.
+    /// * `message_id` - The ID of the gateway message.
+    /// * `source_address` - The source address of the gateway message, i.e. the ITS hub address.
+    /// * `payload` - The payload of the gateway message.
+    ///
+    /// # Errors
+    /// * `ContractError::MessageNotApproved` - If the message isn't approved by the gateway.
+    /// * `ContractError::InvalidMessageType` - If the message isn't an interchain transfer with data.
+    /// * `ContractError::ExpressReceiptAlreadySet` - If the transfer has already been express executed.
+    /// * `ContractError::NotRecoveryCaller` - If the caller is neither the destination contract nor the recovery address.
+    fn escrow_failed_execution(
+        env: &Env,
+        caller: Address,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload: Bytes,
+    ) -> Result<(), ContractError> {
+        when_not_paused!(env);
+
+        caller.require_auth();
+
+        Self::validate_message(env, &source_chain, &message_id, &source_address, &payload)
+            .map_err(|_| ContractError::MessageNotApproved)?;
+
+        let payload_hash: BytesN<32> = env.crypto().keccak256(&payload).into();
+        let (source_chain, message) = Self::get_execute_params(env, source_chain, &payload)?;
+
+        let Message::InterchainTransfer(InterchainTransfer {
+            token_id,
+            source_address,
+            destination_address,
+            amount,
+            data: Some(data),
+        }) = message
+        else {
+            return Err(ContractError::InvalidMessageType);
+        };
+
+        ensure!(
+            !env.storage()
+                .persistent()
+                .has(&DataKey::ExpressReceipt(ExpressReceiptKey {
+                    message_id: message_id.clone(),
+                    payload_hash,
+                })),
+            ContractError::ExpressReceiptAlreadySet
+        );
+
+        let destination_address = Address::from_xdr(env, &destination_address)
+            .map_err(|_| ContractError::InvalidDestinationAddress)?;
+
+        Self::ensure_recovery_caller(env, &caller, &destination_address)?;
+
+        Self::token_id_config_with_extended_ttl(env, token_id.clone())?;
+
+        let (amount, dust) =
+            Self::scale_inbound_amount(env, token_id.clone(), source_chain.clone(), amount);
+        token_decimals::record_dust(
+            env,
+            token_id.clone(),
+            source_chain.clone(),
+            message_id.clone(),
+            dust,
+        );
+
+        let key = DataKey::FailedExecution(FailedExecutionKey {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+        });
+
+        env.storage().persistent().set(
+            &key,
+            &FailedExecution {
+                source_chain: source_chain.clone(),
+                source_address,
+                destination_address: destination_address.clone(),
+                token_id: token_id.clone(),
+                amount,
+                data: data.clone(),
+            },
+        );
+        extend_persistent_ttl(env, &key);
+
+        ExecutionFailedEvent {
+            source_chain,
+            message_id,
+            destination_address,
+            token_id,
+            amount,
+            data,
+        }
+        .emit(env);
+
+        extend_instance_ttl(env);
+
+        Ok(())
+    }
+
+    /// Gives the escrowed tokens of a failed execution to its destination contract, without executing it.
+    ///
+    /// # Arguments
+    /// * `env` - A reference to the environment in which the function operates.
+    /// * `caller` - The destination contract of the transfer or the recovery address. The caller must authenticate.
+    /// * `source_chain` - The original source chain of the transfer.
+    /// * `message_id` - The ID of the gateway message whose execution failed.
+    ///
+    /// # Errors
+    /// * `ContractError::FailedExecutionNotFound` - If there is no failed execution for the message.
+    /// * `ContractError::NotRecoveryCaller` - If the caller is neither the destination contract nor the recovery address.
+    fn claim_failed_execution(
+        env: &Env,
+        caller: Address,
+        source_chain: String,
+        message_id: String,
+    ) -> Result<(), ContractError> {
+        when_not_paused!(env);
+
+        let FailedExecution {
+            destination_address,
+            token_id,
+            amount,
+            ..
+        } = Self::take_failed_execution(env, &caller, source_chain.clone(), message_id.clone())?;
+
+        let amount = token_handler::give_token(
+            env,
+            &destination_address,
+            token_id.clone(),
+            Self::token_id_config_with_extended_ttl(env, token_id.clone())?,
+            amount,
+        )?;
+
+        FailedExecutionClaimedEvent {
+            source_chain,
+            message_id,
+            destination_address,
+            token_id,
+            amount,
+        }
+        .emit(env);
+
+        extend_instance_ttl(env);
+
+        Ok(())
+    }
+
+    /// Gives the escrowed tokens of a failed execution to its destination contract and executes it again.
+    ///
+    /// If the destination contract fails again, the whole call reverts and the tokens stay in escrow.
+    ///
+    /// # Arguments
+    /// * `env` - A reference to the environment in which the function operates.
+    /// * `caller` - The destination contract of the transfer or the recovery address. The caller must authenticate.
+    /// * `source_chain` - The original source chain of the transfer.
+    /// * `message_id` - The ID of the gateway message whose execution failed.
+    ///
+    /// # Errors
+    /// * `ContractError::FailedExecutionNotFound` - If there is no failed execution for the message.
+    /// * `ContractError::NotRecoveryCaller` - If the caller is neither the destination contract nor the recovery address.
+    fn retry_failed_execution(
+        env: &Env,
+        caller: Address,
+        source_chain: String,
+        message_id: String,
+    ) -> Result<(), ContractError> {
+        when_not_paused!(env);
 
-                let token_address = token_config_value.token_address;
-
-                if let Some(payload) = data {
-                    let executable =
-                        InterchainTokenExecutableClient::new(env, &destination_address);
-                    executable.execute_with_interchain_token(
-                        &source_chain,
-                        &message_id,
-                        &source_address,
-                        &payload,
-                        &token_id,
-                        &token_address,
-                        &amount,
+        let FailedExecution {
+            source_chain,
+            source_address,
+            destination_address,
+            token_id,
+            amount,
+            data,
+        } = Self::take_failed_execution(env, &caller, source_chain, message_id.clone())?;
+
+        let token_config_value = Self::token_id_config_with_extended_ttl(env, token_id.clone())?;
+
+        let amount = token_handler::give_token(
+            env,
+            &destination_address,
+            token_id.clone(),
+            token_config_value.clone(),
+            amount,
+        )?;
+
+        Self::complete_interchain_transfer(
+            env,
+            source_chain,
+            message_id,
+            InterchainTransfer {
+                token_id,
+                source_address,
+                destination_address: destination_address.clone().to_xdr(env),
+                amount,
+                data: Some(data),
+            },
+            destination_address,
+            token_config_value.token_address,
+        );
+
+        extend_instance_ttl(env);
+
+        Ok(())
+    }
+}
+
+impl CustomAxelarExecutable for InterchainTokenService {
+    type Error = ContractError;
+
+    fn __gateway(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Gateway)
+            .expect("gateway not found")
+    }
+
+    fn execute_payload(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload: Bytes,
+    ) -> Result<(), Self::Error> {
+        Self::execute_message(env, source_chain, message_id, source_address, payload)
+    }
+}
+
+impl InterchainTokenService {
+    // Modify this function to add migration logic
+    const fn run_migration(_env: &Env, _migration_data: ()) {}
+
+    fn pay_gas_and_call_contract(
+        env: &Env,
+        caller: Address,
+        destination_chain: String,
+        message: Message,
+        gas_token: Token,
+    ) -> Result<(), ContractError> {
+        // Note: ITS Hub chain as the actual destination chain for the messsage isn't supported
+        ensure!(
+            Self::is_trusted_chain(env, destination_chain.clone()),
+            ContractError::UntrustedChain
+        );
+
+        let payload = HubMessage::SendToHub {
+            destination_chain: destination_chain.clone(),
+            message,
+        }
+        .abi_encode(env)?;
+
+        let ttl = TtlConfig::load(env);
+        Self::pay_gas_and_call_hub(env, &ttl, caller, payload, gas_token);
+
+        ttl.extend_persistent_ttl(env, &DataKey::TrustedChain(destination_chain));
+
+        Ok(())
+    }
+
+    /// Pays the gas for and sends the `payload` to the ITS hub.
+    fn pay_gas_and_call_hub(
+        env: &Env,
+        ttl: &TtlConfig,
+        caller: Address,
+        payload: Bytes,
+        gas_token: Token,
+    ) {
+        let gateway = AxelarGatewayMessagingClient::new(env, &Self::gateway(env));
+        let gas_service = AxelarGasServiceClient::new(env, &Self::gas_service(env));
+
+        let hub_chain = Self::its_hub_chain_name(env);
+        let hub_address = Self::its_hub_address(env);
+
+        gas_service.pay_gas(
+            &env.current_contract_address(),
+            &hub_chain,
+            &hub_address,
+            &payload,
+            &caller,
+            &gas_token,
+            &Bytes::new(env),
+        );
+
+        gateway.call_contract(
+            &env.current_contract_address(),
+            &hub_chain,
+            &hub_address,
+            &payload,
+        );
+
+        ttl.extend_instance_ttl(env);
+    }
+
+    fn execute_message(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+        _source_address: String,
+        payload: Bytes,
+    ) -> Result<(), ContractError> {
+        let (source_chain, message) = Self::get_execute_params(env, source_chain, &payload)?;
+
+        match message {
+            Message::InterchainTransfer(message) => {
+                let payload_hash: BytesN<32> = env.crypto().keccak256(&payload).into();
+
+                let token_config_value =
+                    Self::token_id_config_with_extended_ttl(env, message.token_id.clone())?;
+
+                let (amount, dust) = Self::scale_inbound_amount(
+                    env,
+                    message.token_id.clone(),
+                    source_chain.clone(),
+                    message.amount,
+                );
+                token_decimals::record_dust(
+                    env,
+                    message.token_id.clone(),
+                    source_chain.clone(),
+                    message_id.clone(),
+                    dust,
+                );
+                let message = InterchainTransfer { amount, ..message };
+
+                let express_receipt_key = DataKey::ExpressReceipt(ExpressReceiptKey {
+                    message_id: message_id.clone(),
+                    payload_hash: payload_hash.clone(),
+                });
+
+                if let Some(express_executor) = env
+                    .storage()
+                    .persistent()
+                    .get::<_, Address>(&express_receipt_key)
+                {
+                    env.storage().persistent().remove(&express_receipt_key);
+
+                    token_handler::give_token(
+                        env,
+                        &express_executor,
+                        message.token_id,
+                        token_config_value,
+                        message.amount,
+                    )?;
+
+                    ExpressExecutionFulfilledEvent {
+                        source_chain: source_chain.clone(),
+                        message_id,
+                        source_address: message.source_address,
+                        payload_hash,
+                        express_executor,
+                    }
+                    .emit(env);
+                } else {
+                    let destination_address = Address::from_xdr(env, &message.destination_address)
+                        .map_err(|_| ContractError::InvalidDestinationAddress)?;
+
+                    let amount = token_handler::give_token(
+                        env,
+                        &destination_address,
+                        message.token_id.clone(),
+                        token_config_value.clone(),
+                        message.amount,
+                    )?;
+
+                    Self::complete_interchain_transfer(
+                        env,
+                        source_chain.clone(),
+                        message_id,
+                        InterchainTransfer { amount, ..message },
+                        destination_address,
+                        token_config_value.token_address,
                     );
                 }
             }
@@ -552,7 +1309,7 @@ impl InterchainTokenService {
                 let token_metadata = TokenMetadata {
                     name,
                     symbol,
-                    decimal: decimals as u32,
+                    decimal: u32::from(decimals),
                 };
 
                 ensure!(
@@ -566,6 +1323,13 @@ impl InterchainTokenService {
                     .transpose()
                     .map_err(|_| ContractError::InvalidMinter)?;
 
+                token_decimals::set_token_decimals(
+                    env,
+                    token_id.clone(),
+                    source_chain.clone(),
+                    u32::from(decimals),
+                );
+
                 let deployed_address = Self::deploy_interchain_token_contract(
                     env,
                     minter,
@@ -582,14 +1346,191 @@ impl InterchainTokenService {
                     },
                 );
             }
+            Message::LinkToken(LinkToken {
+                token_id,
+                token_manager_type,
+                destination_token_address,
+                ..
+            }) => {
+                ensure!(
+                    Self::token_id_config(env, token_id.clone()).is_err(),
+                    ContractError::TokenAlreadyRegistered
+                );
+
+                // Note: attempt to convert a byte string which doesn't represent a valid Soroban address fails at the Host level
+                let token_address = Address::from_xdr(env, &destination_token_address)
+                    .map_err(|_| ContractError::InvalidDestinationAddress)?;
+
+                Self::ensure_token_registrable(env, &token_address, token_manager_type)?;
+
+                Self::set_token_id_config(
+                    env,
+                    token_id.clone(),
+                    TokenIdConfigValue {
+                        token_address: token_address.clone(),
+                        token_manager_type,
+                    },
+                );
+
+                Self::record_local_decimals(
+                    env,
+                    token_id.clone(),
+                    token::Client::new(env, &token_address).decimals(),
+                );
+
+                TokenLinkedEvent {
+                    source_chain: source_chain.clone(),
+                    token_id,
+                    token_address,
+                    token_manager_type,
+                }
+                .emit(env);
+            }
         };
 
-        extend_persistent_ttl(env, &DataKey::TrustedChain(source_chain));
-        extend_instance_ttl(env);
+        let ttl = TtlConfig::load(env);
+        ttl.extend_persistent_ttl(env, &DataKey::TrustedChain(source_chain));
+        ttl.extend_instance_ttl(env);
 
         Ok(())
     }
 
+    /// Emits the event for a received interchain transfer and executes the destination contract if data is attached.
+    ///
+    /// The tokens must already have been given to the `destination_address`.
+    fn complete_interchain_transfer(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+        InterchainTransfer {
+            token_id,
+            source_address,
+            destination_address: _,
+            amount,
+            data,
+        }: InterchainTransfer,
+        destination_address: Address,
+        token_address: Address,
+    ) {
+        InterchainTransferReceivedEvent {
+            source_chain: source_chain.clone(),
+            token_id: token_id.clone(),
+            source_address: source_address.clone(),
+            destination_address: destination_address.clone(),
+            amount,
+            data: data.clone(),
+        }
+        .emit(env);
+
+        if let Some(payload) = data {
+            let executable = InterchainTokenExecutableClient::new(env, &destination_address);
+            executable.execute_with_interchain_token(
+                &source_chain,
+                &message_id,
+                &source_address,
+                &payload,
+                &token_id,
+                &token_address,
+                &amount,
+            );
+        }
+    }
+
+    /// Removes the failed execution for `source_chain` and `message_id` so its escrowed tokens can be released.
+    fn take_failed_execution(
+        env: &Env,
+        caller: &Address,
+        source_chain: String,
+        message_id: String,
+    ) -> Result<FailedExecution, ContractError> {
+        caller.require_auth();
+
+        let key = DataKey::FailedExecution(FailedExecutionKey {
+            source_chain,
+            message_id,
+        });
+
+        let failed_execution: FailedExecution = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ContractError::FailedExecutionNotFound)?;
+
+        Self::ensure_recovery_caller(env, caller, &failed_execution.destination_address)?;
+
+        env.storage().persistent().remove(&key);
+
+        Ok(failed_execution)
+    }
+
+    /// Ensures that the `caller` can act on a failed execution for `destination_address`,
+    /// i.e. that it's either the destination contract itself or the recovery address.
+    fn ensure_recovery_caller(
+        env: &Env,
+        caller: &Address,
+        destination_address: &Address,
+    ) -> Result<(), ContractError> {
+        ensure!(
+            caller == destination_address || Some(caller.clone()) == Self::recovery_address(env),
+            ContractError::NotRecoveryCaller
+        );
+
+        Ok(())
+    }
+
+    /// Records the decimals of the token with `token_id` on this chain.
+    fn record_local_decimals(env: &Env, token_id: BytesN<32>, decimals: u32) {
+        token_decimals::set_token_decimals(env, token_id, Self::chain_name(env), decimals);
+    }
+
+    /// Retrieves the recorded decimals of the token on this chain and on `chain`, if both are known.
+    fn local_and_remote_decimals(
+        env: &Env,
+        token_id: BytesN<32>,
+        chain: String,
+    ) -> Option<(u32, u32)> {
+        let local_decimals =
+            token_decimals::token_decimals(env, token_id.clone(), Self::chain_name(env))?;
+        let remote_decimals = token_decimals::token_decimals(env, token_id, chain)?;
+
+        Some((local_decimals, remote_decimals))
+    }
+
+    /// Scales the amount of an outbound interchain transfer from the token's decimals on this chain to its decimals on `destination_chain`.
+    ///
+    /// Returns the scaled amount and the dust, i.e. the part of `amount` that can't be sent, in the decimals on this chain.
+    fn scale_outbound_amount(
+        env: &Env,
+        token_id: BytesN<32>,
+        destination_chain: String,
+        amount: i128,
+    ) -> Result<(i128, i128), ContractError> {
+        Self::local_and_remote_decimals(env, token_id, destination_chain).map_or(
+            Ok((amount, 0)),
+            |(local_decimals, remote_decimals)| {
+                token_decimals::scale_amount(amount, local_decimals, remote_decimals)
+            },
+        )
+    }
+
+    /// Scales the amount of an inbound interchain transfer from the token's decimals on `source_chain` to its decimals on this chain.
+    ///
+    /// Returns the scaled amount and the dust, i.e. the part of `amount` that isn't given to the recipient, in the decimals on `source_chain`.
+    /// This never fails, so that the message can always be executed, even if the whole amount becomes dust.
+    fn scale_inbound_amount(
+        env: &Env,
+        token_id: BytesN<32>,
+        source_chain: String,
+        amount: i128,
+    ) -> (i128, i128) {
+        Self::local_and_remote_decimals(env, token_id, source_chain).map_or(
+            (amount, 0),
+            |(local_decimals, remote_decimals)| {
+                token_decimals::scale_amount_or_dust(amount, remote_decimals, local_decimals)
+            },
+        )
+    }
+
     fn get_execute_params(
         env: &Env,
         source_chain: String,
@@ -626,11 +1567,67 @@ impl InterchainTokenService {
     }
 
     fn set_token_id_config(env: &Env, token_id: BytesN<32>, token_data: TokenIdConfigValue) {
+        let token_address_key = DataKey::RegisteredTokenAddress(token_data.token_address.clone());
+        env.storage()
+            .persistent()
+            .set(&token_address_key, &token_id);
+        extend_persistent_ttl(env, &token_address_key);
+
         env.storage()
             .persistent()
             .set(&DataKey::TokenIdConfigKey(token_id), &token_data);
     }
 
+    /// Ensures that an existing token can be registered under a custom token manager type.
+    ///
+    /// A token address can only be registered under a single token ID, which also excludes tokens deployed by this
+    /// contract. This keeps the balance locked by this contract for a lock/unlock token separate per token ID.
+    /// For mint/burn based types, this contract must be the admin of the token, since it mints the token on receipt.
+    ///
+    /// # Errors
+    /// * `ContractError::InvalidTokenManagerType` - If the token manager type is `NativeInterchainToken`.
+    /// * `ContractError::TokenAlreadyRegistered` - If the token address is already registered under a token ID.
+    /// * `ContractError::InvalidTokenAddress` - If the token address is not a token contract.
+    /// * `ContractError::TokenNotMintable` - If this contract is not the admin of a mint/burn based token.
+    fn ensure_token_registrable(
+        env: &Env,
+        token_address: &Address,
+        token_manager_type: TokenManagerType,
+    ) -> Result<(), ContractError> {
+        ensure!(
+            token_manager_type != TokenManagerType::NativeInterchainToken,
+            ContractError::InvalidTokenManagerType
+        );
+
+        ensure!(
+            !env.storage()
+                .persistent()
+                .has(&DataKey::RegisteredTokenAddress(token_address.clone())),
+            ContractError::TokenAlreadyRegistered
+        );
+
+        ensure!(
+            matches!(
+                token::Client::new(env, token_address).try_decimals(),
+                Ok(Ok(_))
+            ),
+            ContractError::InvalidTokenAddress
+        );
+
+        if matches!(
+            token_manager_type,
+            TokenManagerType::MintBurn | TokenManagerType::MintBurnFrom
+        ) {
+            let admin = StellarAssetClient::new(env, token_address).try_admin();
+            ensure!(
+                matches!(admin, Ok(Ok(admin)) if admin == env.current_contract_address()),
+                ContractError::TokenNotMintable
+            );
+        }
+
+        Ok(())
+    }
+
     /// Retrieves the configuration value for the specified token ID.
     ///
     /// # Arguments
@@ -668,6 +1665,35 @@ impl InterchainTokenService {
         Ok(config)
     }
 
+    /// Returns the address of the interchain token deployed by this contract for the given `token_id`.
+    ///
+    /// # Errors
+    /// - `ContractError::InvalidTokenId`: If the token ID does not exist in storage.
+    /// - `ContractError::InvalidTokenManagerType`: If the token was not deployed by this contract.
+    fn native_interchain_token_address(
+        env: &Env,
+        token_id: BytesN<32>,
+    ) -> Result<Address, ContractError> {
+        let TokenIdConfigValue {
+            token_address,
+            token_manager_type,
+        } = Self::token_id_config_with_extended_ttl(env, token_id)?;
+
+        ensure!(
+            token_manager_type == TokenManagerType::NativeInterchainToken,
+            ContractError::InvalidTokenManagerType
+        );
+
+        Ok(token_address)
+    }
+
+    fn custom_token_deploy_salt(env: &Env, deployer: Address, salt: BytesN<32>) -> BytesN<32> {
+        let chain_name_hash = Self::chain_name_hash(env);
+        env.crypto()
+            .keccak256(&(PREFIX_CUSTOM_TOKEN_SALT, chain_name_hash, deployer, salt).to_xdr(env))
+            .into()
+    }
+
     fn chain_name_hash(env: &Env) -> BytesN<32> {
         let chain_name = Self::chain_name(env);
         env.crypto().keccak256(&chain_name.to_xdr(env)).into()
@@ -713,6 +1739,14 @@ impl InterchainTokenService {
             ContractError::InvalidTokenMetaData
         );
 
+        Self::record_local_decimals(env, token_id.clone(), token_metadata.decimal);
+        token_decimals::set_token_decimals(
+            env,
+            token_id.clone(),
+            destination_chain.clone(),
+            token_metadata.decimal,
+        );
+
         let message = Message::DeployInterchainToken(DeployInterchainToken {
             token_id: token_id.clone(),
             name: token_metadata.name.clone(),
@@ -721,7 +1755,7 @@ impl InterchainTokenService {
             minter: None,
         });
 
-        InterchainTokenDeploymentStartedEvent {
+        TokenDeploymentStartedEvent {
             token_id: token_id.clone(),
             token_address,
             destination_chain: destination_chain.clone(),
@@ -756,6 +1790,8 @@ impl InterchainTokenService {
                 ),
             );
 
+        Self::record_local_decimals(env, token_id.clone(), token_metadata.decimal);
+
         InterchainTokenDeployedEvent {
             token_id,
             token_address: deployed_address.clone(),
@@ -22,4 +22,22 @@ pub enum ContractError {
     InvalidTokenMetaData = 16,
     InvalidTokenId = 17,
     TokenAlreadyDeployed = 18,
+    ContractPaused = 19,
+    InvalidFlowLimit = 20,
+    FlowLimitExceeded = 21,
+    FlowAmountOverflow = 22,
+    InvalidTokenManagerType = 23,
+    MessageAlreadyApproved = 24,
+    MessageAlreadyExecuted = 25,
+    ExpressReceiptAlreadySet = 26,
+    InvalidTtlConfig = 27,
+    FailedExecutionNotFound = 28,
+    NotRecoveryCaller = 29,
+    ScaledAmountOverflow = 30,
+    ScaledAmountZero = 31,
+    InvalidTokenAddress = 32,
+    TokenNotMintable = 33,
+    MissingRole = 34,
+    UnapprovedWasmHash = 35,
+    MessageNotApproved = 36,
 }
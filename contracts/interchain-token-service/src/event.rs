@@ -1,232 +1,236 @@
-use core::fmt::Debug;
+use axelar_soroban_std::IntoEvent;
+use soroban_sdk::{Address, Bytes, BytesN, String};
 
-use axelar_soroban_std::events::Event;
-use soroban_sdk::{Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Topics, Val, Vec};
+use crate::types::TokenManagerType;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
 pub struct TrustedChainSetEvent {
+    #[topic]
     pub chain: String,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
 pub struct TrustedChainRemovedEvent {
+    #[topic]
     pub chain: String,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
 pub struct InterchainTokenDeployedEvent {
+    #[topic]
     pub token_id: BytesN<32>,
+    #[topic]
     pub token_address: Address,
+    #[topic]
     pub name: String,
+    #[topic]
     pub symbol: String,
+    #[topic]
     pub decimals: u32,
+    #[topic]
     pub minter: Option<Address>,
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct InterchainTokenDeploymentStartedEvent {
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct TokenDeploymentStartedEvent {
+    #[topic]
     pub token_id: BytesN<32>,
+    #[topic]
     pub token_address: Address,
+    #[topic]
     pub destination_chain: String,
+    #[topic]
     pub name: String,
+    #[topic]
     pub symbol: String,
+    #[topic]
     pub decimals: u32,
+    #[topic]
     pub minter: Option<Address>,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
 pub struct InterchainTokenIdClaimedEvent {
+    #[topic]
     pub token_id: BytesN<32>,
+    #[topic]
     pub deployer: Address,
+    #[topic]
     pub salt: BytesN<32>,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct InterchainTransferSentEvent {
+    #[topic]
     pub token_id: BytesN<32>,
+    #[topic]
     pub source_address: Address,
+    #[topic]
     pub destination_chain: String,
+    #[topic]
     pub destination_address: Bytes,
+    #[topic]
     pub amount: i128,
+    #[data]
     pub data: Option<Bytes>,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct InterchainTransferReceivedEvent {
+    #[topic]
     pub source_chain: String,
+    #[topic]
     pub token_id: BytesN<32>,
+    #[topic]
     pub source_address: Bytes,
+    #[topic]
     pub destination_address: Address,
+    #[topic]
     pub amount: i128,
+    #[data]
     pub data: Option<Bytes>,
 }
 
-impl Event for TrustedChainSetEvent {
-    fn topics(&self, env: &Env) -> impl Topics + Debug {
-        (Symbol::new(env, "trusted_chain_set"), self.chain.to_val())
-    }
-
-    fn data(&self, env: &Env) -> impl IntoVal<Env, Val> + Debug {
-        Vec::<Val>::new(env)
-    }
-}
-
-impl Event for TrustedChainRemovedEvent {
-    fn topics(&self, env: &Env) -> impl Topics + Debug {
-        (
-            Symbol::new(env, "trusted_chain_removed"),
-            self.chain.to_val(),
-        )
-    }
-
-    fn data(&self, env: &Env) -> impl IntoVal<Env, Val> + Debug {
-        Vec::<Val>::new(env)
-    }
-}
-
-impl Event for InterchainTokenDeployedEvent {
-    fn topics(&self, env: &Env) -> impl Topics + Debug {
-        (
-            Symbol::new(env, "interchain_token_deployed"),
-            self.token_id.to_val(),
-            self.token_address.to_val(),
-            self.name.to_val(),
-            self.symbol.to_val(),
-            self.decimals,
-            self.minter.clone(),
-        )
-    }
-
-    fn data(&self, env: &Env) -> impl IntoVal<Env, Val> + Debug {
-        Vec::<Val>::new(env)
-    }
-}
-
-impl Event for InterchainTokenDeploymentStartedEvent {
-    fn topics(&self, env: &Env) -> impl Topics + Debug {
-        (
-            Symbol::new(env, "token_deployment_started"),
-            self.token_id.to_val(),
-            self.token_address.to_val(),
-            self.destination_chain.to_val(),
-            self.name.to_val(),
-            self.symbol.to_val(),
-            self.decimals,
-            self.minter.clone(),
-        )
-    }
-
-    fn data(&self, env: &Env) -> impl IntoVal<Env, Val> + Debug {
-        Vec::<Val>::new(env)
-    }
-}
-
-impl Event for InterchainTokenIdClaimedEvent {
-    fn topics(&self, env: &Env) -> impl Topics + Debug {
-        (
-            Symbol::new(env, "interchain_token_id_claimed"),
-            self.token_id.to_val(),
-            self.deployer.to_val(),
-            self.salt.to_val(),
-        )
-    }
-
-    fn data(&self, env: &Env) -> impl IntoVal<Env, Val> + Debug {
-        Vec::<Val>::new(env)
-    }
-}
-
-impl Event for InterchainTransferSentEvent {
-    fn topics(&self, env: &Env) -> impl Topics + Debug {
-        (
-            Symbol::new(env, "interchain_transfer_sent"),
-            self.token_id.to_val(),
-            self.source_address.to_val(),
-            self.destination_chain.to_val(),
-            self.destination_address.to_val(),
-            self.amount,
-        )
-    }
-
-    fn data(&self, _env: &Env) -> impl IntoVal<Env, Val> + Debug {
-        (self.data.clone(),)
-    }
-}
-
-impl Event for InterchainTransferReceivedEvent {
-    fn topics(&self, env: &Env) -> impl Topics + Debug {
-        (
-            Symbol::new(env, "interchain_transfer_received"),
-            self.source_chain.to_val(),
-            self.token_id.to_val(),
-            self.source_address.to_val(),
-            self.destination_address.to_val(),
-            self.amount,
-        )
-    }
-
-    fn data(&self, _env: &Env) -> impl IntoVal<Env, Val> + Debug {
-        (self.data.clone(),)
-    }
-}
-
-#[cfg(any(test, feature = "testutils"))]
-use axelar_soroban_std::impl_event_testutils;
-
-#[cfg(any(test, feature = "testutils"))]
-impl_event_testutils!(TrustedChainSetEvent, (Symbol, String), ());
-
-#[cfg(any(test, feature = "testutils"))]
-impl_event_testutils!(TrustedChainRemovedEvent, (Symbol, String), ());
-
-#[cfg(any(test, feature = "testutils"))]
-impl_event_testutils!(
-    InterchainTokenDeployedEvent,
-    (
-        Symbol,
-        BytesN<32>,
-        Address,
-        String,
-        String,
-        u32,
-        Option<Address>
-    ),
-    ()
-);
-
-#[cfg(any(test, feature = "testutils"))]
-impl_event_testutils!(
-    InterchainTokenDeploymentStartedEvent,
-    (
-        Symbol,
-        BytesN<32>,
-        Address,
-        String,
-        String,
-        String,
-        u32,
-        Option<Address>
-    ),
-    ()
-);
-
-#[cfg(any(test, feature = "testutils"))]
-impl_event_testutils!(
-    InterchainTokenIdClaimedEvent,
-    (Symbol, BytesN<32>, Address, BytesN<32>),
-    ()
-);
-
-#[cfg(any(test, feature = "testutils"))]
-impl_event_testutils!(
-    InterchainTransferSentEvent,
-    (Symbol, BytesN<32>, Address, String, Bytes, i128),
-    (Option<Bytes>)
-);
-
-#[cfg(any(test, feature = "testutils"))]
-impl_event_testutils!(
-    InterchainTransferReceivedEvent,
-    (Symbol, String, BytesN<32>, Bytes, Address, i128),
-    (Option<Bytes>)
-);
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct FlowLimitSetEvent {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub flow_limit: Option<i128>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct TokenMetadataRegisteredEvent {
+    #[topic]
+    pub token_address: Address,
+    #[topic]
+    pub decimals: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct LinkTokenStartedEvent {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub destination_chain: String,
+    #[topic]
+    pub source_token_address: Address,
+    #[topic]
+    pub destination_token_address: Bytes,
+    #[topic]
+    pub token_manager_type: TokenManagerType,
+    #[data]
+    pub params: Option<Bytes>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct TokenLinkedEvent {
+    #[topic]
+    pub source_chain: String,
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub token_address: Address,
+    #[topic]
+    pub token_manager_type: TokenManagerType,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct ExpressExecutedEvent {
+    #[topic]
+    pub source_chain: String,
+    #[topic]
+    pub message_id: String,
+    #[topic]
+    pub source_address: Bytes,
+    #[topic]
+    pub payload_hash: BytesN<32>,
+    #[topic]
+    pub express_executor: Address,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct ExpressExecutionFulfilledEvent {
+    #[topic]
+    pub source_chain: String,
+    #[topic]
+    pub message_id: String,
+    #[topic]
+    pub source_address: Bytes,
+    #[topic]
+    pub payload_hash: BytesN<32>,
+    #[topic]
+    pub express_executor: Address,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct ExecutionFailedEvent {
+    #[topic]
+    pub source_chain: String,
+    #[topic]
+    pub message_id: String,
+    #[topic]
+    pub destination_address: Address,
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub amount: i128,
+    #[data]
+    pub data: Bytes,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct FailedExecutionClaimedEvent {
+    #[topic]
+    pub source_chain: String,
+    #[topic]
+    pub message_id: String,
+    #[topic]
+    pub destination_address: Address,
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub amount: i128,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct RecoveryAddressSetEvent {
+    #[topic]
+    pub recovery_address: Address,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct TokenDecimalsSetEvent {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub chain: String,
+    #[topic]
+    pub decimals: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct DustRecordedEvent {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub chain: String,
+    #[topic]
+    pub message_id: String,
+    #[topic]
+    pub dust: i128,
+}
@@ -7,6 +7,9 @@
 
 use soroban_sdk::{contractclient, Address, Bytes, BytesN, Env, String};
 
+#[doc(hidden)]
+pub use axelar_soroban_std::executable::execute_validated;
+
 /// Interface for an Interchain Token Executable app.
 #[contractclient(name = "InterchainTokenExecutableClient")]
 pub trait InterchainTokenExecutableInterface {
@@ -15,6 +18,7 @@ pub trait InterchainTokenExecutableInterface {
 
     /// Execute a cross-chain message with the given payload and token.
     /// [`validate`] must be called first in the implementation of [`execute_with_interchain_token`].
+    /// Implementing [CustomInterchainTokenExecutable] and [crate::impl_interchain_token_executable] takes care of this.
     fn execute_with_interchain_token(
         env: &Env,
         source_chain: String,
@@ -31,3 +35,109 @@ pub trait InterchainTokenExecutableInterface {
         Self::interchain_token_service(env).require_auth();
     }
 }
+
+/// A cross-chain message received with an interchain token, passed to [CustomInterchainTokenExecutable::execute_with_token].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InterchainTokenMessage {
+    pub source_chain: String,
+    pub message_id: String,
+    pub source_address: Bytes,
+    pub token_id: BytesN<32>,
+    pub token_address: Address,
+    pub amount: i128,
+    pub payload: Bytes,
+}
+
+/// The app logic of an Interchain Token Executable app.
+///
+/// [crate::impl_interchain_token_executable] implements [InterchainTokenExecutableInterface] on top of this trait,
+/// so the exposed `execute_with_interchain_token` entrypoint always requires the interchain token service's auth
+/// before running [CustomInterchainTokenExecutable::execute_with_token].
+pub trait CustomInterchainTokenExecutable {
+    /// Error type returned if the message can't be executed.
+    type Error: Into<soroban_sdk::Error>;
+
+    /// Return the trusted interchain token service contract address.
+    /// Prefixed to avoid clashing with the `interchain_token_service` entrypoint generated by [crate::impl_interchain_token_executable].
+    fn __interchain_token_service(env: &Env) -> Address;
+
+    /// Execute a cross-chain message received with a token. Only called once the interchain token service has authorized the call.
+    fn execute_with_token(env: &Env, message: InterchainTokenMessage) -> Result<(), Self::Error>;
+}
+
+/// Implements the [InterchainTokenExecutableInterface] for a Soroban contract that implements [CustomInterchainTokenExecutable].
+///
+/// The generated `execute_with_interchain_token` entrypoint requires the auth of the interchain token service,
+/// and only then runs [CustomInterchainTokenExecutable::execute_with_token] with the received message.
+///
+/// # Example
+/// ```rust
+/// # mod test {
+/// use interchain_token_service::executable::{CustomInterchainTokenExecutable, InterchainTokenMessage};
+/// use interchain_token_service::impl_interchain_token_executable;
+/// use soroban_sdk::{contract, symbol_short, Address, Env};
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// impl_interchain_token_executable!(Contract);
+///
+/// impl CustomInterchainTokenExecutable for Contract {
+///     type Error = soroban_sdk::Error;
+///
+///     fn __interchain_token_service(env: &Env) -> Address {
+///         env.storage().instance().get(&symbol_short!("its")).unwrap()
+///     }
+///
+///     fn execute_with_token(env: &Env, message: InterchainTokenMessage) -> Result<(), Self::Error> {
+///         // only reached when called by the interchain token service
+///         Ok(())
+///     }
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_interchain_token_executable {
+    ($contract:ident) => {
+        use $crate::executable::InterchainTokenExecutableInterface as _;
+
+        #[soroban_sdk::contractimpl]
+        impl $crate::executable::InterchainTokenExecutableInterface for $contract {
+            fn interchain_token_service(env: &soroban_sdk::Env) -> soroban_sdk::Address {
+                <Self as $crate::executable::CustomInterchainTokenExecutable>::__interchain_token_service(env)
+            }
+
+            fn execute_with_interchain_token(
+                env: &soroban_sdk::Env,
+                source_chain: soroban_sdk::String,
+                message_id: soroban_sdk::String,
+                source_address: soroban_sdk::Bytes,
+                payload: soroban_sdk::Bytes,
+                token_id: soroban_sdk::BytesN<32>,
+                token_address: soroban_sdk::Address,
+                amount: i128,
+            ) {
+                // validate panics if the interchain token service didn't authorize the call
+                let validation = Ok::<_, soroban_sdk::Error>(
+                    <Self as $crate::executable::InterchainTokenExecutableInterface>::validate(env),
+                );
+
+                $crate::executable::execute_validated(env, validation, || {
+                    let message = $crate::executable::InterchainTokenMessage {
+                        source_chain,
+                        message_id,
+                        source_address,
+                        token_id,
+                        token_address,
+                        amount,
+                        payload,
+                    };
+
+                    <Self as $crate::executable::CustomInterchainTokenExecutable>::execute_with_token(
+                        env, message,
+                    )
+                });
+            }
+        }
+    };
+}
@@ -0,0 +1,152 @@
+use axelar_soroban_std::{ensure, events::Event, ttl::LEDGERS_PER_DAY};
+use soroban_sdk::{BytesN, Env};
+
+use crate::error::ContractError;
+use crate::event::FlowLimitSetEvent;
+use crate::storage_types::{DataKey, FlowKey};
+
+/// Duration of a flow limit epoch in seconds (6 hours), matching the EVM ITS.
+const EPOCH_TIME: u64 = 6 * 60 * 60;
+
+/// Flow amounts only need to be retained for the duration of the epoch they belong to.
+const EPOCH_TTL: u32 = LEDGERS_PER_DAY / 4;
+
+enum FlowDirection {
+    /// An interchain transfer coming into this chain
+    In,
+    /// An interchain transfer going out from this chain
+    Out,
+}
+
+impl FlowDirection {
+    const fn flow_key(&self, token_id: BytesN<32>, epoch: u64) -> DataKey {
+        let flow_key = FlowKey { token_id, epoch };
+
+        match self {
+            Self::In => DataKey::FlowIn(flow_key),
+            Self::Out => DataKey::FlowOut(flow_key),
+        }
+    }
+
+    const fn reverse(&self) -> Self {
+        match self {
+            Self::In => Self::Out,
+            Self::Out => Self::In,
+        }
+    }
+
+    fn flow(&self, env: &Env, token_id: BytesN<32>) -> i128 {
+        env.storage()
+            .temporary()
+            .get(&self.flow_key(token_id, current_epoch(env)))
+            .unwrap_or(0)
+    }
+
+    fn update_flow(&self, env: &Env, token_id: BytesN<32>, new_flow: i128) {
+        let key = self.flow_key(token_id, current_epoch(env));
+
+        env.storage().temporary().set(&key, &new_flow);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, EPOCH_TTL, EPOCH_TTL);
+    }
+
+    /// Adds `flow_amount` to the flow of the current epoch in this direction.
+    ///
+    /// The net flow in this direction (i.e. the flow in this direction minus the flow in the reverse direction)
+    /// must not exceed the flow limit of the token. If no flow limit is set, the flow is not tracked.
+    fn add_flow(
+        &self,
+        env: &Env,
+        token_id: BytesN<32>,
+        flow_amount: i128,
+    ) -> Result<(), ContractError> {
+        let Some(flow_limit) = flow_limit(env, token_id.clone()) else {
+            return Ok(());
+        };
+
+        ensure!(flow_amount <= flow_limit, ContractError::FlowLimitExceeded);
+
+        let flow_to_add = self.flow(env, token_id.clone());
+        let flow_to_compare = self.reverse().flow(env, token_id.clone());
+
+        let new_flow = flow_to_add
+            .checked_add(flow_amount)
+            .ok_or(ContractError::FlowAmountOverflow)?;
+        let max_allowed = flow_to_compare
+            .checked_add(flow_limit)
+            .ok_or(ContractError::FlowAmountOverflow)?;
+
+        ensure!(new_flow <= max_allowed, ContractError::FlowLimitExceeded);
+
+        self.update_flow(env, token_id, new_flow);
+
+        Ok(())
+    }
+}
+
+fn current_epoch(env: &Env) -> u64 {
+    env.ledger().timestamp() / EPOCH_TIME
+}
+
+pub fn flow_limit(env: &Env, token_id: BytesN<32>) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FlowLimit(token_id))
+}
+
+/// Sets the flow limit of a token. A flow limit of `None` disables flow limiting for the token.
+///
+/// As in the EVM ITS, a flow limit of `Some(0)` means no limit, so it disables flow limiting as well
+/// instead of rejecting every transfer.
+pub fn set_flow_limit(
+    env: &Env,
+    token_id: BytesN<32>,
+    flow_limit: Option<i128>,
+) -> Result<(), ContractError> {
+    let key = DataKey::FlowLimit(token_id.clone());
+
+    if let Some(limit) = flow_limit {
+        ensure!(limit >= 0, ContractError::InvalidFlowLimit);
+    }
+
+    let flow_limit = flow_limit.filter(|limit| *limit != 0);
+
+    if let Some(flow_limit) = flow_limit {
+        env.storage().persistent().set(&key, &flow_limit);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    FlowLimitSetEvent {
+        token_id,
+        flow_limit,
+    }
+    .emit(env);
+
+    Ok(())
+}
+
+pub fn flow_out_amount(env: &Env, token_id: BytesN<32>) -> i128 {
+    FlowDirection::Out.flow(env, token_id)
+}
+
+pub fn flow_in_amount(env: &Env, token_id: BytesN<32>) -> i128 {
+    FlowDirection::In.flow(env, token_id)
+}
+
+pub fn add_flow_out(
+    env: &Env,
+    token_id: BytesN<32>,
+    flow_amount: i128,
+) -> Result<(), ContractError> {
+    FlowDirection::Out.add_flow(env, token_id, flow_amount)
+}
+
+pub fn add_flow_in(
+    env: &Env,
+    token_id: BytesN<32>,
+    flow_amount: i128,
+) -> Result<(), ContractError> {
+    FlowDirection::In.add_flow(env, token_id, flow_amount)
+}
@@ -3,7 +3,10 @@ use axelar_soroban_std::types::Token;
 use soroban_sdk::{contractclient, Address, Bytes, BytesN, Env, String};
 use soroban_token_sdk::metadata::TokenMetadata;
 
-use crate::{error::ContractError, types::TokenManagerType};
+use crate::{
+    error::ContractError,
+    types::{FailedExecution, TokenManagerType},
+};
 
 #[allow(dead_code)]
 #[contractclient(name = "InterchainTokenServiceClient")]
@@ -20,9 +23,29 @@ pub trait InterchainTokenServiceInterface: AxelarExecutableInterface {
 
     fn is_trusted_chain(env: &Env, chain: String) -> bool;
 
-    fn set_trusted_chain(env: &Env, chain: String) -> Result<(), ContractError>;
+    /// Adds `chain` to the trusted chains.
+    ///
+    /// Can only be called by accounts with the `CHAIN_MANAGER_ROLE`.
+    fn set_trusted_chain(env: &Env, caller: Address, chain: String) -> Result<(), ContractError>;
 
-    fn remove_trusted_chain(env: &Env, chain: String) -> Result<(), ContractError>;
+    /// Removes `chain` from the trusted chains.
+    ///
+    /// Can only be called by accounts with the `CHAIN_MANAGER_ROLE`.
+    fn remove_trusted_chain(env: &Env, caller: Address, chain: String)
+        -> Result<(), ContractError>;
+
+    /// Returns whether the contract is currently paused.
+    fn paused(env: &Env) -> bool;
+
+    /// Pauses the contract, so interchain transfers and token deployments fail until it is unpaused.
+    ///
+    /// Can only be called by accounts with the `PAUSER_ROLE`.
+    fn pause(env: &Env, caller: Address) -> Result<(), ContractError>;
+
+    /// Unpauses the contract.
+    ///
+    /// Can only be called by accounts with the `PAUSER_ROLE`.
+    fn unpause(env: &Env, caller: Address) -> Result<(), ContractError>;
 
     fn interchain_token_deploy_salt(env: &Env, deployer: Address, salt: BytesN<32>) -> BytesN<32>;
 
@@ -34,6 +57,61 @@ pub trait InterchainTokenServiceInterface: AxelarExecutableInterface {
 
     fn token_manager_type(env: &Env, token_id: BytesN<32>) -> TokenManagerType;
 
+    /// Extends the TTL of the configuration of the token with the given `token_id`.
+    ///
+    /// Anyone can call this to keep the registration of a rarely transferred token from being archived.
+    fn extend_token_config_ttl(env: &Env, token_id: BytesN<32>) -> Result<(), ContractError>;
+
+    /// Extends the TTL of the trusted chain entry for `chain`.
+    ///
+    /// Anyone can call this.
+    fn extend_trusted_chain_ttl(env: &Env, chain: String) -> Result<(), ContractError>;
+
+    /// Retrieves the flow limit for the token associated with the specified token ID.
+    /// Returns `None` if no flow limit is set.
+    fn flow_limit(env: &Env, token_id: BytesN<32>) -> Option<i128>;
+
+    /// Retrieves the amount that has flowed out of the chain for the specified token ID during the current epoch.
+    fn flow_out_amount(env: &Env, token_id: BytesN<32>) -> i128;
+
+    /// Retrieves the amount that has flowed into the chain for the specified token ID during the current epoch.
+    fn flow_in_amount(env: &Env, token_id: BytesN<32>) -> i128;
+
+    /// Sets the flow limit for the token associated with the specified token ID. Setting it to `None` removes the limit.
+    /// As in the EVM ITS, a flow limit of `Some(0)` means no limit and removes it as well.
+    ///
+    /// Can only be called by accounts with the `FLOW_LIMITER_ROLE`.
+    fn set_flow_limit(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        flow_limit: Option<i128>,
+    ) -> Result<(), ContractError>;
+
+    /// Authorizes or deauthorizes `account` to hold and transfer the interchain token with the given `token_id`.
+    ///
+    /// Only supported for interchain tokens deployed by this contract, which is their admin.
+    ///
+    /// Can only be called by the operator.
+    fn set_token_authorized(
+        env: &Env,
+        token_id: BytesN<32>,
+        account: Address,
+        authorize: bool,
+    ) -> Result<(), ContractError>;
+
+    /// Claws back `amount` of the interchain token with the given `token_id` from `from`.
+    ///
+    /// Only supported for interchain tokens deployed by this contract, which is their admin.
+    ///
+    /// Can only be called by the operator.
+    fn clawback_token(
+        env: &Env,
+        token_id: BytesN<32>,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), ContractError>;
+
     fn deploy_interchain_token(
         env: &Env,
         deployer: Address,
@@ -74,4 +152,124 @@ pub trait InterchainTokenServiceInterface: AxelarExecutableInterface {
         env: &Env,
         token_address: Address,
     ) -> Result<BytesN<32>, ContractError>;
+
+    fn register_custom_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        token_address: Address,
+        token_manager_type: TokenManagerType,
+    ) -> Result<BytesN<32>, ContractError>;
+
+    /// Registers the decimals of a token with the ITS hub, so that the token can be linked to tokens on other chains.
+    fn register_token_metadata(
+        env: &Env,
+        token_address: Address,
+        spender: Address,
+        gas_token: Token,
+    ) -> Result<(), ContractError>;
+
+    /// Links a custom token registered via `register_custom_token` to an existing token on the destination chain.
+    fn link_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        destination_chain: String,
+        destination_token_address: Bytes,
+        token_manager_type: TokenManagerType,
+        link_params: Option<Bytes>,
+        gas_token: Token,
+    ) -> Result<BytesN<32>, ContractError>;
+
+    /// Retrieves the express executor that fronted the tokens for the message with the given ID and payload hash.
+    /// Returns `None` if the message has not been express executed, or if the express executor has already been repaid.
+    fn express_executor(env: &Env, message_id: String, payload_hash: BytesN<32>)
+        -> Option<Address>;
+
+    /// Executes an inbound interchain transfer before it is approved by the gateway, with the tokens provided by the `express_executor`.
+    ///
+    /// Once the message is approved and executed via `execute`, the tokens are given to the `express_executor` instead of the recipient.
+    fn express_execute(
+        env: &Env,
+        express_executor: Address,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload: Bytes,
+    ) -> Result<(), ContractError>;
+
+    /// Retrieves the address that can escrow, claim or retry failed executions on behalf of their recipients.
+    /// Returns `None` if no recovery address is set.
+    fn recovery_address(env: &Env) -> Option<Address>;
+
+    /// Sets the address that can escrow, claim or retry failed executions on behalf of their recipients.
+    ///
+    /// Can only be called by the owner.
+    fn set_recovery_address(env: &Env, recovery_address: Address);
+
+    /// Retrieves the escrowed inbound interchain transfer from `source_chain` with the given message ID.
+    /// Returns `None` if there is no failed execution for the message, or if it has already been claimed or retried.
+    fn failed_execution(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+    ) -> Option<FailedExecution>;
+
+    /// Moves an approved inbound interchain transfer with data, whose destination contract fails to execute, into escrow.
+    ///
+    /// The tokens are given to the destination contract before it is executed, so a failing destination contract reverts
+    /// the whole `execute` call and the message stays approved. This executes the message without calling the destination
+    /// contract, and holds the tokens in escrow until they're claimed or the execution is retried.
+    ///
+    /// The `caller` must be the destination contract of the transfer or the recovery address, and must authenticate.
+    fn escrow_failed_execution(
+        env: &Env,
+        caller: Address,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload: Bytes,
+    ) -> Result<(), ContractError>;
+
+    /// Gives the escrowed tokens of a failed execution to its destination contract, without executing it.
+    ///
+    /// The `caller` must be the destination contract of the transfer or the recovery address, and must authenticate.
+    fn claim_failed_execution(
+        env: &Env,
+        caller: Address,
+        source_chain: String,
+        message_id: String,
+    ) -> Result<(), ContractError>;
+
+    /// Gives the escrowed tokens of a failed execution to its destination contract and executes it again.
+    ///
+    /// The `caller` must be the destination contract of the transfer or the recovery address, and must authenticate.
+    fn retry_failed_execution(
+        env: &Env,
+        caller: Address,
+        source_chain: String,
+        message_id: String,
+    ) -> Result<(), ContractError>;
+
+    /// Retrieves the decimals of the token with the given `token_id` on `chain`, which can also be this chain.
+    /// Returns `None` if they are unknown. Amounts are only scaled if the decimals on both chains are known.
+    fn token_decimals(env: &Env, token_id: BytesN<32>, chain: String) -> Option<u32>;
+
+    /// Sets the decimals of the token with the given `token_id` on `chain`.
+    ///
+    /// Decimals on this chain are recorded when a token is deployed, linked or its metadata is registered,
+    /// and decimals on other chains when a token is deployed to or from them. Link messages don't carry the decimals
+    /// of the remote token, so they need to be set for linked tokens whose decimals differ between chains.
+    ///
+    /// Can only be called by the owner.
+    fn set_token_decimals(
+        env: &Env,
+        token_id: BytesN<32>,
+        chain: String,
+        decimals: u32,
+    ) -> Result<(), ContractError>;
+
+    /// Retrieves the total dust of inbound transfers of the token with the given `token_id` from `chain`,
+    /// i.e. the amount in the token's decimals on `chain` that couldn't be represented on this chain and wasn't given to the recipients.
+    fn dust(env: &Env, token_id: BytesN<32>, chain: String) -> i128;
 }
@@ -14,8 +14,10 @@ cfg_if::cfg_if! {
     } else {
         mod abi;
         pub mod event;
+        mod flow_limit;
         mod storage_types;
         mod token_handler;
+        mod token_decimals;
         mod contract;
 
         pub use contract::{InterchainTokenService, InterchainTokenServiceClient};
@@ -12,6 +12,43 @@ pub enum DataKey {
     ChainName,
     InterchainTokenWasmHash,
     TokenIdConfigKey(BytesN<32>),
+    RegisteredTokenAddress(Address),
+    FlowLimit(BytesN<32>),
+    FlowOut(FlowKey),
+    FlowIn(FlowKey),
+    ExpressReceipt(ExpressReceiptKey),
+    RecoveryAddress,
+    FailedExecution(FailedExecutionKey),
+    TokenDecimals(TokenChainKey),
+    Dust(TokenChainKey),
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FlowKey {
+    pub token_id: BytesN<32>,
+    pub epoch: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TokenChainKey {
+    pub token_id: BytesN<32>,
+    pub chain: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FailedExecutionKey {
+    pub source_chain: String,
+    pub message_id: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ExpressReceiptKey {
+    pub message_id: String,
+    pub payload_hash: BytesN<32>,
 }
 
 #[contracttype]
@@ -0,0 +1,173 @@
+use axelar_soroban_std::ttl::extend_persistent_ttl;
+use axelar_soroban_std::{ensure, events::Event};
+use soroban_sdk::{BytesN, Env, String};
+
+use crate::error::ContractError;
+use crate::event::{DustRecordedEvent, TokenDecimalsSetEvent};
+use crate::storage_types::{DataKey, TokenChainKey};
+
+/// Retrieves the decimals of the token with `token_id` on `chain`, if they are known.
+pub fn token_decimals(env: &Env, token_id: BytesN<32>, chain: String) -> Option<u32> {
+    let key = DataKey::TokenDecimals(TokenChainKey { token_id, chain });
+    let decimals = env.storage().persistent().get(&key);
+
+    if decimals.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+
+    decimals
+}
+
+/// Records the decimals of the token with `token_id` on `chain`, which are used to scale the amounts of interchain transfers to and from that chain.
+pub fn set_token_decimals(env: &Env, token_id: BytesN<32>, chain: String, decimals: u32) {
+    let key = DataKey::TokenDecimals(TokenChainKey {
+        token_id: token_id.clone(),
+        chain: chain.clone(),
+    });
+    env.storage().persistent().set(&key, &decimals);
+    extend_persistent_ttl(env, &key);
+
+    TokenDecimalsSetEvent {
+        token_id,
+        chain,
+        decimals,
+    }
+    .emit(env);
+}
+
+/// Retrieves the total dust of inbound transfers of the token with `token_id` from `chain`, in its decimals on `chain`.
+pub fn dust(env: &Env, token_id: BytesN<32>, chain: String) -> i128 {
+    let key = DataKey::Dust(TokenChainKey { token_id, chain });
+    let Some(dust) = env.storage().persistent().get(&key) else {
+        return 0;
+    };
+
+    extend_persistent_ttl(env, &key);
+
+    dust
+}
+
+/// Records the `dust` of the inbound transfer with `message_id` from `chain`, i.e. the part of its amount that
+/// can't be represented in the token's decimals on this chain and is therefore not given to the recipient.
+pub fn record_dust(env: &Env, token_id: BytesN<32>, chain: String, message_id: String, dust: i128) {
+    if dust == 0 {
+        return;
+    }
+
+    let key = DataKey::Dust(TokenChainKey {
+        token_id: token_id.clone(),
+        chain: chain.clone(),
+    });
+    let total_dust = env
+        .storage()
+        .persistent()
+        .get::<_, i128>(&key)
+        .unwrap_or(0)
+        .saturating_add(dust);
+    env.storage().persistent().set(&key, &total_dust);
+    extend_persistent_ttl(env, &key);
+
+    DustRecordedEvent {
+        token_id,
+        chain,
+        message_id,
+        dust,
+    }
+    .emit(env);
+}
+
+/// Scales an `amount` of a token with `from_decimals` to the same value in `to_decimals`, rounding down.
+///
+/// Returns the scaled amount and the dust, i.e. the part of `amount` that is lost to rounding, in `from_decimals`.
+///
+/// # Errors
+/// * `ContractError::ScaledAmountOverflow` - If the scaled amount doesn't fit into an `i128`.
+/// * `ContractError::ScaledAmountZero` - If a non-zero amount is rounded down to zero.
+pub fn scale_amount(
+    amount: i128,
+    from_decimals: u32,
+    to_decimals: u32,
+) -> Result<(i128, i128), ContractError> {
+    if to_decimals >= from_decimals {
+        let scaled_amount = 10i128
+            .checked_pow(to_decimals - from_decimals)
+            .and_then(|factor| amount.checked_mul(factor))
+            .ok_or(ContractError::ScaledAmountOverflow)?;
+
+        return Ok((scaled_amount, 0));
+    }
+
+    // a factor that doesn't fit into an i128 rounds every amount down to zero
+    let (scaled_amount, dust) = 10i128
+        .checked_pow(from_decimals - to_decimals)
+        .map_or((0, amount), |factor| (amount / factor, amount % factor));
+
+    ensure!(
+        scaled_amount != 0 || amount == 0,
+        ContractError::ScaledAmountZero
+    );
+
+    Ok((scaled_amount, dust))
+}
+
+/// Scales an `amount` of a token with `from_decimals` to the same value in `to_decimals`, rounding down.
+///
+/// Unlike [scale_amount], this never fails: an amount that is rounded down to zero or doesn't fit into an `i128`
+/// in `to_decimals` is scaled to zero, and becomes dust as a whole.
+pub fn scale_amount_or_dust(amount: i128, from_decimals: u32, to_decimals: u32) -> (i128, i128) {
+    scale_amount(amount, from_decimals, to_decimals).unwrap_or((0, amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_amount_with_same_decimals_is_unchanged() {
+        assert_eq!(scale_amount(1234, 7, 7), Ok((1234, 0)));
+    }
+
+    #[test]
+    fn scale_amount_up_multiplies() {
+        assert_eq!(scale_amount(1234, 7, 18), Ok((1234 * 10i128.pow(11), 0)));
+    }
+
+    #[test]
+    fn scale_amount_down_returns_dust() {
+        assert_eq!(scale_amount(1_000_000_000_123, 18, 7), Ok((10, 123)));
+    }
+
+    #[test]
+    fn scale_amount_fails_on_overflow() {
+        assert_eq!(
+            scale_amount(i128::MAX / 10 + 1, 7, 8),
+            Err(ContractError::ScaledAmountOverflow)
+        );
+        assert_eq!(
+            scale_amount(1, 0, 39),
+            Err(ContractError::ScaledAmountOverflow)
+        );
+    }
+
+    #[test]
+    fn scale_amount_or_dust_never_fails() {
+        assert_eq!(scale_amount_or_dust(1_000_000_000_123, 18, 7), (10, 123));
+        assert_eq!(scale_amount_or_dust(99, 18, 16), (0, 99));
+        assert_eq!(
+            scale_amount_or_dust(i128::MAX / 10 + 1, 7, 8),
+            (0, i128::MAX / 10 + 1)
+        );
+    }
+
+    #[test]
+    fn scale_amount_fails_if_truncated_to_zero() {
+        assert_eq!(
+            scale_amount(99, 18, 16),
+            Err(ContractError::ScaledAmountZero)
+        );
+        assert_eq!(
+            scale_amount(i128::MAX, 39, 0),
+            Err(ContractError::ScaledAmountZero)
+        );
+    }
+}
@@ -1,50 +1,107 @@
 use soroban_sdk::token::{StellarAssetClient, TokenClient};
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, BytesN, Env};
 
 use crate::error::ContractError;
+use crate::flow_limit;
 use crate::storage_types::TokenIdConfigValue;
 use crate::types::TokenManagerType;
 
+/// Takes `amount` tokens from the `sender` according to the token manager type of the token.
+///
+/// Returns the amount that was actually taken, which can be less than `amount` for fee-on-transfer tokens.
 pub fn take_token(
     env: &Env,
     sender: &Address,
+    token_id: BytesN<32>,
     TokenIdConfigValue {
         token_address,
         token_manager_type,
     }: TokenIdConfigValue,
     amount: i128,
-) -> Result<(), ContractError> {
+) -> Result<i128, ContractError> {
     let token = TokenClient::new(env, &token_address);
+    let contract_address = env.current_contract_address();
 
-    match token_manager_type {
-        TokenManagerType::NativeInterchainToken => token.burn(sender, &amount),
+    let amount = match token_manager_type {
+        TokenManagerType::NativeInterchainToken => {
+            token.burn(sender, &amount);
+            amount
+        }
+        TokenManagerType::MintBurnFrom => {
+            token.burn_from(&contract_address, sender, &amount);
+            amount
+        }
         TokenManagerType::LockUnlock => {
-            token.transfer(sender, &env.current_contract_address(), &amount)
+            token.transfer(sender, &contract_address, &amount);
+            amount
+        }
+        TokenManagerType::LockUnlockFee => {
+            transfer_with_balance_delta(&token, sender, &contract_address, amount)?
         }
-    }
+        TokenManagerType::MintBurn => {
+            token.transfer(sender, &contract_address, &amount);
+            token.burn(&contract_address, &amount);
+            amount
+        }
+    };
+
+    flow_limit::add_flow_out(env, token_id, amount)?;
 
-    Ok(())
+    Ok(amount)
 }
 
+/// Gives `amount` tokens to the `recipient` according to the token manager type of the token.
+///
+/// Returns the amount that was actually received, which can be less than `amount` for fee-on-transfer tokens.
 pub fn give_token(
     env: &Env,
     recipient: &Address,
+    token_id: BytesN<32>,
     TokenIdConfigValue {
         token_address,
         token_manager_type,
     }: TokenIdConfigValue,
     amount: i128,
-) -> Result<(), ContractError> {
-    match token_manager_type {
-        TokenManagerType::NativeInterchainToken => {
-            StellarAssetClient::new(env, &token_address).mint(recipient, &amount)
+) -> Result<i128, ContractError> {
+    let contract_address = env.current_contract_address();
+
+    let amount = match token_manager_type {
+        TokenManagerType::NativeInterchainToken
+        | TokenManagerType::MintBurnFrom
+        | TokenManagerType::MintBurn => {
+            StellarAssetClient::new(env, &token_address).mint(recipient, &amount);
+            amount
         }
-        TokenManagerType::LockUnlock => TokenClient::new(env, &token_address).transfer(
-            &env.current_contract_address(),
+        TokenManagerType::LockUnlock => {
+            TokenClient::new(env, &token_address).transfer(&contract_address, recipient, &amount);
+            amount
+        }
+        TokenManagerType::LockUnlockFee => transfer_with_balance_delta(
+            &TokenClient::new(env, &token_address),
+            &contract_address,
             recipient,
-            &amount,
-        ),
-    }
+            amount,
+        )?,
+    };
+
+    flow_limit::add_flow_in(env, token_id, amount)?;
+
+    Ok(amount)
+}
+
+/// Transfers `amount` tokens and measures the amount received by `to` via its balance delta.
+fn transfer_with_balance_delta(
+    token: &TokenClient,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    let balance_before = token.balance(to);
+
+    token.transfer(from, to, &amount);
 
-    Ok(())
+    token
+        .balance(to)
+        .checked_sub(balance_before)
+        .ok_or(ContractError::InvalidAmount)
 }
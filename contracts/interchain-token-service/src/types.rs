@@ -1,4 +1,13 @@
-use soroban_sdk::{contracttype, Bytes, BytesN, String};
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, String, Symbol};
+
+/// Role allowed to set the flow limits of tokens. Granted to the operator on construction.
+pub const FLOW_LIMITER_ROLE: Symbol = symbol_short!("limiter");
+
+/// Role allowed to add and remove trusted chains. Granted to the owner on construction.
+pub const CHAIN_MANAGER_ROLE: Symbol = symbol_short!("chain_mgr");
+
+/// Role allowed to pause and unpause the contract. Granted to the owner on construction.
+pub const PAUSER_ROLE: Symbol = symbol_short!("pauser");
 
 #[contracttype]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -9,12 +18,15 @@ pub enum MessageType {
     DeployTokenManager = 2, // note, this case is not supported by the ITS hub
     SendToHub = 3,
     ReceiveFromHub = 4,
+    LinkToken = 5,
+    RegisterTokenMetadata = 6,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Message {
     InterchainTransfer(InterchainTransfer),
     DeployInterchainToken(DeployInterchainToken),
+    LinkToken(LinkToken),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -35,6 +47,36 @@ pub struct DeployInterchainToken {
     pub minter: Option<Bytes>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkToken {
+    pub token_id: BytesN<32>,
+    pub token_manager_type: TokenManagerType,
+    pub source_token_address: Bytes,
+    pub destination_token_address: Bytes,
+    pub params: Option<Bytes>,
+}
+
+/// Registers the decimals of a token with the ITS hub, which is required before the token can be linked.
+/// Unlike the other messages, this is sent to the ITS hub directly instead of being routed to another chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegisterTokenMetadata {
+    pub token_address: Bytes,
+    pub decimals: u8,
+}
+
+/// An inbound interchain transfer whose destination contract failed to execute.
+/// The tokens are held in escrow by ITS until they're claimed or the execution is retried.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FailedExecution {
+    pub source_chain: String,
+    pub source_address: Bytes,
+    pub destination_address: Address,
+    pub token_id: BytesN<32>,
+    pub amount: i128,
+    pub data: Bytes,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HubMessage {
     SendToHub {
@@ -45,6 +87,7 @@ pub enum HubMessage {
         source_chain: String,
         message: Message,
     },
+    RegisterTokenMetadata(RegisterTokenMetadata),
 }
 
 /// The type of token manager used for the tokenId.
@@ -55,8 +98,8 @@ pub enum HubMessage {
 #[repr(u32)]
 pub enum TokenManagerType {
     NativeInterchainToken = 0,
-    // MintBurnFrom = 1,
+    MintBurnFrom = 1,
     LockUnlock = 2,
-    // LockUnlockFee = 3,
-    // MintBurn = 4,
+    LockUnlockFee = 3,
+    MintBurn = 4,
 }
@@ -2,7 +2,7 @@ mod utils;
 
 use axelar_soroban_std::{address::AddressExt, auth_invocation, events};
 use interchain_token_service::{
-    event::InterchainTokenDeploymentStartedEvent,
+    event::TokenDeploymentStartedEvent,
     types::{DeployInterchainToken, HubMessage, Message, TokenManagerType},
 };
 use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
@@ -39,7 +39,7 @@ fn deploy_remote_canonical_token_succeeds() {
 
     client
         .mock_all_auths()
-        .set_trusted_chain(&destination_chain);
+        .set_trusted_chain(&client.owner(), &destination_chain);
 
     let token = token::Client::new(&env, &asset.address());
     let token_metadata = TokenMetadata {
@@ -68,7 +68,7 @@ fn deploy_remote_canonical_token_succeeds() {
     assert_eq!(expected_id, deployed_token_id);
 
     goldie::assert!(events::fmt_emitted_event_at_idx::<
-        InterchainTokenDeploymentStartedEvent,
+        TokenDeploymentStartedEvent,
     >(&env, -4));
 
     let transfer_auth = auth_invocation!(
@@ -121,7 +121,7 @@ fn deploy_remote_canonical_token_fail_no_actual_token() {
     let destination_chain = String::from_str(&env, "ethereum");
     client
         .mock_all_auths()
-        .set_trusted_chain(&destination_chain);
+        .set_trusted_chain(&client.owner(), &destination_chain);
 
     client
         .mock_all_auths_allowing_non_root_auth()
@@ -3,7 +3,7 @@ mod utils;
 use axelar_soroban_std::{assert_contract_err, auth_invocation, events};
 use interchain_token_service::{
     error::ContractError,
-    event::InterchainTokenDeploymentStartedEvent,
+    event::TokenDeploymentStartedEvent,
     types::{DeployInterchainToken, HubMessage, Message},
 };
 use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
@@ -36,7 +36,7 @@ fn deploy_remote_interchain_token_succeeds() {
 
     client
         .mock_all_auths()
-        .set_trusted_chain(&destination_chain);
+        .set_trusted_chain(&client.owner(), &destination_chain);
 
     let deployed_token_id = client.mock_all_auths().deploy_remote_interchain_token(
         &sender,
@@ -48,7 +48,7 @@ fn deploy_remote_interchain_token_succeeds() {
     assert_eq!(token_id, deployed_token_id);
 
     goldie::assert!(events::fmt_emitted_event_at_idx::<
-        InterchainTokenDeploymentStartedEvent,
+        TokenDeploymentStartedEvent,
     >(&env, -4));
 
     let message = Message::DeployInterchainToken(DeployInterchainToken {
@@ -2,101 +2,103 @@ mod utils;
 
 use axelar_gateway::testutils::{generate_proof, get_approve_hash};
 use axelar_gateway::types::Message as GatewayMessage;
+use axelar_gateway::AxelarGatewayClient;
 use axelar_soroban_std::traits::BytesExt;
-use axelar_soroban_std::{assert_invoke_auth_err, events};
-use interchain_token_service::types::{HubMessage, InterchainTransfer, Message};
+use axelar_soroban_std::ttl::{PERSISTENT_TTL_EXTEND_TO, PERSISTENT_TTL_THRESHOLD};
+use axelar_soroban_std::{assert_contract_err, assert_invoke_auth_err, events};
+use interchain_token_service::error::ContractError;
+use interchain_token_service::event::{ExecutionFailedEvent, FailedExecutionClaimedEvent};
+use interchain_token_service::types::{FailedExecution, HubMessage, InterchainTransfer, Message};
+use interchain_token_service::InterchainTokenServiceClient;
+use soroban_sdk::testutils::{storage::Persistent as _, Address as _, Ledger};
 use soroban_sdk::token;
 use soroban_sdk::xdr::ToXdr;
-use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, BytesN, String};
-use utils::{register_chains, setup_env, setup_its_token, HUB_CHAIN};
+use soroban_sdk::{contracttype, vec, Address, Bytes, BytesN, Env, String};
+use utils::{approve_gateway_messages, register_chains, setup_env, setup_its_token, HUB_CHAIN};
 
 mod test {
-    use axelar_soroban_std::{events::Event, impl_event_testutils};
-    use core::fmt::Debug;
-    use interchain_token_service::executable::InterchainTokenExecutableInterface;
+    use axelar_soroban_std::events::Event;
+    use axelar_soroban_std::IntoEvent;
+    use interchain_token_service::executable::{
+        CustomInterchainTokenExecutable, InterchainTokenMessage,
+    };
+    use interchain_token_service::impl_interchain_token_executable;
     use soroban_sdk::{
-        contract, contractimpl, contracttype, Address, Bytes, BytesN, Env, IntoVal, String, Symbol,
-        Topics, Val,
+        contract, contracterror, contractimpl, contracttype, Address, Bytes, BytesN, Env, String,
     };
 
     #[contract]
     pub struct ExecutableContract;
 
+    impl_interchain_token_executable!(ExecutableContract);
+
     #[contracttype]
     #[derive(Clone, Debug)]
     pub enum DataKey {
         InterchainTokenService,
         Message,
+        Fail,
     }
 
-    #[derive(Debug, PartialEq, Eq)]
+    #[contracterror]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(u32)]
+    pub enum ContractError {
+        ExecutionFailed = 1,
+    }
+
+    #[derive(Debug, PartialEq, Eq, IntoEvent)]
     pub struct ExecutedEvent {
+        #[topic]
         pub source_chain: String,
+        #[topic]
         pub message_id: String,
+        #[topic]
         pub source_address: Bytes,
-        pub payload: Bytes,
+        #[topic]
         pub token_id: BytesN<32>,
+        #[topic]
         pub token_address: Address,
+        #[topic]
         pub amount: i128,
+        #[data]
+        pub payload: Bytes,
     }
 
-    impl Event for ExecutedEvent {
-        fn topics(&self, env: &Env) -> impl Topics + Debug {
-            (
-                Symbol::new(env, "executed"),
-                self.source_chain.to_val(),
-                self.message_id.to_val(),
-                self.source_address.to_val(),
-                self.token_id.to_val(),
-                self.token_address.to_val(),
-                self.amount,
-            )
-        }
-
-        fn data(&self, _env: &Env) -> impl IntoVal<Env, Val> + Debug {
-            (self.payload.to_val(),)
-        }
-    }
-
-    impl_event_testutils!(
-        ExecutedEvent,
-        (Symbol, String, String, Bytes, BytesN<32>, Address, i128),
-        (Bytes)
-    );
+    impl CustomInterchainTokenExecutable for ExecutableContract {
+        type Error = ContractError;
 
-    #[contractimpl]
-    impl InterchainTokenExecutableInterface for ExecutableContract {
-        fn interchain_token_service(env: &Env) -> Address {
+        fn __interchain_token_service(env: &Env) -> Address {
             env.storage()
                 .instance()
                 .get(&DataKey::InterchainTokenService)
                 .expect("its not found")
         }
 
-        fn execute_with_interchain_token(
+        fn execute_with_token(
             env: &Env,
-            source_chain: String,
-            message_id: String,
-            source_address: Bytes,
-            payload: Bytes,
-            token_id: BytesN<32>,
-            token_address: Address,
-            amount: i128,
-        ) {
-            Self::validate(env);
-
-            env.storage().persistent().set(&DataKey::Message, &payload);
+            message: InterchainTokenMessage,
+        ) -> Result<(), Self::Error> {
+            if env.storage().instance().has(&DataKey::Fail) {
+                return Err(ContractError::ExecutionFailed);
+            }
+
+            env.storage()
+                .persistent()
+                .set(&DataKey::Message, &message.payload);
 
             ExecutedEvent {
-                source_chain,
-                message_id,
-                source_address,
-                payload,
-                token_id,
-                token_address,
-                amount,
+                source_chain: message.source_chain,
+                message_id: message.message_id,
+                source_address: message.source_address,
+                payload: message.payload,
+                token_id: message.token_id,
+                token_address: message.token_address,
+                amount: message.amount,
             }
             .emit(env);
+
+            Ok(())
         }
     }
 
@@ -108,6 +110,14 @@ mod test {
                 .set(&DataKey::InterchainTokenService, &interchain_token_service);
         }
 
+        pub fn set_fail(env: &Env, fail: bool) {
+            if fail {
+                env.storage().instance().set(&DataKey::Fail, &());
+            } else {
+                env.storage().instance().remove(&DataKey::Fail);
+            }
+        }
+
         pub fn message(env: &Env) -> Option<Bytes> {
             env.storage()
                 .persistent()
@@ -201,3 +211,360 @@ fn executable_fails_if_not_executed_from_its() {
         )
     );
 }
+
+/// An approved gateway message that can be executed by ITS.
+struct ApprovedMessage {
+    source_chain: String,
+    message_id: String,
+    source_address: String,
+    payload: Bytes,
+}
+
+/// Sets up an executable that fails, and approves an interchain transfer with data for it.
+fn setup_failing_transfer<'a>(
+    amount: i128,
+) -> (
+    Env,
+    InterchainTokenServiceClient<'a>,
+    AxelarGatewayClient<'a>,
+    test::ExecutableContractClient<'a>,
+    BytesN<32>,
+    ApprovedMessage,
+) {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let executable_id = env.register(test::ExecutableContract, (client.address.clone(),));
+    let executable_client = test::ExecutableContractClient::new(&env, &executable_id);
+    executable_client.set_fail(&true);
+
+    let token_id = setup_its_token(&env, &client, &Address::generate(&env), amount);
+
+    let payload = HubMessage::ReceiveFromHub {
+        source_chain: String::from_str(&env, HUB_CHAIN),
+        message: Message::InterchainTransfer(InterchainTransfer {
+            token_id: token_id.clone(),
+            source_address: Address::generate(&env).to_xdr(&env),
+            destination_address: executable_id.to_xdr(&env),
+            amount,
+            data: Some(Bytes::from_hex(&env, "dead")),
+        }),
+    }
+    .abi_encode(&env)
+    .unwrap();
+
+    let message = ApprovedMessage {
+        source_chain: client.its_hub_chain_name(),
+        message_id: String::from_str(&env, "test"),
+        source_address: Address::generate(&env).to_string(),
+        payload,
+    };
+
+    approve_gateway_messages(
+        &env,
+        AxelarGatewayClient::new(&env, &gateway_client.address),
+        signers,
+        vec![
+            &env,
+            GatewayMessage {
+                source_chain: message.source_chain.clone(),
+                message_id: message.message_id.clone(),
+                source_address: message.source_address.clone(),
+                contract_address: client.address.clone(),
+                payload_hash: env.crypto().keccak256(&message.payload).into(),
+            },
+        ],
+    );
+
+    (
+        env,
+        client,
+        gateway_client,
+        executable_client,
+        token_id,
+        message,
+    )
+}
+
+/// Sets up an executable that fails, and escrows an interchain transfer with data for it.
+fn setup_failed_execution<'a>(
+    amount: i128,
+) -> (
+    Env,
+    InterchainTokenServiceClient<'a>,
+    test::ExecutableContractClient<'a>,
+    BytesN<32>,
+    String,
+) {
+    let (env, client, _, executable_client, token_id, message) = setup_failing_transfer(amount);
+
+    client.mock_all_auths().escrow_failed_execution(
+        &executable_client.address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.payload,
+    );
+
+    (env, client, executable_client, token_id, message.message_id)
+}
+
+#[test]
+fn interchain_transfer_execute_reverts_if_executable_fails() {
+    let (env, client, gateway_client, executable_client, token_id, message) =
+        setup_failing_transfer(1000);
+
+    assert!(client
+        .try_execute(
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &message.payload,
+        )
+        .is_err());
+
+    let token = token::TokenClient::new(&env, &client.token_address(&token_id));
+    assert_eq!(token.balance(&executable_client.address), 0);
+    assert!(gateway_client.is_message_approved(
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &client.address,
+        &env.crypto().keccak256(&message.payload).into(),
+    ));
+}
+
+#[test]
+fn escrow_failed_execution_holds_tokens() {
+    let amount = 1000;
+    let (env, client, gateway_client, executable_client, token_id, message) =
+        setup_failing_transfer(amount);
+
+    client.mock_all_auths().escrow_failed_execution(
+        &executable_client.address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.payload,
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<ExecutionFailedEvent>(&env));
+
+    let token = token::TokenClient::new(&env, &client.token_address(&token_id));
+    assert_eq!(token.balance(&executable_client.address), 0);
+    assert_eq!(executable_client.message(), None);
+    assert!(gateway_client.is_message_executed(&message.source_chain, &message.message_id));
+
+    let source_chain = String::from_str(&env, HUB_CHAIN);
+    let failed_execution = client
+        .failed_execution(&source_chain, &message.message_id)
+        .unwrap();
+    assert_eq!(
+        failed_execution,
+        FailedExecution {
+            source_chain,
+            source_address: failed_execution.source_address.clone(),
+            destination_address: executable_client.address,
+            token_id,
+            amount,
+            data: Bytes::from_hex(&env, "dead"),
+        }
+    );
+}
+
+#[test]
+fn escrow_failed_execution_by_recovery_address_succeeds() {
+    let (env, client, _, _, _, message) = setup_failing_transfer(1000);
+
+    let recovery_address = Address::generate(&env);
+    client
+        .mock_all_auths()
+        .set_recovery_address(&recovery_address);
+
+    client.mock_all_auths().escrow_failed_execution(
+        &recovery_address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.payload,
+    );
+
+    assert!(client
+        .failed_execution(&String::from_str(&env, HUB_CHAIN), &message.message_id)
+        .is_some());
+}
+
+#[test]
+fn escrow_failed_execution_fails_if_not_destination_or_recovery_address() {
+    let (env, client, _, _, _, message) = setup_failing_transfer(1000);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_escrow_failed_execution(
+            &Address::generate(&env),
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &message.payload,
+        ),
+        ContractError::NotRecoveryCaller
+    );
+}
+
+#[test]
+fn escrow_failed_execution_fails_if_message_not_approved() {
+    let (env, client, _, executable_client, _, message) = setup_failing_transfer(1000);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_escrow_failed_execution(
+            &executable_client.address,
+            &message.source_chain,
+            &String::from_str(&env, "unknown"),
+            &message.source_address,
+            &message.payload,
+        ),
+        ContractError::MessageNotApproved
+    );
+}
+
+#[test]
+fn escrow_failed_execution_extends_ttl() {
+    #[contracttype]
+    struct FailedExecutionKey {
+        source_chain: String,
+        message_id: String,
+    }
+
+    #[contracttype]
+    enum ItsDataKey {
+        FailedExecution(FailedExecutionKey),
+    }
+
+    let (env, client, _, _, message_id) = setup_failed_execution(1000);
+    let source_chain = String::from_str(&env, HUB_CHAIN);
+    let key = ItsDataKey::FailedExecution(FailedExecutionKey {
+        source_chain: source_chain.clone(),
+        message_id: message_id.clone(),
+    });
+
+    let ttl = || env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key));
+    assert_eq!(ttl(), PERSISTENT_TTL_EXTEND_TO);
+
+    env.ledger().with_mut(|ledger| {
+        ledger.sequence_number += PERSISTENT_TTL_EXTEND_TO - PERSISTENT_TTL_THRESHOLD + 1
+    });
+
+    assert!(client
+        .failed_execution(&source_chain, &message_id)
+        .is_some());
+    assert_eq!(ttl(), PERSISTENT_TTL_EXTEND_TO);
+}
+
+#[test]
+fn claim_failed_execution_gives_tokens_to_destination() {
+    let amount = 1000;
+    let (env, client, executable_client, token_id, message_id) = setup_failed_execution(amount);
+    let source_chain = String::from_str(&env, HUB_CHAIN);
+
+    client.mock_all_auths().claim_failed_execution(
+        &executable_client.address,
+        &source_chain,
+        &message_id,
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<FailedExecutionClaimedEvent>(&env));
+
+    let token = token::TokenClient::new(&env, &client.token_address(&token_id));
+    assert_eq!(token.balance(&executable_client.address), amount);
+    assert_eq!(executable_client.message(), None);
+    assert_eq!(client.failed_execution(&source_chain, &message_id), None);
+}
+
+#[test]
+fn claim_failed_execution_by_recovery_address_gives_tokens_to_destination() {
+    let amount = 1000;
+    let (env, client, executable_client, token_id, message_id) = setup_failed_execution(amount);
+    let source_chain = String::from_str(&env, HUB_CHAIN);
+
+    let recovery_address = Address::generate(&env);
+    client
+        .mock_all_auths()
+        .set_recovery_address(&recovery_address);
+    assert_eq!(client.recovery_address(), Some(recovery_address.clone()));
+
+    client
+        .mock_all_auths()
+        .claim_failed_execution(&recovery_address, &source_chain, &message_id);
+
+    let token = token::TokenClient::new(&env, &client.token_address(&token_id));
+    assert_eq!(token.balance(&executable_client.address), amount);
+    assert_eq!(token.balance(&recovery_address), 0);
+    assert_eq!(client.failed_execution(&source_chain, &message_id), None);
+}
+
+#[test]
+fn claim_failed_execution_fails_if_not_destination_or_recovery_address() {
+    let (env, client, _, _, message_id) = setup_failed_execution(1000);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_claim_failed_execution(
+            &Address::generate(&env),
+            &String::from_str(&env, HUB_CHAIN),
+            &message_id
+        ),
+        ContractError::NotRecoveryCaller
+    );
+}
+
+#[test]
+fn claim_failed_execution_fails_if_not_found() {
+    let (env, client, executable_client, _, message_id) = setup_failed_execution(1000);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_claim_failed_execution(
+            &executable_client.address,
+            &String::from_str(&env, "unknown"),
+            &message_id
+        ),
+        ContractError::FailedExecutionNotFound
+    );
+}
+
+#[test]
+fn retry_failed_execution_gives_tokens_and_executes() {
+    let amount = 1000;
+    let (env, client, executable_client, token_id, message_id) = setup_failed_execution(amount);
+    let source_chain = String::from_str(&env, HUB_CHAIN);
+
+    executable_client.set_fail(&false);
+
+    client.mock_all_auths().retry_failed_execution(
+        &executable_client.address,
+        &source_chain,
+        &message_id,
+    );
+
+    let token = token::TokenClient::new(&env, &client.token_address(&token_id));
+    assert_eq!(token.balance(&executable_client.address), amount);
+    assert_eq!(
+        executable_client.message(),
+        Some(Bytes::from_hex(&env, "dead"))
+    );
+    assert_eq!(client.failed_execution(&source_chain, &message_id), None);
+}
+
+#[test]
+fn retry_failed_execution_keeps_escrow_if_executable_fails_again() {
+    let (env, client, executable_client, token_id, message_id) = setup_failed_execution(1000);
+    let source_chain = String::from_str(&env, HUB_CHAIN);
+
+    assert!(client
+        .mock_all_auths()
+        .try_retry_failed_execution(&executable_client.address, &source_chain, &message_id)
+        .is_err());
+
+    let token = token::TokenClient::new(&env, &client.token_address(&token_id));
+    assert_eq!(token.balance(&executable_client.address), 0);
+    assert!(client
+        .failed_execution(&source_chain, &message_id)
+        .is_some());
+}
@@ -0,0 +1,295 @@
+mod utils;
+
+use axelar_gateway::types::Message as GatewayMessage;
+use axelar_gateway::AxelarGatewayClient;
+use axelar_soroban_std::{assert_contract_err, assert_invoke_auth_err, events};
+use interchain_token_service::error::ContractError;
+use interchain_token_service::event::{ExpressExecutedEvent, ExpressExecutionFulfilledEvent};
+use interchain_token_service::types::{
+    DeployInterchainToken, HubMessage, InterchainTransfer, Message,
+};
+use interchain_token_service::InterchainTokenServiceClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, String};
+use utils::{approve_gateway_messages, register_chains, setup_env, setup_its_token, HUB_CHAIN};
+
+struct TestMessage {
+    source_chain: String,
+    message_id: String,
+    source_address: String,
+    payload: Bytes,
+}
+
+impl TestMessage {
+    fn new(env: &Env, client: &InterchainTokenServiceClient, message: Message) -> Self {
+        let payload = HubMessage::ReceiveFromHub {
+            source_chain: String::from_str(env, HUB_CHAIN),
+            message,
+        }
+        .abi_encode(env)
+        .unwrap();
+
+        Self {
+            source_chain: client.its_hub_chain_name(),
+            message_id: String::from_str(env, "test"),
+            source_address: client.its_hub_address(),
+            payload,
+        }
+    }
+
+    fn transfer(
+        env: &Env,
+        client: &InterchainTokenServiceClient,
+        token_id: &BytesN<32>,
+        recipient: &Address,
+        amount: i128,
+    ) -> Self {
+        Self::new(
+            env,
+            client,
+            Message::InterchainTransfer(InterchainTransfer {
+                token_id: token_id.clone(),
+                source_address: Address::generate(env).to_xdr(env),
+                destination_address: recipient.clone().to_xdr(env),
+                amount,
+                data: None,
+            }),
+        )
+    }
+
+    fn payload_hash(&self, env: &Env) -> BytesN<32> {
+        env.crypto().keccak256(&self.payload).into()
+    }
+
+    fn approve(
+        &self,
+        env: &Env,
+        client: &InterchainTokenServiceClient,
+        gateway_client: AxelarGatewayClient,
+        signers: axelar_gateway::testutils::TestSignerSet,
+    ) {
+        let messages = vec![
+            env,
+            GatewayMessage {
+                source_chain: self.source_chain.clone(),
+                message_id: self.message_id.clone(),
+                source_address: self.source_address.clone(),
+                contract_address: client.address.clone(),
+                payload_hash: self.payload_hash(env),
+            },
+        ];
+        approve_gateway_messages(env, gateway_client, signers, messages);
+    }
+
+    fn express_execute(
+        &self,
+        client: &InterchainTokenServiceClient,
+        express_executor: &Address,
+    ) -> Result<(), ContractError> {
+        client
+            .mock_all_auths()
+            .try_express_execute(
+                express_executor,
+                &self.source_chain,
+                &self.message_id,
+                &self.source_address,
+                &self.payload,
+            )
+            .map(|_| ())
+            .map_err(|err| err.expect("unexpected host error"))
+    }
+
+    fn execute(&self, client: &InterchainTokenServiceClient) {
+        client.execute(
+            &self.source_chain,
+            &self.message_id,
+            &self.source_address,
+            &self.payload,
+        );
+    }
+}
+
+#[test]
+fn express_execute_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    register_chains(&env, &client);
+
+    let amount = 1000;
+    let express_executor = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &express_executor, amount);
+    let recipient = Address::generate(&env);
+
+    let message = TestMessage::transfer(&env, &client, &token_id, &recipient, amount);
+
+    assert_eq!(message.express_execute(&client, &express_executor), Ok(()));
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<ExpressExecutedEvent>(
+        &env, -2
+    ));
+
+    let token = TokenClient::new(&env, &client.token_address(&token_id));
+    assert_eq!(token.balance(&recipient), amount);
+    assert_eq!(token.balance(&express_executor), 0);
+    assert_eq!(
+        client.express_executor(&message.message_id, &message.payload_hash(&env)),
+        Some(express_executor)
+    );
+}
+
+#[test]
+fn execute_after_express_execute_repays_express_executor() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let amount = 1000;
+    let express_executor = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &express_executor, amount);
+    let recipient = Address::generate(&env);
+
+    let message = TestMessage::transfer(&env, &client, &token_id, &recipient, amount);
+
+    assert_eq!(message.express_execute(&client, &express_executor), Ok(()));
+
+    message.approve(&env, &client, gateway_client, signers);
+    message.execute(&client);
+
+    goldie::assert!(events::fmt_last_emitted_event::<
+        ExpressExecutionFulfilledEvent,
+    >(&env));
+
+    let token = TokenClient::new(&env, &client.token_address(&token_id));
+    assert_eq!(token.balance(&recipient), amount);
+    assert_eq!(token.balance(&express_executor), amount);
+    assert_eq!(
+        client.express_executor(&message.message_id, &message.payload_hash(&env)),
+        None
+    );
+}
+
+#[test]
+fn express_execute_fails_if_not_authorized() {
+    let (env, client, _, _, _) = setup_env();
+    register_chains(&env, &client);
+
+    let amount = 1000;
+    let express_executor = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &express_executor, amount);
+
+    let message = TestMessage::transfer(&env, &client, &token_id, &Address::generate(&env), amount);
+
+    assert_invoke_auth_err!(
+        client.owner(),
+        client.try_express_execute(
+            &express_executor,
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &message.payload,
+        )
+    );
+}
+
+#[test]
+fn express_execute_fails_if_already_express_executed() {
+    let (env, client, _, _, _) = setup_env();
+    register_chains(&env, &client);
+
+    let amount = 1000;
+    let express_executor = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &express_executor, amount * 2);
+
+    let message = TestMessage::transfer(&env, &client, &token_id, &Address::generate(&env), amount);
+
+    assert_eq!(message.express_execute(&client, &express_executor), Ok(()));
+    assert_eq!(
+        message.express_execute(&client, &express_executor),
+        Err(ContractError::ExpressReceiptAlreadySet)
+    );
+}
+
+#[test]
+fn express_execute_fails_if_message_already_approved() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let amount = 1000;
+    let express_executor = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &express_executor, amount);
+
+    let message = TestMessage::transfer(&env, &client, &token_id, &Address::generate(&env), amount);
+    message.approve(&env, &client, gateway_client, signers);
+
+    assert_eq!(
+        message.express_execute(&client, &express_executor),
+        Err(ContractError::MessageAlreadyApproved)
+    );
+}
+
+#[test]
+fn express_execute_fails_if_message_already_executed() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let amount = 1000;
+    let express_executor = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &express_executor, amount);
+
+    let message = TestMessage::transfer(&env, &client, &token_id, &Address::generate(&env), amount);
+    message.approve(&env, &client, gateway_client, signers);
+    message.execute(&client);
+
+    assert_eq!(
+        message.express_execute(&client, &express_executor),
+        Err(ContractError::MessageAlreadyExecuted)
+    );
+}
+
+#[test]
+fn express_execute_fails_with_invalid_message_type() {
+    let (env, client, _, _, _) = setup_env();
+    register_chains(&env, &client);
+
+    let message = TestMessage::new(
+        &env,
+        &client,
+        Message::DeployInterchainToken(DeployInterchainToken {
+            token_id: BytesN::from_array(&env, &[1u8; 32]),
+            name: String::from_str(&env, "Test"),
+            symbol: String::from_str(&env, "TEST"),
+            decimals: 18,
+            minter: None,
+        }),
+    );
+
+    assert_eq!(
+        message.express_execute(&client, &Address::generate(&env)),
+        Err(ContractError::InvalidMessageType)
+    );
+}
+
+#[test]
+fn express_execute_fails_when_paused() {
+    let (env, client, _, _, _) = setup_env();
+    register_chains(&env, &client);
+
+    let amount = 1000;
+    let express_executor = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &express_executor, amount);
+
+    let message = TestMessage::transfer(&env, &client, &token_id, &Address::generate(&env), amount);
+
+    client.mock_all_auths().pause(&client.owner());
+
+    assert_contract_err!(
+        client.mock_all_auths().try_express_execute(
+            &express_executor,
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &message.payload,
+        ),
+        ContractError::ContractPaused
+    );
+}
@@ -0,0 +1,107 @@
+mod utils;
+
+use axelar_soroban_std::assert_contract_err;
+use axelar_soroban_std::ttl::{
+    LEDGERS_PER_DAY, PERSISTENT_TTL_EXTEND_TO, PERSISTENT_TTL_THRESHOLD,
+};
+use interchain_token_service::error::ContractError;
+use soroban_sdk::testutils::{storage::Persistent as _, Address as _, Ledger};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String};
+use utils::{setup_env, setup_its_token};
+
+fn advance_days(env: &Env, days: u32) {
+    env.ledger()
+        .with_mut(|ledger| ledger.sequence_number += days * LEDGERS_PER_DAY);
+}
+
+#[test]
+fn extend_token_config_ttl_keeps_token_registered() {
+    let (env, client, _, _, _) = setup_env();
+
+    let token_id = setup_its_token(&env, &client, &Address::generate(&env), 1000);
+    let token_address = client.token_address(&token_id);
+
+    client.extend_token_config_ttl(&token_id);
+    advance_days(&env, 50);
+
+    client.extend_token_config_ttl(&token_id);
+    advance_days(&env, 50);
+
+    assert_eq!(client.token_address(&token_id), token_address);
+}
+
+#[test]
+fn extend_token_config_ttl_fails_with_invalid_token_id() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.try_extend_token_config_ttl(&BytesN::from_array(&env, &[1; 32])),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn extend_trusted_chain_ttl_keeps_chain_trusted() {
+    let (env, client, _, _, _) = setup_env();
+
+    let chain = String::from_str(&env, "chain");
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&client.owner(), &chain);
+
+    client.extend_trusted_chain_ttl(&chain);
+    advance_days(&env, 50);
+
+    client.extend_trusted_chain_ttl(&chain);
+    advance_days(&env, 50);
+
+    assert!(client.is_trusted_chain(&chain));
+}
+
+#[test]
+fn extend_trusted_chain_ttl_fails_if_chain_not_trusted() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.try_extend_trusted_chain_ttl(&String::from_str(&env, "chain")),
+        ContractError::UntrustedChain
+    );
+}
+
+#[test]
+fn token_decimals_ttl_is_extended_on_read() {
+    #[contracttype]
+    struct TokenChainKey {
+        token_id: BytesN<32>,
+        chain: String,
+    }
+
+    #[contracttype]
+    enum ItsDataKey {
+        TokenDecimals(TokenChainKey),
+    }
+
+    let (env, client, _, _, _) = setup_env();
+
+    let token_id = setup_its_token(&env, &client, &Address::generate(&env), 1000);
+    let chain = String::from_str(&env, "chain");
+    client
+        .mock_all_auths()
+        .set_token_decimals(&token_id, &chain, &6);
+
+    let key = ItsDataKey::TokenDecimals(TokenChainKey {
+        token_id: token_id.clone(),
+        chain: chain.clone(),
+    });
+    let ttl = || env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key));
+    assert_eq!(ttl(), PERSISTENT_TTL_EXTEND_TO);
+
+    // keeps the contract instance alive, without touching the token decimals
+    client.extend_token_config_ttl(&token_id);
+    env.ledger().with_mut(|ledger| {
+        ledger.sequence_number += PERSISTENT_TTL_EXTEND_TO - PERSISTENT_TTL_THRESHOLD + 1
+    });
+
+    assert_eq!(client.token_decimals(&token_id, &chain), Some(6));
+    assert_eq!(ttl(), PERSISTENT_TTL_EXTEND_TO);
+}
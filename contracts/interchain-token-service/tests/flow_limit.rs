@@ -0,0 +1,251 @@
+mod utils;
+
+use axelar_soroban_std::{
+    assert_contract_err, assert_invoke_auth_err, assert_invoke_auth_ok, events,
+};
+use interchain_token_service::error::ContractError;
+use interchain_token_service::event::FlowLimitSetEvent;
+use interchain_token_service::types::FLOW_LIMITER_ROLE;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, BytesN};
+use utils::{
+    receive_interchain_transfer, register_chains, send_interchain_transfer, setup_env,
+    setup_its_token,
+};
+
+const EPOCH_TIME: u64 = 6 * 60 * 60;
+
+#[test]
+fn set_flow_limit_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+
+    let sender = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &sender, 1000);
+    let flow_limit = Some(500i128);
+
+    assert_eq!(client.flow_limit(&token_id), None);
+
+    assert_invoke_auth_ok!(
+        client.operator(),
+        client.try_set_flow_limit(&client.operator(), &token_id, &flow_limit)
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<FlowLimitSetEvent>(&env));
+
+    assert_eq!(client.flow_limit(&token_id), flow_limit);
+
+    assert_invoke_auth_ok!(
+        client.operator(),
+        client.try_set_flow_limit(&client.operator(), &token_id, &None::<i128>)
+    );
+    assert_eq!(client.flow_limit(&token_id), None);
+}
+
+#[test]
+fn set_flow_limit_fails_if_caller_is_not_operator() {
+    let (env, client, _, _, _) = setup_env();
+
+    let sender = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &sender, 1000);
+
+    assert_invoke_auth_err!(
+        client.owner(),
+        client.try_set_flow_limit(&client.operator(), &token_id, &Some(500i128))
+    );
+}
+
+#[test]
+fn set_flow_limit_fails_without_flow_limiter_role() {
+    let (env, client, _, _, _) = setup_env();
+
+    let sender = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &sender, 1000);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_set_flow_limit(&client.owner(), &token_id, &Some(500i128)),
+        ContractError::MissingRole
+    );
+}
+
+#[test]
+fn set_flow_limit_succeeds_if_granted_flow_limiter_role() {
+    let (env, client, _, _, _) = setup_env();
+
+    let sender = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &sender, 1000);
+    let flow_limiter = Address::generate(&env);
+    let flow_limit = Some(500i128);
+
+    client
+        .mock_all_auths()
+        .grant_role(&client.owner(), &FLOW_LIMITER_ROLE, &flow_limiter);
+
+    assert_invoke_auth_ok!(
+        flow_limiter,
+        client.try_set_flow_limit(&flow_limiter, &token_id, &flow_limit)
+    );
+    assert_eq!(client.flow_limit(&token_id), flow_limit);
+}
+
+#[test]
+fn set_flow_limit_to_zero_removes_limit() {
+    let (env, client, _, _, _) = setup_env();
+    register_chains(&env, &client);
+
+    let sender = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &sender, 1000);
+
+    client
+        .mock_all_auths()
+        .set_flow_limit(&client.operator(), &token_id, &Some(500));
+    client
+        .mock_all_auths()
+        .set_flow_limit(&client.operator(), &token_id, &Some(0));
+
+    assert_eq!(client.flow_limit(&token_id), None);
+    assert!(send_interchain_transfer(&env, &client, &sender, &token_id, 1000).is_ok());
+}
+
+#[test]
+fn set_flow_limit_fails_invalid_flow_limit() {
+    let (env, client, _, _, _) = setup_env();
+
+    let sender = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &sender, 1000);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_set_flow_limit(&client.operator(), &token_id, &Some(-1i128)),
+        ContractError::InvalidFlowLimit
+    );
+}
+
+#[test]
+fn set_flow_limit_fails_invalid_token_id() {
+    let (env, client, _, _, _) = setup_env();
+
+    let token_id = BytesN::from_array(&env, &[1u8; 32]);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_set_flow_limit(&client.operator(), &token_id, &Some(500i128)),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn interchain_transfer_send_tracks_flow_out() {
+    let (env, client, _, _, _) = setup_env();
+    register_chains(&env, &client);
+
+    let sender = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &sender, 1000);
+
+    client
+        .mock_all_auths()
+        .set_flow_limit(&client.operator(), &token_id, &Some(500));
+
+    assert!(send_interchain_transfer(&env, &client, &sender, &token_id, 300).is_ok());
+    assert_eq!(client.flow_out_amount(&token_id), 300);
+    assert_eq!(client.flow_in_amount(&token_id), 0);
+
+    assert!(send_interchain_transfer(&env, &client, &sender, &token_id, 200).is_ok());
+    assert_eq!(client.flow_out_amount(&token_id), 500);
+}
+
+#[test]
+fn interchain_transfer_send_fails_on_flow_limit_exceeded() {
+    let (env, client, _, _, _) = setup_env();
+    register_chains(&env, &client);
+
+    let sender = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &sender, 1000);
+
+    client
+        .mock_all_auths()
+        .set_flow_limit(&client.operator(), &token_id, &Some(500));
+
+    assert_eq!(
+        send_interchain_transfer(&env, &client, &sender, &token_id, 501),
+        Err(ContractError::FlowLimitExceeded)
+    );
+
+    assert!(send_interchain_transfer(&env, &client, &sender, &token_id, 400).is_ok());
+
+    assert_eq!(
+        send_interchain_transfer(&env, &client, &sender, &token_id, 101),
+        Err(ContractError::FlowLimitExceeded)
+    );
+    assert_eq!(client.flow_out_amount(&token_id), 400);
+}
+
+#[test]
+fn interchain_transfer_send_flow_resets_in_new_epoch() {
+    let (env, client, _, _, _) = setup_env();
+    register_chains(&env, &client);
+
+    let sender = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &sender, 1000);
+
+    client
+        .mock_all_auths()
+        .set_flow_limit(&client.operator(), &token_id, &Some(500));
+
+    assert!(send_interchain_transfer(&env, &client, &sender, &token_id, 500).is_ok());
+    assert_eq!(client.flow_out_amount(&token_id), 500);
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + EPOCH_TIME);
+
+    assert_eq!(client.flow_out_amount(&token_id), 0);
+    assert!(send_interchain_transfer(&env, &client, &sender, &token_id, 500).is_ok());
+    assert_eq!(client.flow_out_amount(&token_id), 500);
+}
+
+#[test]
+fn interchain_transfer_execute_tracks_flow_in() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let sender = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &sender, 1000);
+
+    client
+        .mock_all_auths()
+        .set_flow_limit(&client.operator(), &token_id, &Some(500));
+
+    assert!(
+        receive_interchain_transfer(&env, &client, gateway_client, signers, &token_id, 500).is_ok()
+    );
+
+    assert_eq!(client.flow_in_amount(&token_id), 500);
+    assert_eq!(client.flow_out_amount(&token_id), 0);
+
+    // incoming flow offsets outgoing flow
+    assert!(send_interchain_transfer(&env, &client, &sender, &token_id, 500).is_ok());
+    assert!(send_interchain_transfer(&env, &client, &sender, &token_id, 500).is_ok());
+    assert_eq!(client.flow_out_amount(&token_id), 1000);
+}
+
+#[test]
+fn interchain_transfer_execute_fails_on_flow_limit_exceeded() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let sender = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &sender, 1000);
+
+    client
+        .mock_all_auths()
+        .set_flow_limit(&client.operator(), &token_id, &Some(500));
+
+    assert_eq!(
+        receive_interchain_transfer(&env, &client, gateway_client, signers, &token_id, 501),
+        Err(ContractError::FlowLimitExceeded.into())
+    );
+    assert_eq!(client.flow_in_amount(&token_id), 0);
+}
@@ -0,0 +1,315 @@
+mod utils;
+
+use axelar_soroban_std::traits::BytesExt;
+use axelar_soroban_std::{assert_contract_err, assert_invoke_auth_err, events};
+use interchain_token_service::error::ContractError;
+use interchain_token_service::event::{
+    LinkTokenStartedEvent, TokenLinkedEvent, TokenMetadataRegisteredEvent,
+};
+use interchain_token_service::types::{LinkToken, Message, TokenManagerType};
+use interchain_token_service::InterchainTokenServiceClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, String};
+use utils::{receive_its_message, register_chains, setup_env, setup_gas_token, setup_its_token};
+
+const DESTINATION_CHAIN: &str = "ethereum";
+const DESTINATION_TOKEN_ADDRESS: &str = "4F4495243837681061C4743b74B3eEdf548D56A5";
+
+fn setup_custom_token(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+) -> (Address, BytesN<32>, BytesN<32>) {
+    let deployer = Address::generate(env);
+    let salt = BytesN::<32>::from_array(env, &[1; 32]);
+    let token_address = env
+        .register_stellar_asset_contract_v2(client.address.clone())
+        .address();
+
+    let token_id = client.mock_all_auths().register_custom_token(
+        &deployer,
+        &salt,
+        &token_address,
+        &TokenManagerType::LockUnlock,
+    );
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&client.owner(), &String::from_str(env, DESTINATION_CHAIN));
+
+    (deployer, salt, token_id)
+}
+
+fn link_message(env: &Env, token_id: BytesN<32>, destination_token_address: Bytes) -> Message {
+    Message::LinkToken(LinkToken {
+        token_id,
+        token_manager_type: TokenManagerType::MintBurn,
+        source_token_address: Bytes::from_hex(env, DESTINATION_TOKEN_ADDRESS),
+        destination_token_address,
+        params: None,
+    })
+}
+
+#[test]
+fn register_token_metadata_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+
+    let spender = Address::generate(&env);
+    let gas_token = setup_gas_token(&env, &spender);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    client
+        .mock_all_auths()
+        .register_token_metadata(&token_address, &spender, &gas_token);
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<
+        TokenMetadataRegisteredEvent,
+    >(&env, -4));
+}
+
+#[test]
+fn link_token_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+
+    let (deployer, salt, token_id) = setup_custom_token(&env, &client);
+    let gas_token = setup_gas_token(&env, &deployer);
+
+    let linked_token_id = client.mock_all_auths().link_token(
+        &deployer,
+        &salt,
+        &String::from_str(&env, DESTINATION_CHAIN),
+        &Bytes::from_hex(&env, DESTINATION_TOKEN_ADDRESS),
+        &TokenManagerType::MintBurn,
+        &Some(Bytes::from_hex(&env, "abcd")),
+        &gas_token,
+    );
+    assert_eq!(linked_token_id, token_id);
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<LinkTokenStartedEvent>(
+        &env, -4
+    ));
+}
+
+#[test]
+fn link_token_fails_if_deployer_not_authorized() {
+    let (env, client, _, _, _) = setup_env();
+
+    let (deployer, salt, _) = setup_custom_token(&env, &client);
+    let gas_token = setup_gas_token(&env, &deployer);
+
+    assert_invoke_auth_err!(
+        client.owner(),
+        client.try_link_token(
+            &deployer,
+            &salt,
+            &String::from_str(&env, DESTINATION_CHAIN),
+            &Bytes::from_hex(&env, DESTINATION_TOKEN_ADDRESS),
+            &TokenManagerType::MintBurn,
+            &None::<Bytes>,
+            &gas_token,
+        )
+    );
+}
+
+#[test]
+fn link_token_fails_with_native_interchain_token_type() {
+    let (env, client, _, _, _) = setup_env();
+
+    let (deployer, salt, _) = setup_custom_token(&env, &client);
+    let gas_token = setup_gas_token(&env, &deployer);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_link_token(
+            &deployer,
+            &salt,
+            &String::from_str(&env, DESTINATION_CHAIN),
+            &Bytes::from_hex(&env, DESTINATION_TOKEN_ADDRESS),
+            &TokenManagerType::NativeInterchainToken,
+            &None::<Bytes>,
+            &gas_token,
+        ),
+        ContractError::InvalidTokenManagerType
+    );
+}
+
+#[test]
+fn link_token_fails_with_empty_destination_token_address() {
+    let (env, client, _, _, _) = setup_env();
+
+    let (deployer, salt, _) = setup_custom_token(&env, &client);
+    let gas_token = setup_gas_token(&env, &deployer);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_link_token(
+            &deployer,
+            &salt,
+            &String::from_str(&env, DESTINATION_CHAIN),
+            &Bytes::new(&env),
+            &TokenManagerType::MintBurn,
+            &None::<Bytes>,
+            &gas_token,
+        ),
+        ContractError::InvalidDestinationAddress
+    );
+}
+
+#[test]
+fn link_token_fails_if_token_not_registered() {
+    let (env, client, _, _, _) = setup_env();
+
+    let (deployer, _, _) = setup_custom_token(&env, &client);
+    let gas_token = setup_gas_token(&env, &deployer);
+    let unregistered_salt = BytesN::<32>::from_array(&env, &[2; 32]);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_link_token(
+            &deployer,
+            &unregistered_salt,
+            &String::from_str(&env, DESTINATION_CHAIN),
+            &Bytes::from_hex(&env, DESTINATION_TOKEN_ADDRESS),
+            &TokenManagerType::MintBurn,
+            &None::<Bytes>,
+            &gas_token,
+        ),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn link_token_fails_with_untrusted_destination_chain() {
+    let (env, client, _, _, _) = setup_env();
+
+    let (deployer, salt, _) = setup_custom_token(&env, &client);
+    let gas_token = setup_gas_token(&env, &deployer);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_link_token(
+            &deployer,
+            &salt,
+            &String::from_str(&env, "untrusted_chain"),
+            &Bytes::from_hex(&env, DESTINATION_TOKEN_ADDRESS),
+            &TokenManagerType::MintBurn,
+            &None::<Bytes>,
+            &gas_token,
+        ),
+        ContractError::UntrustedChain
+    );
+}
+
+#[test]
+fn link_token_receive_succeeds() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let token_id = BytesN::<32>::from_array(&env, &[1; 32]);
+    let token_address = env
+        .register_stellar_asset_contract_v2(client.address.clone())
+        .address();
+
+    let message = link_message(&env, token_id.clone(), token_address.clone().to_xdr(&env));
+
+    assert!(receive_its_message(&env, &client, gateway_client, signers, message).is_ok());
+
+    goldie::assert!(events::fmt_last_emitted_event::<TokenLinkedEvent>(&env));
+
+    assert_eq!(client.token_address(&token_id), token_address);
+    assert_eq!(
+        client.token_manager_type(&token_id),
+        TokenManagerType::MintBurn
+    );
+}
+
+#[test]
+fn link_token_receive_fails_if_already_registered() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let (_, _, token_id) = setup_custom_token(&env, &client);
+    let message = link_message(&env, token_id, Address::generate(&env).to_xdr(&env));
+
+    assert_eq!(
+        receive_its_message(&env, &client, gateway_client, signers, message),
+        Err(ContractError::TokenAlreadyRegistered.into())
+    );
+}
+
+#[test]
+fn link_token_receive_fails_with_native_interchain_token_type() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(client.address.clone())
+        .address();
+    let message = Message::LinkToken(LinkToken {
+        token_id: BytesN::<32>::from_array(&env, &[1; 32]),
+        token_manager_type: TokenManagerType::NativeInterchainToken,
+        source_token_address: Bytes::from_hex(&env, DESTINATION_TOKEN_ADDRESS),
+        destination_token_address: token_address.to_xdr(&env),
+        params: None,
+    });
+
+    assert_eq!(
+        receive_its_message(&env, &client, gateway_client, signers, message),
+        Err(ContractError::InvalidTokenManagerType.into())
+    );
+}
+
+#[test]
+fn link_token_receive_fails_if_token_address_already_registered() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let (_, _, token_id) = setup_custom_token(&env, &client);
+    let token_address = client.token_address(&token_id);
+    let message = link_message(
+        &env,
+        BytesN::<32>::from_array(&env, &[2; 32]),
+        token_address.to_xdr(&env),
+    );
+
+    assert_eq!(
+        receive_its_message(&env, &client, gateway_client, signers, message),
+        Err(ContractError::TokenAlreadyRegistered.into())
+    );
+}
+
+#[test]
+fn link_token_receive_fails_with_interchain_token_deployed_by_its() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let token_id = setup_its_token(&env, &client, &Address::generate(&env), 1);
+    let message = link_message(
+        &env,
+        BytesN::<32>::from_array(&env, &[2; 32]),
+        client.token_address(&token_id).to_xdr(&env),
+    );
+
+    assert_eq!(
+        receive_its_message(&env, &client, gateway_client, signers, message),
+        Err(ContractError::TokenAlreadyRegistered.into())
+    );
+}
+
+#[test]
+fn link_token_receive_fails_if_its_is_not_token_admin() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let message = link_message(
+        &env,
+        BytesN::<32>::from_array(&env, &[1; 32]),
+        token_address.to_xdr(&env),
+    );
+
+    assert_eq!(
+        receive_its_message(&env, &client, gateway_client, signers, message),
+        Err(ContractError::TokenNotMintable.into())
+    );
+}
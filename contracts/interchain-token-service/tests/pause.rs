@@ -0,0 +1,48 @@
+mod utils;
+
+use axelar_soroban_std::{assert_contract_err, assert_invoke_auth_err, assert_invoke_auth_ok};
+use interchain_token_service::error::ContractError;
+use interchain_token_service::types::PAUSER_ROLE;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+use utils::setup_env;
+
+#[test]
+fn pause_and_unpause_succeed_for_pauser() {
+    let (_env, client, _, _, _) = setup_env();
+
+    let pauser = client.owner();
+    assert!(!client.paused());
+
+    assert_invoke_auth_ok!(pauser, client.try_pause(&pauser));
+    assert!(client.paused());
+
+    assert_invoke_auth_ok!(pauser, client.try_unpause(&pauser));
+    assert!(!client.paused());
+}
+
+#[test]
+fn pause_fails_if_caller_not_authorized() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_invoke_auth_err!(Address::generate(&env), client.try_pause(&client.owner()));
+}
+
+#[test]
+fn pause_fails_without_pauser_role() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_pause(&client.operator()),
+        ContractError::MissingRole
+    );
+    assert!(!client.paused());
+
+    let pauser = Address::generate(&env);
+    client
+        .mock_all_auths()
+        .grant_role(&client.owner(), &PAUSER_ROLE, &pauser);
+
+    assert_invoke_auth_ok!(pauser, client.try_pause(&pauser));
+    assert!(client.paused());
+}
@@ -0,0 +1,402 @@
+mod utils;
+
+use axelar_soroban_std::{assert_contract_err, assert_invoke_auth_err, events};
+use interchain_token_service::error::ContractError;
+use interchain_token_service::event::{
+    InterchainTokenIdClaimedEvent, InterchainTransferReceivedEvent, InterchainTransferSentEvent,
+};
+use interchain_token_service::types::TokenManagerType;
+use interchain_token_service::InterchainTokenServiceClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use utils::{
+    receive_interchain_transfer, register_chains, send_interchain_transfer, setup_env,
+    setup_its_token,
+};
+
+/// A token that takes a 10% fee on every transfer.
+#[contract]
+pub struct FeeOnTransferToken;
+
+#[contractimpl]
+impl FeeOnTransferToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(balance + amount));
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        7
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+
+        let from_balance = Self::balance(env.clone(), from.clone());
+        assert!(from_balance >= amount, "insufficient balance");
+
+        let to_balance = Self::balance(env.clone(), to.clone());
+        let fee = amount / 10;
+
+        env.storage()
+            .persistent()
+            .set(&from, &(from_balance - amount));
+        env.storage()
+            .persistent()
+            .set(&to, &(to_balance + amount - fee));
+    }
+}
+
+fn register_custom_token(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    token_address: &Address,
+    token_manager_type: TokenManagerType,
+) -> BytesN<32> {
+    let deployer = Address::generate(env);
+    let salt = BytesN::<32>::from_array(env, &[1; 32]);
+
+    client.mock_all_auths().register_custom_token(
+        &deployer,
+        &salt,
+        token_address,
+        &token_manager_type,
+    )
+}
+
+#[test]
+fn register_custom_token_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+
+    let deployer = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let token_address = env
+        .register_stellar_asset_contract_v2(client.address.clone())
+        .address();
+
+    let token_id = client.mock_all_auths().register_custom_token(
+        &deployer,
+        &salt,
+        &token_address,
+        &TokenManagerType::MintBurn,
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<
+        InterchainTokenIdClaimedEvent,
+    >(&env));
+
+    assert_eq!(client.token_address(&token_id), token_address);
+    assert_eq!(
+        client.token_manager_type(&token_id),
+        TokenManagerType::MintBurn
+    );
+}
+
+#[test]
+fn register_custom_token_fails_if_deployer_not_authorized() {
+    let (env, client, _, _, _) = setup_env();
+
+    let deployer = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let token_address = Address::generate(&env);
+    let token_manager_type = TokenManagerType::LockUnlock;
+
+    assert_invoke_auth_err!(
+        client.owner(),
+        client.try_register_custom_token(&deployer, &salt, &token_address, &token_manager_type)
+    );
+}
+
+#[test]
+fn register_custom_token_fails_with_native_interchain_token_type() {
+    let (env, client, _, _, _) = setup_env();
+
+    let deployer = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let token_address = Address::generate(&env);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_register_custom_token(
+            &deployer,
+            &salt,
+            &token_address,
+            &TokenManagerType::NativeInterchainToken
+        ),
+        ContractError::InvalidTokenManagerType
+    );
+}
+
+#[test]
+fn register_custom_token_fails_if_already_registered() {
+    let (env, client, _, _, _) = setup_env();
+
+    let deployer = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    client.mock_all_auths().register_custom_token(
+        &deployer,
+        &salt,
+        &token_address,
+        &TokenManagerType::LockUnlock,
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_register_custom_token(
+            &deployer,
+            &salt,
+            &token_address,
+            &TokenManagerType::LockUnlockFee
+        ),
+        ContractError::TokenAlreadyRegistered
+    );
+}
+
+#[test]
+fn register_custom_token_fails_if_token_address_already_registered() {
+    let (env, client, _, _, _) = setup_env();
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    register_custom_token(&env, &client, &token_address, TokenManagerType::LockUnlock);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_register_custom_token(
+            &Address::generate(&env),
+            &BytesN::<32>::from_array(&env, &[2; 32]),
+            &token_address,
+            &TokenManagerType::LockUnlock
+        ),
+        ContractError::TokenAlreadyRegistered
+    );
+}
+
+#[test]
+fn register_custom_token_fails_with_interchain_token_deployed_by_its() {
+    let (env, client, _, _, _) = setup_env();
+
+    let token_id = setup_its_token(&env, &client, &Address::generate(&env), 1);
+    let token_address = client.token_address(&token_id);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_register_custom_token(
+            &Address::generate(&env),
+            &BytesN::<32>::from_array(&env, &[1; 32]),
+            &token_address,
+            &TokenManagerType::MintBurn
+        ),
+        ContractError::TokenAlreadyRegistered
+    );
+}
+
+#[test]
+fn register_custom_token_fails_with_invalid_token_address() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_register_custom_token(
+            &Address::generate(&env),
+            &BytesN::<32>::from_array(&env, &[1; 32]),
+            &Address::generate(&env),
+            &TokenManagerType::LockUnlock
+        ),
+        ContractError::InvalidTokenAddress
+    );
+}
+
+#[test]
+fn register_custom_token_fails_if_its_is_not_token_admin() {
+    let (env, client, _, _, _) = setup_env();
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    for token_manager_type in [TokenManagerType::MintBurn, TokenManagerType::MintBurnFrom] {
+        assert_contract_err!(
+            client.mock_all_auths().try_register_custom_token(
+                &Address::generate(&env),
+                &BytesN::<32>::from_array(&env, &[1; 32]),
+                &token_address,
+                &token_manager_type
+            ),
+            ContractError::TokenNotMintable
+        );
+    }
+}
+
+#[test]
+fn mint_burn_token_send_and_receive_succeeds() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(client.address.clone())
+        .address();
+    let token_id = register_custom_token(&env, &client, &token_address, TokenManagerType::MintBurn);
+
+    let sender = Address::generate(&env);
+    let amount = 1000;
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .mint(&sender, &amount);
+
+    let token = TokenClient::new(&env, &token_address);
+
+    assert!(send_interchain_transfer(&env, &client, &sender, &token_id, amount).is_ok());
+    assert_eq!(token.balance(&sender), 0);
+    assert_eq!(token.balance(&client.address), 0);
+
+    assert!(
+        receive_interchain_transfer(&env, &client, gateway_client, signers, &token_id, amount)
+            .is_ok()
+    );
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn mint_burn_from_token_send_and_receive_succeeds() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(client.address.clone())
+        .address();
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &token_address,
+        TokenManagerType::MintBurnFrom,
+    );
+
+    let sender = Address::generate(&env);
+    let amount = 1000;
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .mint(&sender, &amount);
+
+    let token = TokenClient::new(&env, &token_address);
+
+    token.mock_all_auths().approve(
+        &sender,
+        &client.address,
+        &amount,
+        &(env.ledger().sequence() + 1000),
+    );
+
+    assert!(send_interchain_transfer(&env, &client, &sender, &token_id, amount).is_ok());
+    assert_eq!(token.balance(&sender), 0);
+    assert_eq!(token.allowance(&sender, &client.address), 0);
+
+    assert!(
+        receive_interchain_transfer(&env, &client, gateway_client, signers, &token_id, amount)
+            .is_ok()
+    );
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn lock_unlock_fee_token_send_uses_received_amount() {
+    let (env, client, _, _, _) = setup_env();
+    register_chains(&env, &client);
+
+    let token_address = env.register(FeeOnTransferToken, ());
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &token_address,
+        TokenManagerType::LockUnlockFee,
+    );
+
+    let sender = Address::generate(&env);
+    let amount = 1000;
+    FeeOnTransferTokenClient::new(&env, &token_address).mint(&sender, &amount);
+
+    assert!(send_interchain_transfer(&env, &client, &sender, &token_id, amount).is_ok());
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<
+        InterchainTransferSentEvent,
+    >(&env, -4));
+
+    let token = TokenClient::new(&env, &token_address);
+    assert_eq!(token.balance(&sender), 0);
+    assert_eq!(token.balance(&client.address), 900);
+}
+
+#[test]
+fn lock_unlock_fee_token_send_scales_received_amount_and_returns_dust() {
+    let (env, client, _, _, _) = setup_env();
+    register_chains(&env, &client);
+
+    let token_address = env.register(FeeOnTransferToken, ());
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &token_address,
+        TokenManagerType::LockUnlockFee,
+    );
+    client
+        .mock_all_auths()
+        .set_token_decimals(&token_id, &client.chain_name(), &7);
+    client
+        .mock_all_auths()
+        .set_token_decimals(&token_id, &client.its_hub_chain_name(), &6);
+
+    let sender = Address::generate(&env);
+    let amount = 1005;
+    FeeOnTransferTokenClient::new(&env, &token_address).mint(&sender, &amount);
+    client
+        .mock_all_auths()
+        .set_flow_limit(&client.operator(), &token_id, &Some(amount));
+
+    // 905 tokens are received after the fee, of which 5 can't be represented with 6 decimals
+    assert!(send_interchain_transfer(&env, &client, &sender, &token_id, amount).is_ok());
+
+    let token = TokenClient::new(&env, &token_address);
+    assert_eq!(token.balance(&sender), 5);
+    assert_eq!(token.balance(&client.address), 900);
+    assert_eq!(client.flow_out_amount(&token_id), 905);
+    assert_eq!(client.flow_in_amount(&token_id), 5);
+}
+
+#[test]
+fn lock_unlock_fee_token_receive_uses_received_amount() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let token_address = env.register(FeeOnTransferToken, ());
+    let token_id = register_custom_token(
+        &env,
+        &client,
+        &token_address,
+        TokenManagerType::LockUnlockFee,
+    );
+
+    let amount = 1000;
+    FeeOnTransferTokenClient::new(&env, &token_address).mint(&client.address, &amount);
+    client
+        .mock_all_auths()
+        .set_flow_limit(&client.operator(), &token_id, &Some(amount));
+
+    assert!(
+        receive_interchain_transfer(&env, &client, gateway_client, signers, &token_id, amount)
+            .is_ok()
+    );
+
+    goldie::assert!(events::fmt_last_emitted_event::<
+        InterchainTransferReceivedEvent,
+    >(&env));
+
+    let token = TokenClient::new(&env, &token_address);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(client.flow_in_amount(&token_id), 900);
+}
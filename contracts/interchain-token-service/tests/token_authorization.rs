@@ -0,0 +1,146 @@
+mod utils;
+
+use axelar_soroban_std::{assert_contract_err, assert_invoke_auth_err, assert_invoke_auth_ok};
+use interchain_token::InterchainToken;
+use interchain_token_service::error::ContractError;
+use interchain_token_service::types::TokenManagerType;
+use interchain_token_service::InterchainTokenServiceClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, BytesN, Env};
+use soroban_token_sdk::metadata::TokenMetadata;
+use utils::{setup_env, setup_its_token};
+
+/// Deploys an interchain token via ITS and replaces its code with the current token contract,
+/// since the prebuilt token wasm used by ITS in tests doesn't support authorization and clawback.
+fn setup_token(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    account: &Address,
+    supply: i128,
+) -> BytesN<32> {
+    let token_id = setup_its_token(env, client, account, 0);
+    let token_address = client.token_address(&token_id);
+    let token = TokenClient::new(env, &token_address);
+
+    env.register_at(
+        &token_address,
+        InterchainToken,
+        (
+            client.address.clone(),
+            None::<Address>,
+            token_id.clone(),
+            TokenMetadata {
+                name: token.name(),
+                symbol: token.symbol(),
+                decimal: token.decimals(),
+            },
+        ),
+    );
+    StellarAssetClient::new(env, &token_address)
+        .mock_all_auths()
+        .mint(account, &supply);
+
+    token_id
+}
+
+#[test]
+fn set_token_authorized_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+
+    let account = Address::generate(&env);
+    let token_id = setup_token(&env, &client, &account, 100);
+    let token = StellarAssetClient::new(&env, &client.token_address(&token_id));
+
+    assert_invoke_auth_ok!(
+        client.operator(),
+        client.try_set_token_authorized(&token_id, &account, &false)
+    );
+    assert!(!token.authorized(&account));
+
+    assert_invoke_auth_ok!(
+        client.operator(),
+        client.try_set_token_authorized(&token_id, &account, &true)
+    );
+    assert!(token.authorized(&account));
+}
+
+#[test]
+fn set_token_authorized_fails_if_not_operator() {
+    let (env, client, _, _, _) = setup_env();
+
+    let account = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &account, 100);
+
+    assert_invoke_auth_err!(
+        client.owner(),
+        client.try_set_token_authorized(&token_id, &account, &false)
+    );
+}
+
+#[test]
+fn set_token_authorized_fails_with_invalid_token_id() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_set_token_authorized(
+            &BytesN::from_array(&env, &[1; 32]),
+            &Address::generate(&env),
+            &false
+        ),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn set_token_authorized_fails_for_custom_token() {
+    let (env, client, _, _, _) = setup_env();
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(client.address.clone())
+        .address();
+    let token_id = client.mock_all_auths().register_custom_token(
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[1; 32]),
+        &token_address,
+        &TokenManagerType::MintBurn,
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_set_token_authorized(
+            &token_id,
+            &Address::generate(&env),
+            &false
+        ),
+        ContractError::InvalidTokenManagerType
+    );
+}
+
+#[test]
+fn clawback_token_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+
+    let account = Address::generate(&env);
+    let token_id = setup_token(&env, &client, &account, 100);
+    let token = TokenClient::new(&env, &client.token_address(&token_id));
+    let amount: i128 = 40;
+
+    assert_invoke_auth_ok!(
+        client.operator(),
+        client.try_clawback_token(&token_id, &account, &amount)
+    );
+    assert_eq!(token.balance(&account), 100 - amount);
+}
+
+#[test]
+fn clawback_token_fails_if_not_operator() {
+    let (env, client, _, _, _) = setup_env();
+
+    let account = Address::generate(&env);
+    let token_id = setup_its_token(&env, &client, &account, 100);
+
+    assert_invoke_auth_err!(
+        client.owner(),
+        client.try_clawback_token(&token_id, &account, &40i128)
+    );
+}
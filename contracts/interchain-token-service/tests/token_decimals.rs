@@ -0,0 +1,364 @@
+mod utils;
+
+use axelar_gateway::event::ContractCalledEvent;
+use axelar_soroban_std::events::EventTestutils;
+use axelar_soroban_std::{assert_contract_err, events};
+use interchain_token_service::error::ContractError;
+use interchain_token_service::event::{DustRecordedEvent, TokenDecimalsSetEvent};
+use interchain_token_service::types::{
+    DeployInterchainToken, HubMessage, InterchainTransfer, LinkToken, Message, TokenManagerType,
+};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::token;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, String};
+use utils::{
+    receive_its_message, register_chains, setup_env, setup_gas_token, setup_its_token, HUB_CHAIN,
+};
+
+#[test]
+fn set_token_decimals_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+
+    let token_id = setup_its_token(&env, &client, &Address::generate(&env), 1);
+    let chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_token_decimals(&token_id, &chain, &6);
+
+    goldie::assert!(events::fmt_last_emitted_event::<TokenDecimalsSetEvent>(
+        &env
+    ));
+
+    assert_eq!(client.token_decimals(&token_id, &chain), Some(6));
+}
+
+#[test]
+fn set_token_decimals_fails_with_invalid_token_id() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_set_token_decimals(
+            &BytesN::from_array(&env, &[1; 32]),
+            &String::from_str(&env, "ethereum"),
+            &6
+        ),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn deploy_remote_interchain_token_records_token_decimals() {
+    let (env, client, _, _, _) = setup_env();
+
+    let sender = Address::generate(&env);
+    let gas_token = setup_gas_token(&env, &sender);
+    let token_id = setup_its_token(&env, &client, &sender, 1);
+    let destination_chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&client.owner(), &destination_chain);
+
+    assert_eq!(client.token_decimals(&token_id, &destination_chain), None);
+
+    client.mock_all_auths().deploy_remote_interchain_token(
+        &sender,
+        &BytesN::from_array(&env, &[1; 32]),
+        &destination_chain,
+        &gas_token,
+    );
+
+    assert_eq!(
+        client.token_decimals(&token_id, &destination_chain),
+        Some(18)
+    );
+}
+
+#[test]
+fn deploy_interchain_token_message_execute_records_source_chain_decimals() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let token_id = BytesN::from_array(&env, &[1; 32]);
+    let message = Message::DeployInterchainToken(DeployInterchainToken {
+        token_id: token_id.clone(),
+        name: String::from_str(&env, "Test"),
+        symbol: String::from_str(&env, "TEST"),
+        decimals: 6,
+        minter: None,
+    });
+
+    receive_its_message(&env, &client, gateway_client, signers, message).unwrap();
+
+    assert_eq!(
+        client.token_decimals(&token_id, &String::from_str(&env, HUB_CHAIN)),
+        Some(6)
+    );
+}
+
+#[test]
+fn interchain_transfer_send_scales_amount_down_and_leaves_dust() {
+    let (env, client, _, _, _) = setup_env();
+
+    let sender = Address::generate(&env);
+    let gas_token = setup_gas_token(&env, &sender);
+    let dust = 123;
+    let amount = 1_000_000_000_000_000_000 + dust;
+    let token_id = setup_its_token(&env, &client, &sender, amount);
+
+    let destination_chain = String::from_str(&env, "ethereum");
+    let destination_address = Bytes::from_array(&env, &[1; 20]);
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&client.owner(), &destination_chain);
+    client
+        .mock_all_auths()
+        .set_token_decimals(&token_id, &destination_chain, &6);
+
+    client.mock_all_auths().interchain_transfer(
+        &sender,
+        &token_id,
+        &destination_chain,
+        &destination_address,
+        &amount,
+        &None,
+        &gas_token,
+    );
+
+    let token = token::TokenClient::new(&env, &client.token_address(&token_id));
+    assert_eq!(token.balance(&sender), dust);
+
+    let payload = HubMessage::SendToHub {
+        destination_chain,
+        message: Message::InterchainTransfer(InterchainTransfer {
+            token_id,
+            source_address: sender.to_xdr(&env),
+            destination_address,
+            amount: 1_000_000,
+            data: None,
+        }),
+    }
+    .abi_encode(&env)
+    .unwrap();
+
+    let expected_event = ContractCalledEvent {
+        caller: client.address.clone(),
+        destination_chain: client.its_hub_chain_name(),
+        destination_address: client.its_hub_address(),
+        payload_hash: env.crypto().keccak256(&payload).into(),
+        payload,
+    };
+    assert!(expected_event.matches(&env, &env.events().all().last().unwrap()));
+}
+
+#[test]
+fn interchain_transfer_send_fails_if_scaled_amount_is_zero() {
+    let (env, client, _, _, _) = setup_env();
+
+    let sender = Address::generate(&env);
+    let gas_token = setup_gas_token(&env, &sender);
+    let amount = 999_999_999_999;
+    let token_id = setup_its_token(&env, &client, &sender, amount);
+
+    let destination_chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&client.owner(), &destination_chain);
+    client
+        .mock_all_auths()
+        .set_token_decimals(&token_id, &destination_chain, &6);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_interchain_transfer(
+            &sender,
+            &token_id,
+            &destination_chain,
+            &Bytes::from_array(&env, &[1; 20]),
+            &amount,
+            &None,
+            &gas_token,
+        ),
+        ContractError::ScaledAmountZero
+    );
+}
+
+#[test]
+fn interchain_transfer_receive_scales_amount_up() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let token_id = setup_its_token(&env, &client, &Address::generate(&env), 1);
+    client
+        .mock_all_auths()
+        .set_token_decimals(&token_id, &String::from_str(&env, HUB_CHAIN), &6);
+
+    let recipient = Address::generate(&env);
+    let message = Message::InterchainTransfer(InterchainTransfer {
+        token_id: token_id.clone(),
+        source_address: Address::generate(&env).to_xdr(&env),
+        destination_address: recipient.clone().to_xdr(&env),
+        amount: 1_000_000,
+        data: None,
+    });
+
+    receive_its_message(&env, &client, gateway_client, signers, message).unwrap();
+
+    let token = token::TokenClient::new(&env, &client.token_address(&token_id));
+    assert_eq!(token.balance(&recipient), 1_000_000_000_000_000_000);
+}
+
+#[test]
+fn interchain_transfer_receive_scales_amount_down_and_records_dust() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let source_chain = String::from_str(&env, HUB_CHAIN);
+    let token_id = setup_its_token(&env, &client, &Address::generate(&env), 1);
+    client
+        .mock_all_auths()
+        .set_token_decimals(&token_id, &source_chain, &20);
+
+    let recipient = Address::generate(&env);
+    let message = Message::InterchainTransfer(InterchainTransfer {
+        token_id: token_id.clone(),
+        source_address: Address::generate(&env).to_xdr(&env),
+        destination_address: recipient.clone().to_xdr(&env),
+        amount: 1_000_123,
+        data: None,
+    });
+
+    receive_its_message(&env, &client, gateway_client, signers, message).unwrap();
+
+    let token = token::TokenClient::new(&env, &client.token_address(&token_id));
+    assert_eq!(token.balance(&recipient), 10_001);
+    assert_eq!(client.dust(&token_id, &source_chain), 23);
+}
+
+#[test]
+fn interchain_transfer_receive_records_whole_amount_as_dust_if_scaled_amount_is_zero() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let source_chain = String::from_str(&env, HUB_CHAIN);
+    let token_id = setup_its_token(&env, &client, &Address::generate(&env), 1);
+    client
+        .mock_all_auths()
+        .set_token_decimals(&token_id, &source_chain, &20);
+
+    let recipient = Address::generate(&env);
+    let message = Message::InterchainTransfer(InterchainTransfer {
+        token_id: token_id.clone(),
+        source_address: Address::generate(&env).to_xdr(&env),
+        destination_address: recipient.clone().to_xdr(&env),
+        amount: 99,
+        data: None,
+    });
+
+    receive_its_message(&env, &client, gateway_client, signers, message).unwrap();
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<DustRecordedEvent>(
+        &env, -3
+    ));
+
+    let token = token::TokenClient::new(&env, &client.token_address(&token_id));
+    assert_eq!(token.balance(&recipient), 0);
+    assert_eq!(client.dust(&token_id, &source_chain), 99);
+}
+
+#[test]
+fn interchain_transfer_receive_records_whole_amount_as_dust_if_scaled_amount_overflows() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let source_chain = String::from_str(&env, HUB_CHAIN);
+    let token_id = setup_its_token(&env, &client, &Address::generate(&env), 1);
+    client
+        .mock_all_auths()
+        .set_token_decimals(&token_id, &source_chain, &0);
+
+    let recipient = Address::generate(&env);
+    let amount = i128::MAX / 10;
+    let message = Message::InterchainTransfer(InterchainTransfer {
+        token_id: token_id.clone(),
+        source_address: Address::generate(&env).to_xdr(&env),
+        destination_address: recipient.clone().to_xdr(&env),
+        amount,
+        data: None,
+    });
+
+    receive_its_message(&env, &client, gateway_client, signers, message).unwrap();
+
+    let token = token::TokenClient::new(&env, &client.token_address(&token_id));
+    assert_eq!(token.balance(&recipient), 0);
+    assert_eq!(client.dust(&token_id, &source_chain), amount);
+}
+
+#[test]
+fn deploy_interchain_token_records_local_decimals() {
+    let (env, client, _, _, _) = setup_env();
+
+    let token_id = setup_its_token(&env, &client, &Address::generate(&env), 1);
+
+    assert_eq!(
+        client.token_decimals(&token_id, &client.chain_name()),
+        Some(18)
+    );
+}
+
+#[test]
+fn register_token_metadata_records_local_decimals() {
+    let (env, client, _, _, _) = setup_env();
+
+    let spender = Address::generate(&env);
+    let gas_token = setup_gas_token(&env, &spender);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_id = client.mock_all_auths().register_custom_token(
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[1; 32]),
+        &token_address,
+        &TokenManagerType::LockUnlock,
+    );
+
+    assert_eq!(client.token_decimals(&token_id, &client.chain_name()), None);
+
+    client
+        .mock_all_auths()
+        .register_token_metadata(&token_address, &spender, &gas_token);
+
+    assert_eq!(
+        client.token_decimals(&token_id, &client.chain_name()),
+        Some(7)
+    );
+}
+
+#[test]
+fn link_token_receive_records_local_decimals() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+    register_chains(&env, &client);
+
+    let token_id = BytesN::<32>::from_array(&env, &[1; 32]);
+    let token_address = env
+        .register_stellar_asset_contract_v2(client.address.clone())
+        .address();
+
+    let message = Message::LinkToken(LinkToken {
+        token_id: token_id.clone(),
+        token_manager_type: TokenManagerType::LockUnlock,
+        source_token_address: Bytes::from_array(&env, &[1; 20]),
+        destination_token_address: token_address.to_xdr(&env),
+        params: None,
+    });
+
+    receive_its_message(&env, &client, gateway_client, signers, message).unwrap();
+
+    assert_eq!(
+        client.token_decimals(&token_id, &client.chain_name()),
+        Some(7)
+    );
+}
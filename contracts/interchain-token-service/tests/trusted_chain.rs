@@ -7,6 +7,7 @@ use axelar_soroban_std::{
     assert_contract_err, assert_invoke_auth_err, assert_invoke_auth_ok, events,
 };
 use interchain_token_service::error::ContractError;
+use interchain_token_service::types::CHAIN_MANAGER_ROLE;
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::{Address, String};
 
@@ -16,7 +17,10 @@ fn set_trusted_address() {
 
     let chain = String::from_str(&env, "chain");
 
-    assert_invoke_auth_ok!(client.owner(), client.try_set_trusted_chain(&chain));
+    assert_invoke_auth_ok!(
+        client.owner(),
+        client.try_set_trusted_chain(&client.owner(), &chain)
+    );
 
     goldie::assert!(events::fmt_last_emitted_event::<TrustedChainSetEvent>(&env));
 
@@ -24,13 +28,49 @@ fn set_trusted_address() {
 }
 
 #[test]
-fn set_trusted_chain_fails_if_not_owner() {
+fn set_trusted_chain_fails_if_caller_not_authorized() {
     let (env, client, _, _, _) = setup_env();
 
     let not_owner = Address::generate(&env);
     let chain = String::from_str(&env, "chain");
 
-    assert_invoke_auth_err!(not_owner, client.try_set_trusted_chain(&chain));
+    assert_invoke_auth_err!(
+        not_owner,
+        client.try_set_trusted_chain(&client.owner(), &chain)
+    );
+}
+
+#[test]
+fn set_trusted_chain_fails_without_chain_manager_role() {
+    let (env, client, _, _, _) = setup_env();
+
+    let not_chain_manager = Address::generate(&env);
+    let chain = String::from_str(&env, "chain");
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_set_trusted_chain(&not_chain_manager, &chain),
+        ContractError::MissingRole
+    );
+}
+
+#[test]
+fn set_trusted_chain_succeeds_if_granted_chain_manager_role() {
+    let (env, client, _, _, _) = setup_env();
+
+    let chain_manager = Address::generate(&env);
+    let chain = String::from_str(&env, "chain");
+
+    client
+        .mock_all_auths()
+        .grant_role(&client.owner(), &CHAIN_MANAGER_ROLE, &chain_manager);
+
+    assert_invoke_auth_ok!(
+        chain_manager,
+        client.try_set_trusted_chain(&chain_manager, &chain)
+    );
+    assert!(client.is_trusted_chain(&chain));
 }
 
 #[test]
@@ -39,10 +79,10 @@ fn set_trusted_chain_fails_if_already_set() {
     env.mock_all_auths();
 
     let chain = String::from_str(&env, "chain");
-    client.set_trusted_chain(&chain);
+    client.set_trusted_chain(&client.owner(), &chain);
 
     assert_contract_err!(
-        client.try_set_trusted_chain(&chain),
+        client.try_set_trusted_chain(&client.owner(), &chain),
         ContractError::TrustedChainAlreadySet
     );
 }
@@ -53,9 +93,15 @@ fn remove_trusted_chain() {
 
     let chain = String::from_str(&env, "chain");
 
-    assert_invoke_auth_ok!(client.owner(), client.try_set_trusted_chain(&chain));
+    assert_invoke_auth_ok!(
+        client.owner(),
+        client.try_set_trusted_chain(&client.owner(), &chain)
+    );
 
-    assert_invoke_auth_ok!(client.owner(), client.try_remove_trusted_chain(&chain));
+    assert_invoke_auth_ok!(
+        client.owner(),
+        client.try_remove_trusted_chain(&client.owner(), &chain)
+    );
 
     goldie::assert!(events::fmt_last_emitted_event::<TrustedChainRemovedEvent>(
         &env
@@ -74,7 +120,7 @@ fn remove_trusted_chain_fails_if_not_set() {
     assert!(!client.is_trusted_chain(&chain));
 
     assert_contract_err!(
-        client.try_remove_trusted_chain(&chain),
+        client.try_remove_trusted_chain(&client.owner(), &chain),
         ContractError::TrustedChainNotSet
     );
 }
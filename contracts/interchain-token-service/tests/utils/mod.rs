@@ -1,10 +1,13 @@
 use axelar_gas_service::{AxelarGasService, AxelarGasServiceClient};
 use axelar_gateway::testutils::{generate_proof, get_approve_hash, setup_gateway, TestSignerSet};
-use axelar_gateway::{types::Message, AxelarGatewayClient};
+use axelar_gateway::{types::Message as GatewayMessage, AxelarGatewayClient};
 use axelar_soroban_std::types::Token;
+use interchain_token_service::error::ContractError;
+use interchain_token_service::types::{HubMessage, InterchainTransfer, Message};
 use interchain_token_service::{InterchainTokenService, InterchainTokenServiceClient};
+use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{testutils::Address as _, token::StellarAssetClient, Address, Env, String, Vec};
-use soroban_sdk::{BytesN, IntoVal};
+use soroban_sdk::{vec, Bytes, BytesN, IntoVal};
 use soroban_token_sdk::metadata::TokenMetadata;
 
 pub const HUB_CHAIN: &str = "axelar";
@@ -26,6 +29,7 @@ fn setup_its<'a>(
     gas_service: &AxelarGasServiceClient,
 ) -> InterchainTokenServiceClient<'a> {
     let owner = Address::generate(env);
+    let operator = Address::generate(env);
     let its_hub_address = String::from_str(env, "its_hub_address");
     let chain_name = String::from_str(env, "chain_name");
 
@@ -39,6 +43,7 @@ fn setup_its<'a>(
         InterchainTokenService,
         (
             &owner,
+            &operator,
             &gateway.address,
             &gas_service.address,
             its_hub_address,
@@ -111,7 +116,9 @@ pub fn setup_its_token(
 #[allow(dead_code)]
 pub fn register_chains(env: &Env, client: &InterchainTokenServiceClient) {
     let chain = String::from_str(env, HUB_CHAIN);
-    client.mock_all_auths().set_trusted_chain(&chain);
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&client.owner(), &chain);
 }
 
 #[allow(dead_code)]
@@ -119,13 +126,98 @@ pub fn approve_gateway_messages(
     env: &Env,
     gateway_client: AxelarGatewayClient,
     signers: TestSignerSet,
-    messages: Vec<Message>,
+    messages: Vec<GatewayMessage>,
 ) {
     let data_hash = get_approve_hash(env, messages.clone());
     let proof = generate_proof(env, data_hash, signers);
     gateway_client.approve_messages(&messages, &proof);
 }
 
+#[allow(dead_code)]
+pub fn send_interchain_transfer(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    sender: &Address,
+    token_id: &BytesN<32>,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let gas_token = setup_gas_token(env, sender);
+    let destination_chain = client.its_hub_chain_name();
+    let destination_address = Bytes::from_array(env, &[1u8; 20]);
+
+    client
+        .mock_all_auths()
+        .try_interchain_transfer(
+            sender,
+            token_id,
+            &destination_chain,
+            &destination_address,
+            &amount,
+            &None,
+            &gas_token,
+        )
+        .map(|_| ())
+        .map_err(|err| err.expect("unexpected host error"))
+}
+
+#[allow(dead_code)]
+pub fn receive_interchain_transfer(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    gateway_client: AxelarGatewayClient,
+    signers: TestSignerSet,
+    token_id: &BytesN<32>,
+    amount: i128,
+) -> Result<(), soroban_sdk::Error> {
+    let message = Message::InterchainTransfer(InterchainTransfer {
+        token_id: token_id.clone(),
+        source_address: Address::generate(env).to_xdr(env),
+        destination_address: Address::generate(env).to_xdr(env),
+        amount,
+        data: None,
+    });
+
+    receive_its_message(env, client, gateway_client, signers, message)
+}
+
+/// Approves and executes the `message` as if it was routed from the hub chain via the ITS hub.
+#[allow(dead_code)]
+pub fn receive_its_message(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    gateway_client: AxelarGatewayClient,
+    signers: TestSignerSet,
+    message: Message,
+) -> Result<(), soroban_sdk::Error> {
+    let source_chain = client.its_hub_chain_name();
+    let source_address = Address::generate(env).to_string();
+    let message_id = String::from_str(env, "test");
+
+    let payload = HubMessage::ReceiveFromHub {
+        source_chain: String::from_str(env, HUB_CHAIN),
+        message,
+    }
+    .abi_encode(env)
+    .unwrap();
+
+    let messages = vec![
+        env,
+        GatewayMessage {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+            source_address: source_address.clone(),
+            contract_address: client.address.clone(),
+            payload_hash: env.crypto().keccak256(&payload).into(),
+        },
+    ];
+    approve_gateway_messages(env, gateway_client, signers, messages);
+
+    client
+        .try_execute(&source_chain, &message_id, &source_address, &payload)
+        .map(|_| ())
+        .map_err(|err| err.expect("unexpected conversion error"))
+}
+
 #[allow(dead_code)]
 pub trait TokenMetadataExt {
     fn new(env: &Env, name: &str, symbol: &str, decimal: u32) -> Self;
@@ -1,16 +1,17 @@
+use axelar_soroban_std::events::Event;
 use axelar_soroban_std::token::validate_token_metadata;
-use axelar_soroban_std::ttl::{extend_instance_ttl, extend_persistent_ttl};
+use axelar_soroban_std::ttl::{extend_instance_ttl, TtlConfig};
 use soroban_token_sdk::metadata::TokenMetadata;
 use soroban_token_sdk::TokenUtils;
 
 use crate::error::ContractError;
-use crate::event;
+use crate::event::{MinterAddedEvent, MinterRemovedEvent};
 use crate::storage_types::DataKey;
 
 use crate::interface::InterchainTokenInterface;
 use crate::storage_types::{AllowanceDataKey, AllowanceValue};
 use axelar_soroban_std::interfaces::OwnableInterface;
-use axelar_soroban_std::{ensure, interfaces, Upgradable};
+use axelar_soroban_std::{ensure, interfaces, TtlConfigurable, Upgradable};
 use soroban_sdk::token::{StellarAssetInterface, TokenInterface};
 
 use soroban_sdk::{
@@ -20,7 +21,8 @@ use soroban_sdk::{
 use soroban_token_sdk::event::Events as TokenEvents;
 
 #[contract]
-#[derive(Upgradable)]
+#[derive(Upgradable, TtlConfigurable)]
+#[upgradable(wasm_registry)]
 pub struct InterchainToken;
 
 #[contractimpl]
@@ -52,20 +54,38 @@ impl InterchainToken {
 
 #[contractimpl]
 impl StellarAssetInterface for InterchainToken {
+    /// Proposes `admin` as the new owner of the token, who becomes the admin once they accept the ownership.
     fn set_admin(env: Env, admin: Address) {
-        Self::transfer_ownership(&env, admin);
+        Self::propose_ownership(&env, admin);
     }
 
     fn admin(env: Env) -> Address {
         Self::owner(&env)
     }
 
-    fn set_authorized(_env: Env, _id: Address, _authorize: bool) {
-        todo!()
+    fn set_authorized(env: Env, id: Address, authorize: bool) {
+        let owner = Self::owner(&env);
+        owner.require_auth();
+
+        let ttl = TtlConfig::load(&env);
+        let key = DataKey::Deauthorized(id.clone());
+
+        if authorize {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &());
+            ttl.extend_persistent_ttl(&env, &key);
+        }
+
+        ttl.extend_instance_ttl(&env);
+
+        TokenUtils::new(&env)
+            .events()
+            .set_authorized(owner, id, authorize);
     }
 
-    fn authorized(_env: Env, _id: Address) -> bool {
-        todo!()
+    fn authorized(env: Env, id: Address) -> bool {
+        Self::is_authorized(&env, &TtlConfig::load(&env), id)
     }
 
     fn mint(env: Env, to: Address, amount: i128) {
@@ -74,8 +94,19 @@ impl StellarAssetInterface for InterchainToken {
         }
     }
 
-    fn clawback(_env: Env, _from: Address, _amount: i128) {
-        todo!()
+    fn clawback(env: Env, from: Address, amount: i128) {
+        let owner = Self::owner(&env);
+        owner.require_auth();
+
+        let ttl = TtlConfig::load(&env);
+
+        Self::validate_amount(&env, amount);
+        // clawback must succeed regardless of the authorization state of `from`
+        Self::spend_balance(&env, &ttl, from.clone(), amount);
+
+        ttl.extend_instance_ttl(&env);
+
+        TokenUtils::new(&env).events().clawback(owner, from, amount);
     }
 }
 
@@ -105,11 +136,18 @@ impl InterchainTokenInterface for InterchainToken {
             ContractError::NotMinter
         );
 
+        let ttl = TtlConfig::load(env);
+
+        ensure!(
+            Self::is_authorized(env, &ttl, to.clone()),
+            ContractError::AccountNotAuthorized
+        );
+
         Self::validate_amount(env, amount);
 
         Self::receive_balance(env, to.clone(), amount);
 
-        extend_instance_ttl(env);
+        ttl.extend_instance_ttl(env);
 
         TokenUtils::new(env).events().mint(minter, to, amount);
 
@@ -125,7 +163,7 @@ impl InterchainTokenInterface for InterchainToken {
 
         extend_instance_ttl(env);
 
-        event::add_minter(env, minter);
+        MinterAddedEvent { minter }.emit(env);
     }
 
     fn remove_minter(env: &Env, minter: Address) {
@@ -137,7 +175,18 @@ impl InterchainTokenInterface for InterchainToken {
 
         extend_instance_ttl(env);
 
-        event::remove_minter(env, minter);
+        MinterRemovedEvent { minter }.emit(env);
+    }
+
+    fn extend_balance_ttl(env: &Env, addr: Address) {
+        let ttl = TtlConfig::load(env);
+        let key = DataKey::Balance(addr);
+
+        if env.storage().persistent().has(&key) {
+            ttl.extend_persistent_ttl(env, &key);
+        }
+
+        ttl.extend_instance_ttl(env);
     }
 }
 
@@ -169,18 +218,23 @@ impl token::Interface for InterchainToken {
     }
 
     fn balance(env: Env, id: Address) -> i128 {
-        extend_instance_ttl(&env);
-        Self::read_balance(&env, id)
+        let ttl = TtlConfig::load(&env);
+        ttl.extend_instance_ttl(&env);
+        Self::read_balance(&env, &ttl, id)
     }
 
     fn transfer(env: Env, from: Address, to: Address, amount: i128) {
         from.require_auth();
 
+        let ttl = TtlConfig::load(&env);
+
+        Self::validate_authorized(&env, &ttl, from.clone());
+        Self::validate_authorized(&env, &ttl, to.clone());
         Self::validate_amount(&env, amount);
-        Self::spend_balance(&env, from.clone(), amount);
+        Self::spend_balance(&env, &ttl, from.clone(), amount);
         Self::receive_balance(&env, to.clone(), amount);
 
-        extend_instance_ttl(&env);
+        ttl.extend_instance_ttl(&env);
 
         TokenUtils::new(&env).events().transfer(from, to, amount);
     }
@@ -188,12 +242,16 @@ impl token::Interface for InterchainToken {
     fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
         spender.require_auth();
 
+        let ttl = TtlConfig::load(&env);
+
+        Self::validate_authorized(&env, &ttl, from.clone());
+        Self::validate_authorized(&env, &ttl, to.clone());
         Self::validate_amount(&env, amount);
         Self::spend_allowance(&env, from.clone(), spender, amount);
-        Self::spend_balance(&env, from.clone(), amount);
+        Self::spend_balance(&env, &ttl, from.clone(), amount);
         Self::receive_balance(&env, to.clone(), amount);
 
-        extend_instance_ttl(&env);
+        ttl.extend_instance_ttl(&env);
 
         TokenUtils::new(&env).events().transfer(from, to, amount)
     }
@@ -201,10 +259,13 @@ impl token::Interface for InterchainToken {
     fn burn(env: Env, from: Address, amount: i128) {
         from.require_auth();
 
+        let ttl = TtlConfig::load(&env);
+
+        Self::validate_authorized(&env, &ttl, from.clone());
         Self::validate_amount(&env, amount);
-        Self::spend_balance(&env, from.clone(), amount);
+        Self::spend_balance(&env, &ttl, from.clone(), amount);
 
-        extend_instance_ttl(&env);
+        ttl.extend_instance_ttl(&env);
 
         TokenUtils::new(&env).events().burn(from, amount);
     }
@@ -212,11 +273,14 @@ impl token::Interface for InterchainToken {
     fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
         spender.require_auth();
 
+        let ttl = TtlConfig::load(&env);
+
+        Self::validate_authorized(&env, &ttl, from.clone());
         Self::validate_amount(&env, amount);
         Self::spend_allowance(&env, from.clone(), spender, amount);
-        Self::spend_balance(&env, from.clone(), amount);
+        Self::spend_balance(&env, &ttl, from.clone(), amount);
 
-        extend_instance_ttl(&env);
+        ttl.extend_instance_ttl(&env);
 
         TokenUtils::new(&env).events().burn(from, amount)
     }
@@ -242,6 +306,25 @@ impl InterchainToken {
         assert_with_error!(env, amount >= 0, ContractError::InvalidAmount);
     }
 
+    fn is_authorized(env: &Env, ttl: &TtlConfig, addr: Address) -> bool {
+        let key = DataKey::Deauthorized(addr);
+        let deauthorized = env.storage().persistent().has(&key);
+
+        if deauthorized {
+            ttl.extend_persistent_ttl(env, &key);
+        }
+
+        !deauthorized
+    }
+
+    fn validate_authorized(env: &Env, ttl: &TtlConfig, addr: Address) {
+        assert_with_error!(
+            env,
+            Self::is_authorized(env, ttl, addr),
+            ContractError::AccountNotAuthorized
+        );
+    }
+
     fn read_allowance(env: &Env, from: Address, spender: Address) -> AllowanceValue {
         let key = DataKey::Allowance(AllowanceDataKey { from, spender });
         env.storage()
@@ -320,14 +403,14 @@ impl InterchainToken {
         }
     }
 
-    fn read_balance(env: &Env, addr: Address) -> i128 {
+    fn read_balance(env: &Env, ttl: &TtlConfig, addr: Address) -> i128 {
         let key = DataKey::Balance(addr);
         env.storage()
             .persistent()
             .get::<_, i128>(&key)
             .inspect(|_| {
                 // Extend the TTL of the balance entry when the balance is successfully retrieved.
-                extend_persistent_ttl(env, &key);
+                ttl.extend_persistent_ttl(env, &key);
             })
             .unwrap_or_default()
     }
@@ -342,24 +425,24 @@ impl InterchainToken {
             });
     }
 
-    fn spend_balance(env: &Env, addr: Address, amount: i128) {
-        let balance = Self::read_balance(env, addr.clone());
+    fn spend_balance(env: &Env, ttl: &TtlConfig, addr: Address, amount: i128) {
+        let balance = Self::read_balance(env, ttl, addr.clone());
 
         assert_with_error!(env, balance >= amount, ContractError::InsufficientBalance);
 
-        Self::write_balance(env, addr, balance - amount);
+        Self::write_balance(env, ttl, addr, balance - amount);
     }
 
     fn write_metadata(env: &Env, metadata: TokenMetadata) {
         TokenUtils::new(env).metadata().set_metadata(&metadata);
     }
 
-    fn write_balance(env: &Env, addr: Address, amount: i128) {
+    fn write_balance(env: &Env, ttl: &TtlConfig, addr: Address, amount: i128) {
         let key = DataKey::Balance(addr);
 
         env.storage().persistent().set(&key, &amount);
 
-        extend_persistent_ttl(env, &key);
+        ttl.extend_persistent_ttl(env, &key);
     }
 }
 
@@ -369,9 +452,22 @@ impl OwnableInterface for InterchainToken {
         interfaces::owner(env)
     }
 
-    fn transfer_ownership(env: &Env, new_owner: Address) {
-        interfaces::transfer_ownership::<Self>(env, new_owner.clone());
+    fn pending_owner(env: &Env) -> Option<Address> {
+        interfaces::pending_owner(env)
+    }
+
+    fn propose_ownership(env: &Env, new_owner: Address) {
+        interfaces::propose_ownership::<Self>(env, new_owner);
+    }
+
+    fn accept_ownership(env: &Env) {
+        let previous_owner = Self::owner(env);
+        interfaces::accept_ownership::<Self>(env);
         // adhere to reference implementation for tokens and emit predefined soroban event
-        TokenEvents::new(env).set_admin(Self::owner(env), new_owner);
+        TokenEvents::new(env).set_admin(previous_owner, Self::owner(env));
+    }
+
+    fn cancel_ownership_proposal(env: &Env) {
+        interfaces::cancel_ownership_proposal::<Self>(env);
     }
 }
@@ -13,4 +13,7 @@ pub enum ContractError {
     InvalidExpirationLedger = 7,
     InsufficientAllowance = 8,
     InsufficientBalance = 9,
+    AccountNotAuthorized = 10,
+    InvalidTtlConfig = 11,
+    UnapprovedWasmHash = 12,
 }
@@ -1,11 +1,14 @@
-use soroban_sdk::{Address, Env, Symbol};
+use axelar_soroban_std::IntoEvent;
+use soroban_sdk::Address;
 
-pub fn add_minter(env: &Env, minter: Address) {
-    let topics = (Symbol::new(env, "minter_added"), minter);
-    env.events().publish(topics, ());
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct MinterAddedEvent {
+    #[topic]
+    pub minter: Address,
 }
 
-pub fn remove_minter(env: &Env, minter: Address) {
-    let topics = (Symbol::new(env, "minter_removed"), minter);
-    env.events().publish(topics, ());
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct MinterRemovedEvent {
+    #[topic]
+    pub minter: Address,
 }
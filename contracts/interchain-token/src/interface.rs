@@ -20,4 +20,7 @@ pub trait InterchainTokenInterface: token::Interface + StellarAssetInterface {
     ) -> Result<(), ContractError>;
     fn add_minter(env: &Env, minter: Address);
     fn remove_minter(env: &Env, minter: Address);
+
+    /// Extends the TTL of the balance of `addr`, if it has one. Anyone can call this.
+    fn extend_balance_ttl(env: &Env, addr: Address);
 }
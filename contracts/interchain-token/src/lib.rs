@@ -1,5 +1,8 @@
 #![no_std]
 
+#[cfg(any(test, feature = "testutils"))]
+extern crate std;
+
 pub mod error;
 
 mod interface;
@@ -8,7 +11,7 @@ cfg_if::cfg_if! {
     if #[cfg(all(feature = "library", not(feature = "testutils")))] {
         pub use interface::{InterchainTokenClient, InterchainTokenInterface};
     } else {
-        mod event;
+        pub mod event;
         mod storage_types;
         mod contract;
 
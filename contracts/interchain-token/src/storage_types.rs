@@ -21,4 +21,5 @@ pub enum DataKey {
     Balance(Address),
     Minter(Address),
     TokenId,
+    Deauthorized(Address),
 }
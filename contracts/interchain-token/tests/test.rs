@@ -1,13 +1,16 @@
 #![cfg(test)]
 extern crate std;
 
+use axelar_soroban_std::ttl::{TtlConfig, TtlPolicy, LEDGERS_PER_DAY};
 use axelar_soroban_std::{
-    assert_invoke_auth_err, assert_invoke_auth_ok, assert_last_emitted_event,
+    assert_contract_err, assert_invoke_auth_err, assert_invoke_auth_ok, assert_last_emitted_event,
 };
 
+use interchain_token::error::ContractError;
 use interchain_token::{InterchainToken, InterchainTokenClient};
 use soroban_sdk::{
-    testutils::{Address as _, BytesN as _},
+    contracttype,
+    testutils::{storage::Persistent as _, Address as _, BytesN as _, Ledger as _},
     Address, BytesN, Env, IntoVal as _, Symbol,
 };
 use soroban_token_sdk::metadata::TokenMetadata;
@@ -103,7 +106,7 @@ fn register_interchain_token_without_minter() {
 }
 
 #[test]
-fn transfer_ownership_from_non_owner() {
+fn propose_ownership_from_non_owner() {
     let env = Env::default();
 
     let new_owner = Address::generate(&env);
@@ -111,21 +114,24 @@ fn transfer_ownership_from_non_owner() {
 
     let (token, _owner, _minter) = setup_token(&env);
 
-    assert_invoke_auth_err!(user, token.try_transfer_ownership(&new_owner));
+    assert_invoke_auth_err!(user, token.try_propose_ownership(&new_owner));
 }
 
 #[test]
-fn transfer_ownership() {
+fn set_admin_proposes_ownership() {
     let env = Env::default();
     let new_owner = Address::generate(&env);
 
     let (token, owner, _minter) = setup_token(&env);
 
+    assert_invoke_auth_ok!(owner, token.try_set_admin(&new_owner));
+
     assert_eq!(token.owner(), owner);
+    assert_eq!(token.pending_owner(), Some(new_owner.clone()));
 
-    assert_invoke_auth_ok!(owner, token.try_transfer_ownership(&new_owner));
+    assert_invoke_auth_ok!(new_owner, token.try_accept_ownership());
 
-    assert_eq!(token.owner(), new_owner);
+    assert_eq!(token.admin(), new_owner);
 }
 
 #[test]
@@ -491,3 +497,268 @@ fn burn_from_succeeds() {
     assert_eq!(token.balance(&user1), (amount - burn_amount));
     assert_eq!(token.balance(&user2), 0);
 }
+
+#[test]
+fn authorized_by_default() {
+    let env = Env::default();
+
+    let user = Address::generate(&env);
+    let (token, _owner, _minter) = setup_token(&env);
+
+    assert!(token.authorized(&user));
+}
+
+#[test]
+fn fail_set_authorized_from_non_owner() {
+    let env = Env::default();
+
+    let user = Address::generate(&env);
+    let (token, _owner, _minter) = setup_token(&env);
+
+    assert_invoke_auth_err!(user, token.try_set_authorized(&user, &false));
+}
+
+#[test]
+fn set_authorized_succeeds() {
+    let env = Env::default();
+
+    let user = Address::generate(&env);
+    let (token, owner, _minter) = setup_token(&env);
+
+    assert_invoke_auth_ok!(owner, token.try_set_authorized(&user, &false));
+    assert!(!token.authorized(&user));
+
+    assert_last_emitted_event(
+        &env,
+        &token.address,
+        (
+            Symbol::new(&env, "set_authorized"),
+            owner.clone(),
+            user.clone(),
+        ),
+        false,
+    );
+
+    assert_invoke_auth_ok!(owner, token.try_set_authorized(&user, &true));
+    assert!(token.authorized(&user));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")] // AccountNotAuthorized
+fn fail_transfer_from_deauthorized_account() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let amount = 1000;
+
+    let (token, _owner, minter) = setup_token(&env);
+
+    token.mint_from(&minter, &user1, &amount);
+    token.set_authorized(&user1, &false);
+
+    token.transfer(&user1, &user2, &amount);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")] // AccountNotAuthorized
+fn fail_transfer_to_deauthorized_account() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let amount = 1000;
+
+    let (token, _owner, minter) = setup_token(&env);
+
+    token.mint_from(&minter, &user1, &amount);
+    token.set_authorized(&user2, &false);
+
+    token.transfer(&user1, &user2, &amount);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")] // AccountNotAuthorized
+fn fail_transfer_from_with_deauthorized_account() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let user3 = Address::generate(&env);
+    let amount = 1000;
+
+    let (token, _owner, minter) = setup_token(&env);
+
+    token.mint_from(&minter, &user1, &amount);
+    token.approve(&user1, &user2, &amount, &200);
+    token.set_authorized(&user1, &false);
+
+    token.transfer_from(&user2, &user1, &user3, &amount);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")] // AccountNotAuthorized
+fn fail_burn_from_deauthorized_account() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user = Address::generate(&env);
+    let amount = 1000;
+
+    let (token, _owner, minter) = setup_token(&env);
+
+    token.mint_from(&minter, &user, &amount);
+    token.set_authorized(&user, &false);
+
+    token.burn(&user, &amount);
+}
+
+#[test]
+fn fail_mint_from_to_deauthorized_account() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user = Address::generate(&env);
+    let amount = 1000;
+
+    let (token, _owner, minter) = setup_token(&env);
+
+    token.set_authorized(&user, &false);
+
+    assert_contract_err!(
+        token.try_mint_from(&minter, &user, &amount),
+        ContractError::AccountNotAuthorized
+    );
+}
+
+#[test]
+fn fail_clawback_from_non_owner() {
+    let env = Env::default();
+
+    let user = Address::generate(&env);
+    let (token, _owner, _minter) = setup_token(&env);
+
+    assert_invoke_auth_err!(user, token.try_clawback(&user, &1000));
+}
+
+#[test]
+fn clawback_succeeds() {
+    let env = Env::default();
+
+    let user = Address::generate(&env);
+    let amount = 1000;
+    let clawback_amount = 600;
+
+    let (token, owner, minter) = setup_token(&env);
+
+    assert_invoke_auth_ok!(minter, token.try_mint_from(&minter, &user, &amount));
+    assert_invoke_auth_ok!(owner, token.try_set_authorized(&user, &false));
+
+    assert_invoke_auth_ok!(owner, token.try_clawback(&user, &clawback_amount));
+    assert_eq!(token.balance(&user), amount - clawback_amount);
+
+    assert_last_emitted_event(
+        &env,
+        &token.address,
+        (Symbol::new(&env, "clawback"), owner, user),
+        clawback_amount,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")] // InsufficientBalance
+fn fail_clawback_with_insufficient_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let user = Address::generate(&env);
+    let (token, _owner, _minter) = setup_token(&env);
+
+    token.clawback(&user, &1000);
+}
+
+#[test]
+fn two_step_ownership_transfer() {
+    let env = Env::default();
+    let new_owner = Address::generate(&env);
+
+    let (token, owner, _minter) = setup_token(&env);
+
+    assert_invoke_auth_ok!(owner, token.try_propose_ownership(&new_owner));
+    assert_eq!(token.owner(), owner);
+    assert_eq!(token.pending_owner(), Some(new_owner.clone()));
+
+    assert_invoke_auth_ok!(new_owner, token.try_accept_ownership());
+
+    assert_last_emitted_event(
+        &env,
+        &token.address,
+        (Symbol::new(&env, "set_admin"), owner),
+        new_owner.clone(),
+    );
+
+    assert_eq!(token.owner(), new_owner);
+    assert_eq!(token.admin(), new_owner);
+    assert_eq!(token.pending_owner(), None);
+}
+
+#[test]
+fn balance_ttl_follows_key_override() {
+    #[contracttype]
+    enum DataKey {
+        Balance(Address),
+    }
+
+    let env = Env::default();
+    let user = Address::generate(&env);
+    let amount = 1000;
+
+    let (token, owner, minter) = setup_token(&env);
+
+    let mut config = TtlConfig::default(&env);
+    let balance_policy = TtlPolicy::new(90 * LEDGERS_PER_DAY, 90 * LEDGERS_PER_DAY);
+    config
+        .key_overrides
+        .set(Symbol::new(&env, "Balance"), balance_policy);
+
+    assert_invoke_auth_err!(minter, token.try_set_ttl_config(&config));
+    assert_invoke_auth_ok!(owner, token.try_set_ttl_config(&config));
+    assert_eq!(token.ttl_config(), config);
+
+    assert_invoke_auth_ok!(minter, token.try_mint_from(&minter, &user, &amount));
+    assert_eq!(token.balance(&user), amount);
+
+    env.as_contract(&token.address, || {
+        assert_eq!(
+            env.storage()
+                .persistent()
+                .get_ttl(&DataKey::Balance(user.clone())),
+            balance_policy.extend_to
+        );
+    });
+}
+
+#[test]
+fn extend_balance_ttl_keeps_balance() {
+    let env = Env::default();
+    let user = Address::generate(&env);
+    let amount = 1000;
+
+    let (token, _, minter) = setup_token(&env);
+    assert_invoke_auth_ok!(minter, token.try_mint_from(&minter, &user, &amount));
+
+    for _ in 0..3 {
+        token.extend_balance_ttl(&user);
+
+        env.ledger()
+            .with_mut(|ledger| ledger.sequence_number += 50 * LEDGERS_PER_DAY);
+    }
+
+    assert_eq!(token.balance(&user), amount);
+
+    // extending the TTL of an address without a balance is a no-op
+    token.extend_balance_ttl(&Address::generate(&env));
+}
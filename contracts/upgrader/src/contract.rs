@@ -1,25 +1,52 @@
 use crate::error::ContractError;
-use axelar_soroban_std::ensure;
-use axelar_soroban_std::interfaces::UpgradableClient;
+use crate::event::{
+    AllowlistEnforcedSetEvent, MinimumDelaySetEvent, UpgradeCancelledEvent, UpgradeExecutedEvent,
+    UpgradeScheduledEvent, WasmHashRegisteredEvent, WasmHashUnregisteredEvent,
+};
+use crate::storage_types;
+use crate::types::{PendingMinimumDelay, PendingUpgrade, UpgradeItem, UpgradeKey};
+use axelar_soroban_std::events::Event;
+use axelar_soroban_std::interfaces::{
+    AccessControlInterface, ApprovedWasm, OwnableClient, OwnableInterface, UpgradableClient,
+    WasmRegistryInterface,
+};
+use axelar_soroban_std::{ensure, interfaces, only_role, Ownable};
 use soroban_sdk::{
-    contract, contractimpl, symbol_short, Address, BytesN, Env, String, Symbol, Val,
+    contract, contractimpl, panic_with_error, symbol_short, Address, BytesN, Env, String, Symbol,
+    Val, Vec,
 };
 
 const MIGRATE: Symbol = symbol_short!("migrate");
+const AUDITOR_ROLE: Symbol = symbol_short!("auditor");
+const MAX_PENDING_UPGRADES_PER_CONTRACT: u32 = 10;
 
 #[contract]
+#[derive(Ownable)]
 pub struct Upgrader;
 
 #[contractimpl]
 impl Upgrader {
-    pub fn __constructor(_env: Env) {}
+    /// The `auditor` role administers itself, so the owner can't register WASM hashes on its own behalf.
+    pub fn __constructor(env: Env, owner: Address, auditor: Address, minimum_delay: u64) {
+        interfaces::set_owner(&env, &owner);
+        interfaces::set_role(&env, &AUDITOR_ROLE, &auditor);
+        interfaces::init_role_admin(&env, &AUDITOR_ROLE, &AUDITOR_ROLE);
+        storage_types::set_minimum_delay(&env, &minimum_delay);
+    }
 
+    /// Upgrades the contract and runs its migration in the same transaction.
+    ///
+    /// `migration_data` holds the arguments of the new code's `migrate` function. Contracts that migrate
+    /// in versioned steps take a single vector argument with the data of every step that has to run,
+    /// ordered from the lowest to the highest schema version.
+    ///
+    /// Contracts owned by the upgrader can only be upgraded through [Upgrader::schedule_upgrade].
     pub fn upgrade(
         env: Env,
         contract_address: Address,
         new_version: String,
         new_wasm_hash: BytesN<32>,
-        migration_data: soroban_sdk::Vec<Val>,
+        migration_data: Vec<Val>,
     ) -> Result<(), ContractError> {
         let contract_client = UpgradableClient::new(&env, &contract_address);
 
@@ -27,15 +54,536 @@ impl Upgrader {
             contract_client.version() != new_version,
             ContractError::SameVersion
         );
+        ensure!(
+            OwnableClient::new(&env, &contract_address).owner() != env.current_contract_address(),
+            ContractError::TimelockRequired
+        );
+
+        Self::upgrade_and_migrate(
+            &env,
+            &contract_address,
+            &new_version,
+            new_wasm_hash,
+            migration_data,
+        )
+    }
+
+    /// Upgrades and migrates all contracts in order, e.g. all contracts of a protocol release.
+    /// If any of the upgrades fails, the whole batch is reverted.
+    ///
+    /// Before upgrading anything, every target is checked to implement the upgradable interface,
+    /// to be owned by `caller` and to not already be at the new version.
+    /// The owner still needs to authorize each upgrade and migration.
+    pub fn upgrade_batch(
+        env: Env,
+        caller: Address,
+        upgrades: Vec<UpgradeItem>,
+    ) -> Result<(), ContractError> {
+        ensure!(!upgrades.is_empty(), ContractError::EmptyBatch);
+        ensure!(
+            caller != env.current_contract_address(),
+            ContractError::TimelockRequired
+        );
+
+        for upgrade in upgrades.iter() {
+            Self::ensure_can_upgrade(&env, &caller, &upgrade)?;
+        }
+
+        for upgrade in upgrades {
+            Self::upgrade_and_migrate(
+                &env,
+                &upgrade.contract_address,
+                &upgrade.new_version,
+                upgrade.new_wasm_hash,
+                upgrade.migration_data,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the minimum delay in seconds between scheduling and executing an upgrade.
+    pub fn minimum_delay(env: Env) -> u64 {
+        Self::current_minimum_delay(&env)
+    }
+
+    /// Returns the decrease of the minimum delay that has been requested but is not in effect yet.
+    pub fn pending_minimum_delay(env: Env) -> Option<PendingMinimumDelay> {
+        storage_types::try_pending_minimum_delay(&env)
+            .filter(|pending| env.ledger().timestamp() < pending.eta)
+    }
+
+    /// Sets the minimum delay in seconds for upgrades scheduled from now on.
+    ///
+    /// An increase takes effect immediately. A decrease only takes effect once the current minimum delay has passed,
+    /// so it cannot be used to execute an upgrade sooner than the delay that was announced when it was scheduled.
+    /// Setting a new delay replaces any pending decrease.
+    ///
+    /// Only callable by the owner of the upgrader.
+    pub fn set_minimum_delay(env: Env, minimum_delay: u64) {
+        Self::owner(&env).require_auth();
+
+        let current_minimum_delay = Self::current_minimum_delay(&env);
+        let now = env.ledger().timestamp();
+
+        let eta = if minimum_delay >= current_minimum_delay {
+            storage_types::set_minimum_delay(&env, &minimum_delay);
+            storage_types::remove_pending_minimum_delay(&env);
+            now
+        } else {
+            // pin the delay in effect, so a pending decrease that has already passed is not replaced by this one
+            storage_types::set_minimum_delay(&env, &current_minimum_delay);
+
+            let eta = now.saturating_add(current_minimum_delay);
+            storage_types::set_pending_minimum_delay(
+                &env,
+                &PendingMinimumDelay { minimum_delay, eta },
+            );
+            eta
+        };
+
+        MinimumDelaySetEvent { minimum_delay, eta }.emit(&env);
+    }
+
+    /// Schedules an upgrade that can be executed after the minimum delay has passed, and returns the timestamp from which on it can be executed.
+    ///
+    /// Must be authorized by the owner of the contract. Contracts owned by the upgrader itself can only be upgraded this way,
+    /// in which case the owner of the upgrader needs to authorize the upgrade.
+    pub fn schedule_upgrade(env: Env, upgrade: UpgradeItem) -> Result<u64, ContractError> {
+        let current_version = Self::upgrade_authority(&env, &upgrade.contract_address)?;
+
+        ensure!(
+            current_version != upgrade.new_version,
+            ContractError::SameVersion
+        );
+        Self::ensure_wasm_hash_approved(
+            &env,
+            &upgrade.contract_address,
+            &upgrade.new_version,
+            &upgrade.new_wasm_hash,
+        )?;
+
+        let upgrade_key = upgrade.key();
+        ensure!(
+            storage_types::try_pending_upgrade(&env, &upgrade_key).is_none(),
+            ContractError::UpgradeAlreadyScheduled
+        );
+
+        let mut pending_upgrade_keys = Self::pending_upgrade_keys(&env, &upgrade.contract_address);
+        ensure!(
+            pending_upgrade_keys.len() < MAX_PENDING_UPGRADES_PER_CONTRACT,
+            ContractError::TooManyPendingUpgrades
+        );
+
+        let eta = env
+            .ledger()
+            .timestamp()
+            .saturating_add(Self::current_minimum_delay(&env));
+
+        pending_upgrade_keys.push_back(upgrade_key.clone());
+        storage_types::set_pending_upgrade_keys(
+            &env,
+            &upgrade.contract_address,
+            &pending_upgrade_keys,
+        );
+        storage_types::set_pending_upgrade(&env, &upgrade_key, &PendingUpgrade { upgrade, eta });
+
+        UpgradeScheduledEvent {
+            contract_address: upgrade_key.contract_address,
+            new_version: upgrade_key.new_version,
+            new_wasm_hash: upgrade_key.new_wasm_hash,
+            eta,
+        }
+        .emit(&env);
+
+        Ok(eta)
+    }
+
+    /// Cancels a scheduled upgrade. Must be authorized by the same account as [Upgrader::schedule_upgrade].
+    pub fn cancel_upgrade(
+        env: Env,
+        contract_address: Address,
+        new_wasm_hash: BytesN<32>,
+        new_version: String,
+    ) -> Result<(), ContractError> {
+        let upgrade_key = UpgradeKey {
+            contract_address,
+            new_wasm_hash,
+            new_version,
+        };
+
+        Self::upgrade_authority(&env, &upgrade_key.contract_address)?;
+        Self::remove_pending_upgrade(&env, &upgrade_key)?;
+
+        UpgradeCancelledEvent {
+            contract_address: upgrade_key.contract_address,
+            new_version: upgrade_key.new_version,
+            new_wasm_hash: upgrade_key.new_wasm_hash,
+        }
+        .emit(&env);
+
+        Ok(())
+    }
+
+    /// Executes a scheduled upgrade once its delay has passed. Anyone can trigger the execution,
+    /// but the owner of the contract still needs to authorize the upgrade and migration, unless it is the upgrader itself.
+    pub fn execute_upgrade(
+        env: Env,
+        contract_address: Address,
+        new_wasm_hash: BytesN<32>,
+        new_version: String,
+    ) -> Result<(), ContractError> {
+        let upgrade_key = UpgradeKey {
+            contract_address,
+            new_wasm_hash,
+            new_version,
+        };
+
+        let PendingUpgrade { upgrade, eta } = Self::remove_pending_upgrade(&env, &upgrade_key)?;
+        ensure!(
+            env.ledger().timestamp() >= eta,
+            ContractError::UpgradeNotReady
+        );
+
+        Self::upgrade_and_migrate(
+            &env,
+            &upgrade.contract_address,
+            &upgrade.new_version,
+            upgrade.new_wasm_hash,
+            upgrade.migration_data,
+        )?;
+
+        UpgradeExecutedEvent {
+            contract_address: upgrade_key.contract_address,
+            new_version: upgrade_key.new_version,
+            new_wasm_hash: upgrade_key.new_wasm_hash,
+        }
+        .emit(&env);
+
+        Ok(())
+    }
+
+    /// Returns whether upgrades through the upgrader are restricted to WASM hashes registered by an auditor.
+    pub fn allowlist_enforced(env: Env) -> bool {
+        Self::is_allowlist_enforced(&env)
+    }
+
+    /// Returns the timestamp from which on the allowlist is no longer enforced, if it has been disabled but is still in effect.
+    pub fn allowlist_disable_eta(env: Env) -> Option<u64> {
+        storage_types::try_allowlist_disable_eta(&env).filter(|eta| env.ledger().timestamp() < *eta)
+    }
+
+    /// Restricts upgrades through the upgrader to WASM hashes that an auditor registered for the new version and contract kind.
+    /// Applies to scheduled upgrades once they are executed.
+    ///
+    /// Enforcing the allowlist takes effect immediately. Disabling it only takes effect once the minimum delay has passed,
+    /// so unaudited code can't be rolled out sooner than through a scheduled upgrade.
+    ///
+    /// Only callable by the owner of the upgrader.
+    pub fn set_allowlist_enforced(env: Env, enforced: bool) {
+        Self::owner(&env).require_auth();
+
+        let now = env.ledger().timestamp();
+
+        let eta = if enforced {
+            storage_types::set_allowlist_enforced(&env);
+            storage_types::remove_allowlist_disable_eta(&env);
+            now
+        } else if Self::is_allowlist_enforced(&env) {
+            let eta = now.saturating_add(Self::current_minimum_delay(&env));
+            storage_types::set_allowlist_disable_eta(&env, &eta);
+            eta
+        } else {
+            storage_types::remove_allowlist_enforced(&env);
+            storage_types::remove_allowlist_disable_eta(&env);
+            now
+        };
+
+        AllowlistEnforcedSetEvent { enforced, eta }.emit(&env);
+    }
+
+    /// Approves a WASM hash as the audited code of `version` of the given contract kind, i.e. the contract's crate name.
+    ///
+    /// Only callable by accounts with the `auditor` role.
+    pub fn register_wasm_hash(
+        env: Env,
+        auditor: Address,
+        contract_kind: String,
+        version: String,
+        wasm_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        only_role!(&env, auditor, AUDITOR_ROLE);
+        ensure!(
+            auditor != Self::owner(&env),
+            ContractError::OwnerCannotBeAuditor
+        );
+
+        ensure!(
+            storage_types::try_approved_wasm(&env, &wasm_hash).is_none(),
+            ContractError::WasmHashAlreadyRegistered
+        );
+
+        storage_types::set_approved_wasm(
+            &env,
+            &wasm_hash,
+            &ApprovedWasm {
+                contract_kind: contract_kind.clone(),
+                version: version.clone(),
+            },
+        );
+
+        WasmHashRegisteredEvent {
+            contract_kind,
+            version,
+            wasm_hash,
+        }
+        .emit(&env);
+
+        Ok(())
+    }
+
+    /// Revokes the approval of a WASM hash, which also blocks scheduled upgrades to it.
+    ///
+    /// Only callable by accounts with the `auditor` role.
+    pub fn unregister_wasm_hash(
+        env: Env,
+        auditor: Address,
+        wasm_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        only_role!(&env, auditor, AUDITOR_ROLE);
+
+        ensure!(
+            storage_types::try_approved_wasm(&env, &wasm_hash).is_some(),
+            ContractError::WasmHashNotRegistered
+        );
+
+        storage_types::remove_approved_wasm(&env, &wasm_hash);
+
+        WasmHashUnregisteredEvent { wasm_hash }.emit(&env);
+
+        Ok(())
+    }
+
+    /// Returns the scheduled upgrades of a contract in the order they were scheduled.
+    pub fn pending_upgrades(env: Env, contract_address: Address) -> Vec<PendingUpgrade> {
+        let mut pending_upgrades = Vec::new(&env);
+
+        for upgrade_key in Self::pending_upgrade_keys(&env, &contract_address) {
+            pending_upgrades.push_back(storage_types::pending_upgrade(&env, &upgrade_key));
+        }
+
+        pending_upgrades
+    }
+
+    /// Extends the TTL of a scheduled upgrade, so it is not archived before it can be executed.
+    pub fn extend_pending_upgrade_ttl(
+        env: Env,
+        contract_address: Address,
+        new_wasm_hash: BytesN<32>,
+        new_version: String,
+    ) -> Result<(), ContractError> {
+        let upgrade_key = UpgradeKey {
+            contract_address,
+            new_wasm_hash,
+            new_version,
+        };
+
+        // reading the scheduled upgrade and the contract's list of scheduled upgrades extends their TTL
+        storage_types::try_pending_upgrade(&env, &upgrade_key)
+            .ok_or(ContractError::UpgradeNotScheduled)?;
+        Self::pending_upgrade_keys(&env, &upgrade_key.contract_address);
+
+        Ok(())
+    }
+}
+
+#[contractimpl]
+impl WasmRegistryInterface for Upgrader {
+    fn approved_wasm(env: &Env, wasm_hash: BytesN<32>) -> Option<ApprovedWasm> {
+        storage_types::try_approved_wasm(env, &wasm_hash)
+    }
+}
+
+/// The `auditor` role can't be granted to the owner, and its admin role can't be changed.
+#[contractimpl]
+impl AccessControlInterface for Upgrader {
+    fn has_role(env: &Env, role: Symbol, account: Address) -> bool {
+        interfaces::has_role(env, &role, &account)
+    }
+
+    fn role_admin(env: &Env, role: Symbol) -> Option<Symbol> {
+        interfaces::role_admin(env, &role)
+    }
+
+    fn grant_role(env: &Env, caller: Address, role: Symbol, account: Address) {
+        if role == AUDITOR_ROLE && account == Self::owner(env) {
+            panic_with_error!(env, ContractError::OwnerCannotBeAuditor);
+        }
+
+        interfaces::grant_role::<Self>(env, caller, role, account)
+            .unwrap_or_else(|_| panic_with_error!(env, ContractError::MissingRole));
+    }
+
+    fn revoke_role(env: &Env, caller: Address, role: Symbol, account: Address) {
+        interfaces::revoke_role::<Self>(env, caller, role, account)
+            .unwrap_or_else(|_| panic_with_error!(env, ContractError::MissingRole));
+    }
+
+    fn renounce_role(env: &Env, account: Address, role: Symbol) {
+        interfaces::renounce_role(env, account, role);
+    }
+
+    fn set_role_admin(env: &Env, role: Symbol, admin_role: Option<Symbol>) {
+        if role == AUDITOR_ROLE {
+            panic_with_error!(env, ContractError::AuditorRoleAdminLocked);
+        }
+
+        interfaces::set_role_admin::<Self>(env, role, admin_role);
+    }
+}
+
+impl Upgrader {
+    fn ensure_wasm_hash_approved(
+        env: &Env,
+        contract_address: &Address,
+        new_version: &String,
+        new_wasm_hash: &BytesN<32>,
+    ) -> Result<(), ContractError> {
+        if !Self::is_allowlist_enforced(env) {
+            return Ok(());
+        }
+
+        let contract_kind = UpgradableClient::new(env, contract_address)
+            .try_contract_kind()
+            .ok()
+            .and_then(Result::ok)
+            .ok_or(ContractError::NotUpgradable)?;
+
+        ensure!(
+            storage_types::try_approved_wasm(env, new_wasm_hash).is_some_and(|approved| {
+                approved.version == *new_version && approved.contract_kind == contract_kind
+            }),
+            ContractError::UnapprovedWasmHash
+        );
+
+        Ok(())
+    }
+
+    /// Returns whether the allowlist is enforced, which is no longer the case once a disable request has passed.
+    fn is_allowlist_enforced(env: &Env) -> bool {
+        storage_types::is_allowlist_enforced(env)
+            && storage_types::try_allowlist_disable_eta(env)
+                .map_or(true, |eta| env.ledger().timestamp() < eta)
+    }
+
+    fn ensure_can_upgrade(
+        env: &Env,
+        caller: &Address,
+        upgrade: &UpgradeItem,
+    ) -> Result<(), ContractError> {
+        let (version, owner) = Self::version_and_owner(env, &upgrade.contract_address)?;
+
+        ensure!(
+            owner != env.current_contract_address(),
+            ContractError::TimelockRequired
+        );
+        ensure!(owner == *caller, ContractError::NotOwner);
+        ensure!(version != upgrade.new_version, ContractError::SameVersion);
+
+        Self::ensure_wasm_hash_approved(
+            env,
+            &upgrade.contract_address,
+            &upgrade.new_version,
+            &upgrade.new_wasm_hash,
+        )
+    }
+
+    fn version_and_owner(
+        env: &Env,
+        contract_address: &Address,
+    ) -> Result<(String, Address), ContractError> {
+        let version = UpgradableClient::new(env, contract_address)
+            .try_version()
+            .ok()
+            .and_then(Result::ok)
+            .ok_or(ContractError::NotUpgradable)?;
+        let owner = OwnableClient::new(env, contract_address)
+            .try_owner()
+            .ok()
+            .and_then(Result::ok)
+            .ok_or(ContractError::NotUpgradable)?;
+
+        Ok((version, owner))
+    }
+
+    /// Requires the authorization of the account that controls upgrades of the contract, and returns the contract's current version.
+    fn upgrade_authority(env: &Env, contract_address: &Address) -> Result<String, ContractError> {
+        let (version, owner) = Self::version_and_owner(env, contract_address)?;
+
+        if owner == env.current_contract_address() {
+            Self::owner(env).require_auth();
+        } else {
+            owner.require_auth();
+        }
+
+        Ok(version)
+    }
+
+    /// Returns the minimum delay in effect, which is the pending decrease once it has passed.
+    fn current_minimum_delay(env: &Env) -> u64 {
+        match storage_types::try_pending_minimum_delay(env) {
+            Some(pending) if env.ledger().timestamp() >= pending.eta => pending.minimum_delay,
+            _ => storage_types::minimum_delay(env),
+        }
+    }
+
+    fn pending_upgrade_keys(env: &Env, contract_address: &Address) -> Vec<UpgradeKey> {
+        storage_types::try_pending_upgrade_keys(env, contract_address)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn remove_pending_upgrade(
+        env: &Env,
+        upgrade_key: &UpgradeKey,
+    ) -> Result<PendingUpgrade, ContractError> {
+        let pending_upgrade = storage_types::try_pending_upgrade(env, upgrade_key)
+            .ok_or(ContractError::UpgradeNotScheduled)?;
+
+        let contract_address = &upgrade_key.contract_address;
+        let mut pending_upgrade_keys = Self::pending_upgrade_keys(env, contract_address);
+        if let Some(index) = pending_upgrade_keys.first_index_of(upgrade_key) {
+            pending_upgrade_keys.remove(index);
+        }
+
+        if pending_upgrade_keys.is_empty() {
+            storage_types::remove_pending_upgrade_keys(env, contract_address);
+        } else {
+            storage_types::set_pending_upgrade_keys(env, contract_address, &pending_upgrade_keys);
+        }
+        storage_types::remove_pending_upgrade(env, upgrade_key);
+
+        Ok(pending_upgrade)
+    }
+
+    fn upgrade_and_migrate(
+        env: &Env,
+        contract_address: &Address,
+        new_version: &String,
+        new_wasm_hash: BytesN<32>,
+        migration_data: Vec<Val>,
+    ) -> Result<(), ContractError> {
+        Self::ensure_wasm_hash_approved(env, contract_address, new_version, &new_wasm_hash)?;
+
+        let contract_client = UpgradableClient::new(env, contract_address);
 
         contract_client.upgrade(&new_wasm_hash);
         // The types of the arguments to the migrate function are unknown to this contract, so we need to call it with invoke_contract.
         // The migrate function's return value can be safely cast to () no matter what it really is,
         // because it will panic on failure anyway
-        env.invoke_contract::<()>(&contract_address, &MIGRATE, migration_data);
+        env.invoke_contract::<()>(contract_address, &MIGRATE, migration_data);
 
         ensure!(
-            contract_client.version() == new_version,
+            contract_client.version() == *new_version,
             ContractError::UnexpectedNewVersion
         );
         Ok(())
@@ -1,8 +1,25 @@
 use soroban_sdk::contracterror;
 
 #[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum ContractError {
     SameVersion = 1,
     UnexpectedNewVersion = 2,
+    EmptyBatch = 3,
+    NotUpgradable = 4,
+    NotOwner = 5,
+    /// Timelock
+    UpgradeAlreadyScheduled = 6,
+    UpgradeNotScheduled = 7,
+    UpgradeNotReady = 8,
+    TimelockRequired = 9,
+    /// Wasm registry
+    MissingRole = 10,
+    UnapprovedWasmHash = 11,
+    WasmHashAlreadyRegistered = 12,
+    WasmHashNotRegistered = 13,
+    TooManyPendingUpgrades = 14,
+    OwnerCannotBeAuditor = 15,
+    AuditorRoleAdminLocked = 16,
 }
@@ -0,0 +1,66 @@
+use axelar_soroban_std::IntoEvent;
+use soroban_sdk::{Address, BytesN, String};
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct UpgradeScheduledEvent {
+    #[topic]
+    pub contract_address: Address,
+    #[topic]
+    pub new_version: String,
+    #[data]
+    pub new_wasm_hash: BytesN<32>,
+    #[data]
+    pub eta: u64,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct UpgradeCancelledEvent {
+    #[topic]
+    pub contract_address: Address,
+    #[topic]
+    pub new_version: String,
+    #[data]
+    pub new_wasm_hash: BytesN<32>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct UpgradeExecutedEvent {
+    #[topic]
+    pub contract_address: Address,
+    #[topic]
+    pub new_version: String,
+    #[data]
+    pub new_wasm_hash: BytesN<32>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct MinimumDelaySetEvent {
+    #[data]
+    pub minimum_delay: u64,
+    #[data]
+    pub eta: u64,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct WasmHashRegisteredEvent {
+    #[topic]
+    pub contract_kind: String,
+    #[topic]
+    pub version: String,
+    #[data]
+    pub wasm_hash: BytesN<32>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct WasmHashUnregisteredEvent {
+    #[data]
+    pub wasm_hash: BytesN<32>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct AllowlistEnforcedSetEvent {
+    #[data]
+    pub enforced: bool,
+    #[data]
+    pub eta: u64,
+}
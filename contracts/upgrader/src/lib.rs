@@ -2,7 +2,13 @@
 #[cfg(test)]
 extern crate alloc;
 
+#[cfg(any(test, feature = "testutils"))]
+extern crate std;
+
 mod contract;
 pub mod error;
+pub mod event;
+mod storage_types;
+pub mod types;
 
 pub use contract::{Upgrader, UpgraderClient};
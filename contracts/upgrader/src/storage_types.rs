@@ -0,0 +1,37 @@
+use axelar_soroban_std::contractstorage;
+use axelar_soroban_std::interfaces::ApprovedWasm;
+use soroban_sdk::{Address, BytesN, Vec};
+
+use crate::types::{PendingMinimumDelay, PendingUpgrade, UpgradeKey};
+
+#[contractstorage]
+#[derive(Clone, Debug)]
+pub enum DataKey {
+    #[instance]
+    #[value(u64)]
+    MinimumDelay,
+
+    #[instance]
+    #[value(PendingMinimumDelay)]
+    PendingMinimumDelay,
+
+    #[persistent]
+    #[value(Vec<UpgradeKey>)]
+    PendingUpgradeKeys { contract_address: Address },
+
+    #[persistent]
+    #[value(PendingUpgrade)]
+    PendingUpgrade { upgrade_key: UpgradeKey },
+
+    #[instance]
+    #[status]
+    AllowlistEnforced,
+
+    #[instance]
+    #[value(u64)]
+    AllowlistDisableEta,
+
+    #[persistent]
+    #[value(ApprovedWasm)]
+    ApprovedWasm { wasm_hash: BytesN<32> },
+}
@@ -0,0 +1,50 @@
+use soroban_sdk::{contracttype, Address, BytesN, String, Val, Vec};
+
+/// A single contract upgrade of a batch, see `Upgrader::upgrade_batch`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeItem {
+    /// The contract to upgrade.
+    pub contract_address: Address,
+    /// The version the contract reports after the upgrade.
+    pub new_version: String,
+    /// The hash of the uploaded wasm the contract is upgraded to.
+    pub new_wasm_hash: BytesN<32>,
+    /// The arguments of the new code's `migrate` function.
+    pub migration_data: Vec<Val>,
+}
+
+impl UpgradeItem {
+    pub fn key(&self) -> UpgradeKey {
+        UpgradeKey {
+            contract_address: self.contract_address.clone(),
+            new_wasm_hash: self.new_wasm_hash.clone(),
+            new_version: self.new_version.clone(),
+        }
+    }
+}
+
+/// Identifies a scheduled upgrade, see `Upgrader::schedule_upgrade`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeKey {
+    pub contract_address: Address,
+    pub new_wasm_hash: BytesN<32>,
+    pub new_version: String,
+}
+
+/// A decrease of the minimum delay that takes effect once the ledger timestamp reaches `eta`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingMinimumDelay {
+    pub minimum_delay: u64,
+    pub eta: u64,
+}
+
+/// A scheduled upgrade that can be executed once the ledger timestamp reaches `eta`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingUpgrade {
+    pub upgrade: UpgradeItem,
+    pub eta: u64,
+}
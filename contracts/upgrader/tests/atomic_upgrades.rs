@@ -4,9 +4,7 @@ use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
 use soroban_sdk::Address;
 use soroban_sdk::{BytesN, Env, String};
 use upgrader::{Upgrader, UpgraderClient};
-use utils::{DataKey, DummyContract, DummyContractClient};
-
-const WASM_AFTER_UPGRADE: &[u8] = include_bytes!("testdata/dummy.wasm");
+use utils::{DataKey, DummyContract, DummyContractClient, WASM_AFTER_UPGRADE};
 
 #[test]
 fn upgrade_and_migrate_are_atomic() {
@@ -166,7 +164,10 @@ struct TestFixture {
 fn setup_contracts_and_call_args() -> TestFixture {
     let env = Env::default();
 
-    let upgrader_address = env.register(Upgrader, ());
+    let upgrader_address = env.register(
+        Upgrader,
+        (Address::generate(&env), Address::generate(&env), 0u64),
+    );
 
     let contract_owner = Address::generate(&env);
     let contract_address = env.register(DummyContract, (&contract_owner,));
@@ -0,0 +1,202 @@
+mod utils;
+
+use axelar_soroban_std::assert_contract_err;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, String, Vec};
+use upgrader::error::ContractError;
+use utils::{register_dummy, setup, upgrade_item, version, DataKey, TestFixture};
+
+#[test]
+fn upgrade_batch_upgrades_and_migrates_all_contracts() {
+    let TestFixture {
+        env,
+        upgrader,
+        contract_owner: owner,
+        hash_after_upgrade,
+        ..
+    } = setup(0);
+
+    let first = register_dummy(&env, &owner);
+    let second = register_dummy(&env, &owner);
+
+    upgrader.upgrade_batch(
+        &owner,
+        &vec![
+            &env,
+            upgrade_item(&env, &first, "0.2.0", &hash_after_upgrade, "first"),
+            upgrade_item(&env, &second, "0.2.0", &hash_after_upgrade, "second"),
+        ],
+    );
+
+    for (contract_address, expected_data) in [(first, "first"), (second, "second")] {
+        assert_eq!(
+            version(&env, &contract_address),
+            String::from_str(&env, "0.2.0")
+        );
+
+        env.as_contract(&contract_address, || {
+            let data: String = env.storage().instance().get(&DataKey::Data).unwrap();
+            assert_eq!(data, String::from_str(&env, expected_data));
+        });
+    }
+}
+
+#[test]
+fn upgrade_batch_fails_if_empty() {
+    let TestFixture {
+        env,
+        upgrader,
+        contract_owner: owner,
+        ..
+    } = setup(0);
+
+    assert_contract_err!(
+        upgrader.try_upgrade_batch(&owner, &Vec::new(&env)),
+        ContractError::EmptyBatch
+    );
+}
+
+#[test]
+fn upgrade_batch_fails_if_a_contract_is_not_owned_by_caller() {
+    let TestFixture {
+        env,
+        upgrader,
+        contract_owner: owner,
+        hash_after_upgrade,
+        ..
+    } = setup(0);
+
+    let first = register_dummy(&env, &owner);
+    let second = register_dummy(&env, &Address::generate(&env));
+
+    assert_contract_err!(
+        upgrader.try_upgrade_batch(
+            &owner,
+            &vec![
+                &env,
+                upgrade_item(&env, &first, "0.2.0", &hash_after_upgrade, "first"),
+                upgrade_item(&env, &second, "0.2.0", &hash_after_upgrade, "second"),
+            ],
+        ),
+        ContractError::NotOwner
+    );
+
+    assert_eq!(version(&env, &first), String::from_str(&env, "0.1.0"));
+}
+
+#[test]
+fn upgrade_batch_fails_if_a_contract_is_not_upgradable() {
+    let TestFixture {
+        env,
+        upgrader,
+        contract_owner: owner,
+        hash_after_upgrade,
+        ..
+    } = setup(0);
+
+    let first = register_dummy(&env, &owner);
+
+    assert_contract_err!(
+        upgrader.try_upgrade_batch(
+            &owner,
+            &vec![
+                &env,
+                upgrade_item(&env, &first, "0.2.0", &hash_after_upgrade, "first"),
+                upgrade_item(
+                    &env,
+                    &upgrader.address,
+                    "0.2.0",
+                    &hash_after_upgrade,
+                    "upgrader"
+                ),
+            ],
+        ),
+        ContractError::NotUpgradable
+    );
+
+    assert_eq!(version(&env, &first), String::from_str(&env, "0.1.0"));
+}
+
+#[test]
+fn upgrade_batch_fails_if_a_contract_is_already_at_the_new_version() {
+    let TestFixture {
+        env,
+        upgrader,
+        contract_owner: owner,
+        hash_after_upgrade,
+        ..
+    } = setup(0);
+
+    let first = register_dummy(&env, &owner);
+
+    assert_contract_err!(
+        upgrader.try_upgrade_batch(
+            &owner,
+            &vec![
+                &env,
+                upgrade_item(&env, &first, "0.1.0", &hash_after_upgrade, "first"),
+            ],
+        ),
+        ContractError::SameVersion
+    );
+}
+
+#[test]
+fn upgrade_batch_reverts_all_upgrades_if_one_fails() {
+    let TestFixture {
+        env,
+        upgrader,
+        contract_owner: owner,
+        hash_after_upgrade,
+        ..
+    } = setup(0);
+
+    let first = register_dummy(&env, &owner);
+    let second = register_dummy(&env, &owner);
+
+    assert_contract_err!(
+        upgrader.try_upgrade_batch(
+            &owner,
+            &vec![
+                &env,
+                upgrade_item(&env, &first, "0.2.0", &hash_after_upgrade, "first"),
+                upgrade_item(&env, &second, "0.3.0", &hash_after_upgrade, "second"),
+            ],
+        ),
+        ContractError::UnexpectedNewVersion
+    );
+
+    for contract_address in [first, second] {
+        assert_eq!(
+            version(&env, &contract_address),
+            String::from_str(&env, "0.1.0")
+        );
+
+        env.as_contract(&contract_address, || {
+            assert!(!env.storage().instance().has(&DataKey::Data));
+        });
+    }
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn upgrade_batch_fails_if_owner_does_not_authorize() {
+    let TestFixture {
+        env,
+        upgrader,
+        contract_owner: owner,
+        hash_after_upgrade,
+        ..
+    } = setup(0);
+
+    let first = register_dummy(&env, &owner);
+    env.set_auths(&[]);
+
+    upgrader.upgrade_batch(
+        &owner,
+        &vec![
+            &env,
+            upgrade_item(&env, &first, "0.2.0", &hash_after_upgrade, "first"),
+        ],
+    );
+}
@@ -0,0 +1,427 @@
+mod utils;
+
+use axelar_soroban_std::ttl::{PERSISTENT_TTL_EXTEND_TO, PERSISTENT_TTL_THRESHOLD};
+use axelar_soroban_std::{assert_contract_err, events};
+use soroban_sdk::testutils::{storage::Persistent as _, Ledger};
+use soroban_sdk::{contracttype, vec, Env, String};
+use upgrader::error::ContractError;
+use upgrader::event::{
+    MinimumDelaySetEvent, UpgradeCancelledEvent, UpgradeExecutedEvent, UpgradeScheduledEvent,
+};
+use upgrader::types::{PendingMinimumDelay, PendingUpgrade, UpgradeItem, UpgradeKey};
+use upgrader::UpgraderClient;
+use utils::{setup, upgrade_item, version, DataKey, DummyContract, TestFixture};
+
+const MINIMUM_DELAY: u64 = 86400;
+
+fn setup_with_upgrade() -> (TestFixture, UpgradeItem) {
+    let fixture = setup(MINIMUM_DELAY);
+    let upgrade = upgrade_item(
+        &fixture.env,
+        &fixture.contract_address,
+        "0.2.0",
+        &fixture.hash_after_upgrade,
+        "migrated",
+    );
+
+    (fixture, upgrade)
+}
+
+fn advance_time(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|ledger| ledger.timestamp += seconds);
+}
+
+fn execute(upgrader: &UpgraderClient, upgrade: &UpgradeItem) {
+    upgrader.execute_upgrade(
+        &upgrade.contract_address,
+        &upgrade.new_wasm_hash,
+        &upgrade.new_version,
+    );
+}
+
+fn try_execute(
+    upgrader: &UpgraderClient,
+    upgrade: &UpgradeItem,
+) -> Result<Result<(), soroban_sdk::ConversionError>, Result<ContractError, soroban_sdk::InvokeError>>
+{
+    upgrader.try_execute_upgrade(
+        &upgrade.contract_address,
+        &upgrade.new_wasm_hash,
+        &upgrade.new_version,
+    )
+}
+
+#[test]
+fn set_minimum_delay_succeeds_if_caller_is_owner() {
+    let TestFixture {
+        env,
+        upgrader,
+        upgrader_owner,
+        ..
+    } = setup(MINIMUM_DELAY);
+
+    upgrader.set_minimum_delay(&(2 * MINIMUM_DELAY));
+
+    assert_eq!(env.auths()[0].0, upgrader_owner);
+    goldie::assert!(events::fmt_last_emitted_event::<MinimumDelaySetEvent>(&env));
+    assert_eq!(upgrader.minimum_delay(), 2 * MINIMUM_DELAY);
+    assert_eq!(upgrader.pending_minimum_delay(), None);
+}
+
+#[test]
+fn set_minimum_delay_decrease_takes_effect_after_current_delay() {
+    let (TestFixture { env, upgrader, .. }, upgrade) = setup_with_upgrade();
+
+    upgrader.set_minimum_delay(&3600);
+
+    let eta = env.ledger().timestamp() + MINIMUM_DELAY;
+    assert_eq!(upgrader.minimum_delay(), MINIMUM_DELAY);
+    assert_eq!(
+        upgrader.pending_minimum_delay(),
+        Some(PendingMinimumDelay {
+            minimum_delay: 3600,
+            eta
+        })
+    );
+
+    // upgrades scheduled before the decrease takes effect still use the current delay
+    assert_eq!(upgrader.schedule_upgrade(&upgrade), eta);
+
+    advance_time(&env, MINIMUM_DELAY);
+    assert_eq!(upgrader.minimum_delay(), 3600);
+    assert_eq!(upgrader.pending_minimum_delay(), None);
+}
+
+#[test]
+fn set_minimum_delay_increase_replaces_pending_decrease() {
+    let TestFixture { env, upgrader, .. } = setup(MINIMUM_DELAY);
+
+    upgrader.set_minimum_delay(&3600);
+    upgrader.set_minimum_delay(&MINIMUM_DELAY);
+
+    assert_eq!(upgrader.pending_minimum_delay(), None);
+
+    advance_time(&env, MINIMUM_DELAY);
+    assert_eq!(upgrader.minimum_delay(), MINIMUM_DELAY);
+}
+
+#[test]
+fn schedule_upgrade_stores_pending_upgrade() {
+    let (
+        TestFixture {
+            env,
+            upgrader,
+            contract_owner,
+            ..
+        },
+        upgrade,
+    ) = setup_with_upgrade();
+
+    let eta = upgrader.schedule_upgrade(&upgrade);
+
+    assert_eq!(eta, env.ledger().timestamp() + MINIMUM_DELAY);
+    assert_eq!(env.auths()[0].0, contract_owner);
+    assert_eq!(
+        upgrader.pending_upgrades(&upgrade.contract_address),
+        vec![&env, PendingUpgrade { upgrade, eta }]
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<UpgradeScheduledEvent>(
+        &env
+    ));
+}
+
+#[test]
+fn schedule_upgrade_fails_if_already_scheduled() {
+    let (TestFixture { upgrader, .. }, upgrade) = setup_with_upgrade();
+
+    upgrader.schedule_upgrade(&upgrade);
+
+    assert_contract_err!(
+        upgrader.try_schedule_upgrade(&upgrade),
+        ContractError::UpgradeAlreadyScheduled
+    );
+}
+
+#[test]
+fn schedule_upgrade_fails_if_contract_is_at_new_version() {
+    let (TestFixture { env, upgrader, .. }, mut upgrade) = setup_with_upgrade();
+
+    upgrade.new_version = String::from_str(&env, "0.1.0");
+
+    assert_contract_err!(
+        upgrader.try_schedule_upgrade(&upgrade),
+        ContractError::SameVersion
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn schedule_upgrade_fails_if_owner_does_not_authorize() {
+    let (TestFixture { env, upgrader, .. }, upgrade) = setup_with_upgrade();
+
+    env.set_auths(&[]);
+
+    upgrader.schedule_upgrade(&upgrade);
+}
+
+#[test]
+fn execute_upgrade_fails_before_delay_has_passed() {
+    let (
+        TestFixture {
+            env,
+            upgrader,
+            contract_address,
+            ..
+        },
+        upgrade,
+    ) = setup_with_upgrade();
+
+    upgrader.schedule_upgrade(&upgrade);
+    advance_time(&env, MINIMUM_DELAY - 1);
+
+    assert_contract_err!(
+        try_execute(&upgrader, &upgrade),
+        ContractError::UpgradeNotReady
+    );
+    assert_eq!(
+        version(&env, &contract_address),
+        String::from_str(&env, "0.1.0")
+    );
+}
+
+#[test]
+fn execute_upgrade_fails_if_not_scheduled() {
+    let (TestFixture { upgrader, .. }, upgrade) = setup_with_upgrade();
+
+    assert_contract_err!(
+        try_execute(&upgrader, &upgrade),
+        ContractError::UpgradeNotScheduled
+    );
+}
+
+#[test]
+fn execute_upgrade_succeeds_after_delay() {
+    let (
+        TestFixture {
+            env,
+            upgrader,
+            contract_address,
+            ..
+        },
+        upgrade,
+    ) = setup_with_upgrade();
+
+    upgrader.schedule_upgrade(&upgrade);
+    advance_time(&env, MINIMUM_DELAY);
+
+    execute(&upgrader, &upgrade);
+    goldie::assert!(events::fmt_last_emitted_event::<UpgradeExecutedEvent>(&env));
+
+    assert_eq!(version(&env, &contract_address), upgrade.new_version);
+    env.as_contract(&contract_address, || {
+        let data: String = env.storage().instance().get(&DataKey::Data).unwrap();
+        assert_eq!(data, String::from_str(&env, "migrated"));
+    });
+    assert!(upgrader
+        .pending_upgrades(&upgrade.contract_address)
+        .is_empty());
+
+    assert_contract_err!(
+        try_execute(&upgrader, &upgrade),
+        ContractError::UpgradeNotScheduled
+    );
+}
+
+#[test]
+fn cancel_upgrade_removes_pending_upgrade() {
+    let (
+        TestFixture {
+            env,
+            upgrader,
+            contract_owner,
+            ..
+        },
+        upgrade,
+    ) = setup_with_upgrade();
+
+    upgrader.schedule_upgrade(&upgrade);
+    upgrader.cancel_upgrade(
+        &upgrade.contract_address,
+        &upgrade.new_wasm_hash,
+        &upgrade.new_version,
+    );
+
+    assert_eq!(env.auths()[0].0, contract_owner);
+    goldie::assert!(events::fmt_last_emitted_event::<UpgradeCancelledEvent>(
+        &env
+    ));
+    assert!(upgrader
+        .pending_upgrades(&upgrade.contract_address)
+        .is_empty());
+
+    advance_time(&env, MINIMUM_DELAY);
+    assert_contract_err!(
+        try_execute(&upgrader, &upgrade),
+        ContractError::UpgradeNotScheduled
+    );
+}
+
+#[test]
+fn pending_upgrades_lists_upgrades_in_scheduling_order() {
+    let (
+        TestFixture {
+            env,
+            upgrader,
+            contract_owner,
+            ..
+        },
+        upgrade,
+    ) = setup_with_upgrade();
+
+    let other_version = UpgradeItem {
+        new_version: String::from_str(&env, "0.3.0"),
+        ..upgrade.clone()
+    };
+    let other_contract = UpgradeItem {
+        contract_address: env.register(DummyContract, (&contract_owner,)),
+        ..upgrade.clone()
+    };
+
+    let eta = upgrader.schedule_upgrade(&upgrade);
+    advance_time(&env, 10);
+    let other_eta = upgrader.schedule_upgrade(&other_version);
+    upgrader.schedule_upgrade(&other_contract);
+
+    assert_eq!(
+        upgrader.pending_upgrades(&upgrade.contract_address),
+        vec![
+            &env,
+            PendingUpgrade {
+                upgrade: upgrade.clone(),
+                eta
+            },
+            PendingUpgrade {
+                upgrade: other_version,
+                eta: other_eta
+            }
+        ]
+    );
+    assert_eq!(
+        upgrader
+            .pending_upgrades(&other_contract.contract_address)
+            .len(),
+        1
+    );
+
+    upgrader.cancel_upgrade(
+        &upgrade.contract_address,
+        &upgrade.new_wasm_hash,
+        &upgrade.new_version,
+    );
+    assert_eq!(
+        upgrader.pending_upgrades(&upgrade.contract_address).len(),
+        1
+    );
+}
+
+#[test]
+fn schedule_upgrade_fails_if_contract_has_too_many_pending_upgrades() {
+    let (TestFixture { env, upgrader, .. }, upgrade) = setup_with_upgrade();
+
+    for minor in 2..12 {
+        upgrader.schedule_upgrade(&UpgradeItem {
+            new_version: String::from_str(&env, &std::format!("0.{minor}.0")),
+            ..upgrade.clone()
+        });
+    }
+
+    assert_contract_err!(
+        upgrader.try_schedule_upgrade(&UpgradeItem {
+            new_version: String::from_str(&env, "0.12.0"),
+            ..upgrade
+        }),
+        ContractError::TooManyPendingUpgrades
+    );
+}
+
+#[test]
+fn extend_pending_upgrade_ttl_extends_scheduled_upgrade() {
+    #[contracttype]
+    enum UpgraderDataKey {
+        PendingUpgrade(UpgradeKey),
+    }
+
+    let (TestFixture { env, upgrader, .. }, upgrade) = setup_with_upgrade();
+
+    assert_contract_err!(
+        upgrader.try_extend_pending_upgrade_ttl(
+            &upgrade.contract_address,
+            &upgrade.new_wasm_hash,
+            &upgrade.new_version,
+        ),
+        ContractError::UpgradeNotScheduled
+    );
+
+    upgrader.schedule_upgrade(&upgrade);
+
+    let ttl = PERSISTENT_TTL_EXTEND_TO;
+    env.ledger()
+        .with_mut(|ledger| ledger.sequence_number += ttl - PERSISTENT_TTL_THRESHOLD + 1);
+
+    upgrader.extend_pending_upgrade_ttl(
+        &upgrade.contract_address,
+        &upgrade.new_wasm_hash,
+        &upgrade.new_version,
+    );
+
+    env.as_contract(&upgrader.address, || {
+        assert_eq!(
+            env.storage()
+                .persistent()
+                .get_ttl(&UpgraderDataKey::PendingUpgrade(upgrade.key())),
+            ttl
+        );
+    });
+}
+
+#[test]
+fn contract_owned_by_upgrader_can_only_be_upgraded_through_timelock() {
+    let (
+        TestFixture {
+            env,
+            upgrader,
+            upgrader_owner,
+            ..
+        },
+        upgrade,
+    ) = setup_with_upgrade();
+
+    let contract_address = env.register(DummyContract, (&upgrader.address,));
+    let upgrade = UpgradeItem {
+        contract_address: contract_address.clone(),
+        ..upgrade
+    };
+
+    assert_contract_err!(
+        upgrader.try_upgrade(
+            &upgrade.contract_address,
+            &upgrade.new_version,
+            &upgrade.new_wasm_hash,
+            &upgrade.migration_data,
+        ),
+        ContractError::TimelockRequired
+    );
+    assert_contract_err!(
+        upgrader.try_upgrade_batch(&upgrader.address, &vec![&env, upgrade.clone()]),
+        ContractError::TimelockRequired
+    );
+
+    upgrader.schedule_upgrade(&upgrade);
+    assert_eq!(env.auths()[0].0, upgrader_owner);
+
+    advance_time(&env, MINIMUM_DELAY);
+    env.set_auths(&[]);
+    execute(&upgrader, &upgrade);
+
+    assert_eq!(version(&env, &contract_address), upgrade.new_version);
+}
@@ -1,8 +1,11 @@
 //! Dummy contract to test the [crate::Upgrader]
 
 use axelar_soroban_std::interfaces;
-use axelar_soroban_std::interfaces::{OwnableInterface, UpgradableInterface};
-use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, BytesN, Env};
+use axelar_soroban_std::interfaces::{OwnableInterface, UpgradableInterface, VersionHistoryEntry};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, Address, BytesN, Env,
+    Vec,
+};
 
 #[contract]
 pub struct DummyContract;
@@ -13,11 +16,33 @@ impl UpgradableInterface for DummyContract {
         soroban_sdk::String::from_str(env, "0.1.0")
     }
 
+    fn contract_kind(env: &Env) -> soroban_sdk::String {
+        soroban_sdk::String::from_str(env, "dummy")
+    }
+
     fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) {
         Self::owner(env).require_auth();
 
         env.deployer().update_current_contract_wasm(new_wasm_hash);
     }
+
+    fn version_history(env: &Env) -> Vec<VersionHistoryEntry> {
+        interfaces::version_history(env)
+    }
+
+    fn record_deployed_version(env: &Env, wasm_hash: BytesN<32>) {
+        interfaces::record_deployed_version::<Self>(
+            env,
+            wasm_hash,
+            interfaces::INITIAL_SCHEMA_VERSION,
+        )
+        .unwrap_or_else(|_| panic_with_error!(env, ContractError::SomeFailure));
+    }
+
+    fn rollback(env: &Env) {
+        interfaces::rollback::<Self>(env)
+            .unwrap_or_else(|_| panic_with_error!(env, ContractError::SomeFailure));
+    }
 }
 
 #[contractimpl]
@@ -26,8 +51,20 @@ impl OwnableInterface for DummyContract {
         interfaces::owner(env)
     }
 
-    fn transfer_ownership(env: &Env, new_owner: Address) {
-        interfaces::transfer_ownership::<Self>(env, new_owner);
+    fn pending_owner(env: &Env) -> Option<Address> {
+        interfaces::pending_owner(env)
+    }
+
+    fn propose_ownership(env: &Env, new_owner: Address) {
+        interfaces::propose_ownership::<Self>(env, new_owner);
+    }
+
+    fn accept_ownership(env: &Env) {
+        interfaces::accept_ownership::<Self>(env);
+    }
+
+    fn cancel_ownership_proposal(env: &Env) {
+        interfaces::cancel_ownership_proposal::<Self>(env);
     }
 }
 
@@ -2,3 +2,73 @@ mod dummy_contract;
 mod dummy_contract_after_upgrade;
 
 pub use dummy_contract::*;
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, BytesN, Env, String};
+use upgrader::types::UpgradeItem;
+use upgrader::{Upgrader, UpgraderClient};
+
+pub const WASM_AFTER_UPGRADE: &[u8] = include_bytes!("../testdata/dummy.wasm");
+
+#[allow(dead_code)]
+pub struct TestFixture {
+    pub env: Env,
+    pub upgrader: UpgraderClient<'static>,
+    pub upgrader_owner: Address,
+    pub auditor: Address,
+    pub contract_owner: Address,
+    pub contract_address: Address,
+    pub hash_after_upgrade: BytesN<32>,
+}
+
+/// Registers an upgrader with the given minimum delay and an auditor, and a dummy contract that can be upgraded to [WASM_AFTER_UPGRADE].
+#[allow(dead_code)]
+pub fn setup(minimum_delay: u64) -> TestFixture {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let upgrader_owner = Address::generate(&env);
+    let auditor = Address::generate(&env);
+    let upgrader_address = env.register(Upgrader, (&upgrader_owner, &auditor, minimum_delay));
+    let upgrader = UpgraderClient::new(&env, &upgrader_address);
+
+    let contract_owner = Address::generate(&env);
+    let contract_address = register_dummy(&env, &contract_owner);
+    let hash_after_upgrade = env.deployer().upload_contract_wasm(WASM_AFTER_UPGRADE);
+
+    TestFixture {
+        env,
+        upgrader,
+        upgrader_owner,
+        auditor,
+        contract_owner,
+        contract_address,
+        hash_after_upgrade,
+    }
+}
+
+#[allow(dead_code)]
+pub fn register_dummy(env: &Env, owner: &Address) -> Address {
+    env.register(DummyContract, (owner,))
+}
+
+#[allow(dead_code)]
+pub fn upgrade_item(
+    env: &Env,
+    contract_address: &Address,
+    new_version: &str,
+    hash: &BytesN<32>,
+    data: &str,
+) -> UpgradeItem {
+    UpgradeItem {
+        contract_address: contract_address.clone(),
+        new_version: String::from_str(env, new_version),
+        new_wasm_hash: hash.clone(),
+        migration_data: vec![env, String::from_str(env, data).to_val()],
+    }
+}
+
+#[allow(dead_code)]
+pub fn version(env: &Env, contract_address: &Address) -> String {
+    DummyContractClient::new(env, contract_address).version()
+}
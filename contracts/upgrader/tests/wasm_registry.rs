@@ -0,0 +1,312 @@
+mod utils;
+
+use axelar_soroban_std::interfaces::ApprovedWasm;
+use axelar_soroban_std::{assert_contract_err, events};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, String, Symbol};
+use upgrader::error::ContractError;
+use upgrader::event::{
+    AllowlistEnforcedSetEvent, WasmHashRegisteredEvent, WasmHashUnregisteredEvent,
+};
+use upgrader::types::UpgradeItem;
+use utils::{setup, upgrade_item, version, TestFixture};
+
+const MINIMUM_DELAY: u64 = 86400;
+
+fn register(fixture: &TestFixture, auditor: &Address, version: &str) {
+    fixture.upgrader.register_wasm_hash(
+        auditor,
+        &String::from_str(&fixture.env, "dummy"),
+        &String::from_str(&fixture.env, version),
+        &fixture.hash_after_upgrade,
+    );
+}
+
+fn upgrade(fixture: &TestFixture) -> UpgradeItem {
+    upgrade_item(
+        &fixture.env,
+        &fixture.contract_address,
+        "0.2.0",
+        &fixture.hash_after_upgrade,
+        "migrated",
+    )
+}
+
+fn try_upgrade(
+    fixture: &TestFixture,
+) -> Result<Result<(), soroban_sdk::ConversionError>, Result<ContractError, soroban_sdk::InvokeError>>
+{
+    let upgrade = upgrade(fixture);
+    fixture.upgrader.try_upgrade(
+        &upgrade.contract_address,
+        &upgrade.new_version,
+        &upgrade.new_wasm_hash,
+        &upgrade.migration_data,
+    )
+}
+
+#[test]
+fn register_wasm_hash_fails_without_auditor_role() {
+    let fixture = setup(0);
+
+    assert_contract_err!(
+        fixture.upgrader.try_register_wasm_hash(
+            &Address::generate(&fixture.env),
+            &String::from_str(&fixture.env, "dummy"),
+            &String::from_str(&fixture.env, "0.2.0"),
+            &fixture.hash_after_upgrade,
+        ),
+        ContractError::MissingRole
+    );
+}
+
+#[test]
+fn register_wasm_hash_succeeds_if_caller_is_auditor() {
+    let fixture = setup(0);
+    let auditor = &fixture.auditor;
+
+    register(&fixture, auditor, "0.2.0");
+    goldie::assert!(events::fmt_last_emitted_event::<WasmHashRegisteredEvent>(
+        &fixture.env
+    ));
+
+    assert_eq!(
+        fixture.upgrader.approved_wasm(&fixture.hash_after_upgrade),
+        Some(ApprovedWasm {
+            contract_kind: String::from_str(&fixture.env, "dummy"),
+            version: String::from_str(&fixture.env, "0.2.0"),
+        })
+    );
+}
+
+#[test]
+fn register_wasm_hash_fails_if_already_registered() {
+    let fixture = setup(0);
+    let auditor = &fixture.auditor;
+
+    register(&fixture, auditor, "0.2.0");
+
+    assert_contract_err!(
+        fixture.upgrader.try_register_wasm_hash(
+            auditor,
+            &String::from_str(&fixture.env, "dummy"),
+            &String::from_str(&fixture.env, "0.3.0"),
+            &fixture.hash_after_upgrade,
+        ),
+        ContractError::WasmHashAlreadyRegistered
+    );
+}
+
+#[test]
+fn unregister_wasm_hash_removes_approval() {
+    let fixture = setup(0);
+    let auditor = &fixture.auditor;
+
+    assert_contract_err!(
+        fixture
+            .upgrader
+            .try_unregister_wasm_hash(auditor, &fixture.hash_after_upgrade),
+        ContractError::WasmHashNotRegistered
+    );
+
+    register(&fixture, auditor, "0.2.0");
+    fixture
+        .upgrader
+        .unregister_wasm_hash(auditor, &fixture.hash_after_upgrade);
+    goldie::assert!(events::fmt_last_emitted_event::<WasmHashUnregisteredEvent>(
+        &fixture.env
+    ));
+
+    assert_eq!(
+        fixture.upgrader.approved_wasm(&fixture.hash_after_upgrade),
+        None
+    );
+}
+
+#[test]
+fn upgrade_accepts_any_wasm_hash_if_allowlist_not_enforced() {
+    let fixture = setup(0);
+
+    assert!(!fixture.upgrader.allowlist_enforced());
+    assert!(try_upgrade(&fixture).is_ok());
+}
+
+#[test]
+fn upgrade_fails_if_allowlist_enforced_and_wasm_hash_not_approved_for_version() {
+    let fixture = setup(0);
+    let auditor = &fixture.auditor;
+
+    fixture.upgrader.set_allowlist_enforced(&true);
+    goldie::assert!(events::fmt_last_emitted_event::<AllowlistEnforcedSetEvent>(
+        &fixture.env
+    ));
+
+    assert_contract_err!(try_upgrade(&fixture), ContractError::UnapprovedWasmHash);
+
+    register(&fixture, auditor, "0.3.0");
+    assert_contract_err!(try_upgrade(&fixture), ContractError::UnapprovedWasmHash);
+    assert_contract_err!(
+        fixture.upgrader.try_upgrade_batch(
+            &fixture.contract_owner,
+            &vec![&fixture.env, upgrade(&fixture)]
+        ),
+        ContractError::UnapprovedWasmHash
+    );
+    assert_contract_err!(
+        fixture.upgrader.try_schedule_upgrade(&upgrade(&fixture)),
+        ContractError::UnapprovedWasmHash
+    );
+}
+
+#[test]
+fn upgrade_succeeds_if_allowlist_enforced_and_wasm_hash_approved() {
+    let fixture = setup(0);
+    let auditor = &fixture.auditor;
+
+    fixture.upgrader.set_allowlist_enforced(&true);
+    register(&fixture, auditor, "0.2.0");
+
+    assert!(try_upgrade(&fixture).is_ok());
+    assert_eq!(
+        version(&fixture.env, &fixture.contract_address),
+        String::from_str(&fixture.env, "0.2.0")
+    );
+}
+
+#[test]
+fn scheduled_upgrade_fails_if_wasm_hash_is_unregistered_before_execution() {
+    let fixture = setup(0);
+    let auditor = &fixture.auditor;
+
+    fixture.upgrader.set_allowlist_enforced(&true);
+    register(&fixture, auditor, "0.2.0");
+
+    let upgrade = upgrade(&fixture);
+    fixture.upgrader.schedule_upgrade(&upgrade);
+    fixture
+        .upgrader
+        .unregister_wasm_hash(auditor, &fixture.hash_after_upgrade);
+    fixture
+        .env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp += 1);
+
+    assert_contract_err!(
+        fixture.upgrader.try_execute_upgrade(
+            &upgrade.contract_address,
+            &upgrade.new_wasm_hash,
+            &upgrade.new_version,
+        ),
+        ContractError::UnapprovedWasmHash
+    );
+}
+
+#[test]
+fn upgrade_fails_if_wasm_hash_approved_for_other_contract_kind() {
+    let fixture = setup(0);
+
+    fixture.upgrader.set_allowlist_enforced(&true);
+    fixture.upgrader.register_wasm_hash(
+        &fixture.auditor,
+        &String::from_str(&fixture.env, "interchain-token"),
+        &String::from_str(&fixture.env, "0.2.0"),
+        &fixture.hash_after_upgrade,
+    );
+
+    assert_contract_err!(try_upgrade(&fixture), ContractError::UnapprovedWasmHash);
+}
+
+#[test]
+fn disabling_allowlist_takes_effect_after_minimum_delay() {
+    let fixture = setup(MINIMUM_DELAY);
+
+    fixture.upgrader.set_allowlist_enforced(&true);
+    fixture.upgrader.set_allowlist_enforced(&false);
+
+    let eta = fixture.env.ledger().timestamp() + MINIMUM_DELAY;
+    goldie::assert!(events::fmt_last_emitted_event::<AllowlistEnforcedSetEvent>(
+        &fixture.env
+    ));
+    assert!(fixture.upgrader.allowlist_enforced());
+    assert_eq!(fixture.upgrader.allowlist_disable_eta(), Some(eta));
+    assert_contract_err!(try_upgrade(&fixture), ContractError::UnapprovedWasmHash);
+
+    fixture
+        .env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp += MINIMUM_DELAY);
+
+    assert!(!fixture.upgrader.allowlist_enforced());
+    assert_eq!(fixture.upgrader.allowlist_disable_eta(), None);
+    assert!(try_upgrade(&fixture).is_ok());
+}
+
+#[test]
+fn enforcing_allowlist_cancels_pending_disable() {
+    let fixture = setup(MINIMUM_DELAY);
+
+    fixture.upgrader.set_allowlist_enforced(&true);
+    fixture.upgrader.set_allowlist_enforced(&false);
+    fixture.upgrader.set_allowlist_enforced(&true);
+
+    fixture
+        .env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp += MINIMUM_DELAY);
+
+    assert!(fixture.upgrader.allowlist_enforced());
+    assert_eq!(fixture.upgrader.allowlist_disable_eta(), None);
+}
+
+#[test]
+fn owner_cannot_grant_itself_the_auditor_role() {
+    let fixture = setup(0);
+    let auditor_role = Symbol::new(&fixture.env, "auditor");
+
+    assert_eq!(
+        fixture.upgrader.role_admin(&auditor_role),
+        Some(auditor_role.clone())
+    );
+    assert_eq!(
+        fixture.upgrader.try_grant_role(
+            &fixture.upgrader_owner,
+            &auditor_role,
+            &Address::generate(&fixture.env)
+        ),
+        Err(Ok(ContractError::MissingRole.into()))
+    );
+    assert_eq!(
+        fixture
+            .upgrader
+            .try_grant_role(&fixture.auditor, &auditor_role, &fixture.upgrader_owner),
+        Err(Ok(ContractError::OwnerCannotBeAuditor.into()))
+    );
+    assert_eq!(
+        fixture.upgrader.try_set_role_admin(&auditor_role, &None),
+        Err(Ok(ContractError::AuditorRoleAdminLocked.into()))
+    );
+
+    let other_auditor = Address::generate(&fixture.env);
+    fixture
+        .upgrader
+        .grant_role(&fixture.auditor, &auditor_role, &other_auditor);
+    assert!(fixture.upgrader.has_role(&auditor_role, &other_auditor));
+}
+
+#[test]
+fn register_wasm_hash_fails_if_auditor_is_owner() {
+    let fixture = setup(0);
+
+    fixture.upgrader.propose_ownership(&fixture.auditor);
+    fixture.upgrader.accept_ownership();
+
+    assert_contract_err!(
+        fixture.upgrader.try_register_wasm_hash(
+            &fixture.auditor,
+            &String::from_str(&fixture.env, "dummy"),
+            &String::from_str(&fixture.env, "0.2.0"),
+            &fixture.hash_after_upgrade,
+        ),
+        ContractError::OwnerCannotBeAuditor
+    );
+}
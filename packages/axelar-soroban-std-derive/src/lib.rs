@@ -1,7 +1,10 @@
+mod storage;
+
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{
-    parse::Parse, parse::ParseStream, parse_macro_input, DeriveInput, Error, Ident, Token, Type,
+    parse::Parse, parse::ParseStream, parse_macro_input, Data, DeriveInput, Error, Fields, Ident,
+    ItemEnum, Token, Type,
 };
 
 /// Implements the Operatable interface for a Soroban contract.
@@ -38,8 +41,20 @@ pub fn derive_operatable(input: TokenStream) -> TokenStream {
                 axelar_soroban_std::interfaces::operator(env)
             }
 
-            fn transfer_operatorship(env: &Env, new_operator: soroban_sdk::Address) {
-                axelar_soroban_std::interfaces::transfer_operatorship::<Self>(env, new_operator);
+            fn pending_operator(env: &Env) -> Option<soroban_sdk::Address> {
+                axelar_soroban_std::interfaces::pending_operator(env)
+            }
+
+            fn propose_operatorship(env: &Env, new_operator: soroban_sdk::Address) {
+                axelar_soroban_std::interfaces::propose_operatorship::<Self>(env, new_operator);
+            }
+
+            fn accept_operatorship(env: &Env) {
+                axelar_soroban_std::interfaces::accept_operatorship::<Self>(env);
+            }
+
+            fn cancel_operatorship_proposal(env: &Env) {
+                axelar_soroban_std::interfaces::cancel_operatorship_proposal::<Self>(env);
             }
         }
     }
@@ -80,8 +95,258 @@ pub fn derive_ownable(input: TokenStream) -> TokenStream {
                 axelar_soroban_std::interfaces::owner(env)
             }
 
-            fn transfer_ownership(env: &Env, new_owner: soroban_sdk::Address) {
-                axelar_soroban_std::interfaces::transfer_ownership::<Self>(env, new_owner);
+            fn pending_owner(env: &Env) -> Option<soroban_sdk::Address> {
+                axelar_soroban_std::interfaces::pending_owner(env)
+            }
+
+            fn propose_ownership(env: &Env, new_owner: soroban_sdk::Address) {
+                axelar_soroban_std::interfaces::propose_ownership::<Self>(env, new_owner);
+            }
+
+            fn accept_ownership(env: &Env) {
+                axelar_soroban_std::interfaces::accept_ownership::<Self>(env);
+            }
+
+            fn cancel_ownership_proposal(env: &Env) {
+                axelar_soroban_std::interfaces::cancel_ownership_proposal::<Self>(env);
+            }
+        }
+    }
+    .into()
+}
+
+/// Implements the AccessControl interface for a Soroban contract.
+///
+/// Roles are administered by the contract owner unless an admin role is set, so the contract must implement the Ownable interface.
+/// A `ContractError` error type must be defined in scope, and have a `MissingRole` variant.
+/// Use [`only_role!`](../axelar_soroban_std/macro.only_role.html) to restrict entrypoints to members of a role.
+///
+/// # Example
+/// ```rust
+/// # mod test {
+/// # use soroban_sdk::{contract, contracterror, contractimpl, Address, Env, Symbol};
+/// use axelar_soroban_std::only_role;
+/// use axelar_soroban_std_derive::{AccessControl, Ownable};
+/// # #[contracterror]
+/// # #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+/// # #[repr(u32)]
+/// # pub enum ContractError {
+/// #     MissingRole = 1,
+/// # }
+///
+/// #[contract]
+/// #[derive(Ownable, AccessControl)]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn __constructor(env: &Env, owner: Address, pauser: Address) {
+///         axelar_soroban_std::interfaces::set_owner(env, &owner);
+///         axelar_soroban_std::interfaces::set_role(env, &Symbol::new(env, "pauser"), &pauser);
+///     }
+///
+///     pub fn pause(env: &Env, caller: Address) -> Result<(), ContractError> {
+///         only_role!(env, caller, Symbol::new(env, "pauser"));
+///
+///         Ok(())
+///     }
+/// }
+/// # }
+/// ```
+#[proc_macro_derive(AccessControl)]
+pub fn derive_access_control(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    quote! {
+        use axelar_soroban_std::interfaces::AccessControlInterface as _;
+
+        #[soroban_sdk::contractimpl]
+        impl axelar_soroban_std::interfaces::AccessControlInterface for #name {
+            fn has_role(env: &Env, role: soroban_sdk::Symbol, account: soroban_sdk::Address) -> bool {
+                axelar_soroban_std::interfaces::has_role(env, &role, &account)
+            }
+
+            fn role_admin(env: &Env, role: soroban_sdk::Symbol) -> Option<soroban_sdk::Symbol> {
+                axelar_soroban_std::interfaces::role_admin(env, &role)
+            }
+
+            fn grant_role(env: &Env, caller: soroban_sdk::Address, role: soroban_sdk::Symbol, account: soroban_sdk::Address) {
+                axelar_soroban_std::interfaces::grant_role::<Self>(env, caller, role, account)
+                    .unwrap_or_else(|_| soroban_sdk::panic_with_error!(env, ContractError::MissingRole));
+            }
+
+            fn revoke_role(env: &Env, caller: soroban_sdk::Address, role: soroban_sdk::Symbol, account: soroban_sdk::Address) {
+                axelar_soroban_std::interfaces::revoke_role::<Self>(env, caller, role, account)
+                    .unwrap_or_else(|_| soroban_sdk::panic_with_error!(env, ContractError::MissingRole));
+            }
+
+            fn renounce_role(env: &Env, account: soroban_sdk::Address, role: soroban_sdk::Symbol) {
+                axelar_soroban_std::interfaces::renounce_role(env, account, role);
+            }
+
+            fn set_role_admin(env: &Env, role: soroban_sdk::Symbol, admin_role: Option<soroban_sdk::Symbol>) {
+                axelar_soroban_std::interfaces::set_role_admin::<Self>(env, role, admin_role);
+            }
+        }
+    }
+    .into()
+}
+
+/// Implements the TtlConfigurable interface for a Soroban contract.
+///
+/// The contract must implement the Ownable interface, since only the owner can update the TTL config.
+/// A `ContractError` error type must be defined in scope, and have an `InvalidTtlConfig` variant.
+///
+/// No TTL config is stored on deployment. Until one is stored, either by the constructor via
+/// `axelar_soroban_std::interfaces::store_ttl_config` or by the owner via `set_ttl_config`, the contract runs on
+/// `TtlConfig::default`, i.e. the policies of the constants in `axelar_soroban_std::ttl`.
+///
+/// # Example
+/// ```rust
+/// # mod test {
+/// # use soroban_sdk::{contract, contracterror, contractimpl, Address, Env};
+/// use axelar_soroban_std::ttl::TtlConfig;
+/// use axelar_soroban_std_derive::{Ownable, TtlConfigurable};
+/// # #[contracterror]
+/// # #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+/// # #[repr(u32)]
+/// # pub enum ContractError {
+/// #     InvalidTtlConfig = 1,
+/// # }
+///
+/// #[contract]
+/// #[derive(Ownable, TtlConfigurable)]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn __constructor(env: &Env, owner: Address, ttl_config: Option<TtlConfig>) {
+///         axelar_soroban_std::interfaces::set_owner(env, &owner);
+///
+///         // without a config, the contract runs on TtlConfig::default
+///         if let Some(ttl_config) = ttl_config {
+///             axelar_soroban_std::interfaces::store_ttl_config(env, &ttl_config)
+///                 .expect("invalid ttl config");
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[proc_macro_derive(TtlConfigurable)]
+pub fn derive_ttl_configurable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    quote! {
+        use axelar_soroban_std::interfaces::TtlConfigurableInterface as _;
+
+        #[soroban_sdk::contractimpl]
+        impl axelar_soroban_std::interfaces::TtlConfigurableInterface for #name {
+            fn ttl_config(env: &Env) -> axelar_soroban_std::ttl::TtlConfig {
+                axelar_soroban_std::interfaces::ttl_config(env)
+            }
+
+            fn set_ttl_config(env: &Env, config: axelar_soroban_std::ttl::TtlConfig) {
+                axelar_soroban_std::interfaces::set_ttl_config::<Self>(env, config)
+                    .unwrap_or_else(|_| soroban_sdk::panic_with_error!(env, ContractError::InvalidTtlConfig));
+            }
+        }
+    }
+    .into()
+}
+
+#[derive(Debug, Default)]
+struct PausableArgs {
+    authority: Option<Ident>,
+}
+
+impl Parse for PausableArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let ident = input.parse::<Ident>()?;
+        if ident != "by" {
+            return Err(Error::new(ident.span(), "expected `by = ...`"));
+        }
+
+        input.parse::<Token![=]>()?;
+        let authority = input.parse::<Ident>()?;
+        if authority != "owner" && authority != "operator" {
+            return Err(Error::new(
+                authority.span(),
+                "expected `owner` or `operator`",
+            ));
+        }
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self {
+            authority: Some(authority),
+        })
+    }
+}
+
+/// Implements the Pausable interface for a Soroban contract.
+///
+/// By default, only the owner can pause and unpause the contract. Use `#[pausable(by = operator)]`
+/// to delegate this to the operator instead. The contract must implement the corresponding Ownable or Operatable interface.
+///
+/// # Example
+/// ```rust
+/// # mod test {
+/// # use soroban_sdk::{contract, contractimpl, Address, Env};
+/// use axelar_soroban_std_derive::{Ownable, Pausable};
+///
+/// #[contract]
+/// #[derive(Ownable, Pausable)]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn __constructor(env: &Env, owner: Address) {
+///         axelar_soroban_std::interfaces::set_owner(env, &owner);
+///     }
+/// }
+/// # }
+/// ```
+#[proc_macro_derive(Pausable, attributes(pausable))]
+pub fn derive_pausable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let args = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("pausable"))
+        .map(|attr| attr.parse_args::<PausableArgs>())
+        .transpose()
+        .unwrap_or_else(|e| panic!("{}", e))
+        .unwrap_or_default();
+
+    let authority = args
+        .authority
+        .unwrap_or_else(|| Ident::new("owner", proc_macro2::Span::call_site()));
+
+    quote! {
+        use axelar_soroban_std::interfaces::PausableInterface as _;
+
+        #[soroban_sdk::contractimpl]
+        impl axelar_soroban_std::interfaces::PausableInterface for #name {
+            fn paused(env: &Env) -> bool {
+                axelar_soroban_std::interfaces::paused(env)
+            }
+
+            fn pause(env: &Env) {
+                axelar_soroban_std::interfaces::pause(env, Self::#authority(env));
+            }
+
+            fn unpause(env: &Env) {
+                axelar_soroban_std::interfaces::unpause(env, Self::#authority(env));
             }
         }
     }
@@ -91,6 +356,7 @@ pub fn derive_ownable(input: TokenStream) -> TokenStream {
 #[derive(Debug, Default)]
 struct MigrationArgs {
     migration_data: Option<Type>,
+    steps: Vec<Type>,
 }
 
 impl Parse for MigrationArgs {
@@ -99,25 +365,70 @@ impl Parse for MigrationArgs {
             return Ok(Self::default());
         }
 
-        let migration_data = Some(Self::parse_migration_data(input)?);
+        let ident = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+
+        let args = if ident == "with_type" {
+            Self {
+                migration_data: Some(input.parse::<Type>()?),
+                steps: Vec::new(),
+            }
+        } else if ident == "steps" {
+            Self {
+                migration_data: None,
+                steps: Self::parse_steps(input)?,
+            }
+        } else {
+            return Err(Error::new(
+                ident.span(),
+                "expected `with_type = ...` or `steps = [...]`",
+            ));
+        };
 
         if !input.is_empty() {
             input.parse::<Token![,]>()?;
         }
 
-        Ok(Self { migration_data })
+        Ok(args)
     }
 }
 
 impl MigrationArgs {
-    fn parse_migration_data(input: ParseStream) -> syn::Result<Type> {
+    fn parse_steps(input: ParseStream) -> syn::Result<Vec<Type>> {
+        let content;
+        syn::bracketed!(content in input);
+
+        let steps = content.parse_terminated(Type::parse, Token![,])?;
+        if steps.is_empty() {
+            return Err(Error::new(
+                content.span(),
+                "expected at least one migration step",
+            ));
+        }
+
+        Ok(steps.into_iter().collect())
+    }
+}
+
+#[derive(Debug, Default)]
+struct UpgradableArgs {
+    wasm_registry: bool,
+}
+
+impl Parse for UpgradableArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
         let ident = input.parse::<Ident>()?;
-        if ident != "with_type" {
-            return Err(Error::new(ident.span(), "expected `with_type = ...`"));
+        if ident != "wasm_registry" {
+            return Err(Error::new(ident.span(), "expected `wasm_registry`"));
         }
 
-        input.parse::<Token![=]>()?;
-        input.parse::<Type>()
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self {
+            wasm_registry: true,
+        })
     }
 }
 
@@ -125,6 +436,22 @@ impl MigrationArgs {
 ///
 /// A `ContractError` error type must be defined in scope, and have a `MigrationNotAllowed` variant.
 ///
+/// `#[migratable(with_type = T)]` runs a single `run_migration` step with data of type `T`.
+/// `#[migratable(steps = [T2, T3, ...])]` instead tracks the schema version of the contract state,
+/// starting at 1, and migrates it step by step with `run_migration_v2`, `run_migration_v3`, etc.
+/// `migrate` then takes a vector with the data of each step that has to run, so a contract that skipped
+/// several releases can still be migrated. Migrating to a lower schema version fails.
+///
+/// Completed upgrades are recorded in a version history, and `rollback` upgrades the contract back to the
+/// WASM hash of the previous entry. It fails with `MigrationNotAllowed` if there is no previous entry, or if the
+/// previous entry is at a different schema version than the contract state. `record_deployed_version` records the
+/// WASM hash the contract was deployed with, so the first upgrade can be rolled back as well.
+///
+/// `#[upgradable(wasm_registry)]` only allows upgrades and rollbacks to WASM hashes that the contract's registry
+/// approved for this contract kind, i.e. the crate name. The registry can be set internally with
+/// `axelar_soroban_std::interfaces::set_wasm_registry`, and by the owner through the generated `set_wasm_registry` entrypoint.
+/// `ContractError` then also needs an `UnapprovedWasmHash` variant.
+///
 /// # Example
 /// ```rust
 /// # mod test {
@@ -151,12 +478,12 @@ impl MigrationArgs {
 ///
 /// impl Contract {
 ///     fn run_migration(env: &Env, new_owner: Address) {
-///         Self::transfer_ownership(env, new_owner);
+///         axelar_soroban_std::interfaces::set_owner(env, &new_owner);
 ///     }
 /// }
 /// # }
 /// ```
-#[proc_macro_derive(Upgradable, attributes(migratable))]
+#[proc_macro_derive(Upgradable, attributes(migratable, upgradable))]
 pub fn derive_upgradable(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
@@ -170,6 +497,15 @@ pub fn derive_upgradable(input: TokenStream) -> TokenStream {
         .unwrap_or_else(|e| panic!("{}", e))
         .unwrap_or_else(MigrationArgs::default);
 
+    let upgradable_args = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("upgradable"))
+        .map(|attr| attr.parse_args::<UpgradableArgs>())
+        .transpose()
+        .unwrap_or_else(|e| panic!("{}", e))
+        .unwrap_or_default();
+
     syn::parse_str::<Type>("ContractError").unwrap_or_else(|_| {
         panic!(
             "{}",
@@ -189,34 +525,377 @@ pub fn derive_upgradable(input: TokenStream) -> TokenStream {
         )
     });
 
-    let migration_data = args
-        .migration_data
-        .as_ref()
-        .map_or_else(|| quote! { () }, |ty| quote! { #ty });
+    let code_schema_version = if args.steps.is_empty() {
+        quote! { axelar_soroban_std::interfaces::INITIAL_SCHEMA_VERSION }
+    } else {
+        let schema_version = versioned_schema_version(&args.steps);
+        quote! { #schema_version }
+    };
+
+    let version = quote! {
+        fn version(env: &Env) -> soroban_sdk::String {
+            soroban_sdk::String::from_str(env, env!("CARGO_PKG_VERSION"))
+        }
+
+        fn contract_kind(env: &Env) -> soroban_sdk::String {
+            soroban_sdk::String::from_str(env, env!("CARGO_PKG_NAME"))
+        }
+
+        fn version_history(env: &Env) -> soroban_sdk::Vec<axelar_soroban_std::interfaces::VersionHistoryEntry> {
+            axelar_soroban_std::interfaces::version_history(env)
+        }
+
+        fn record_deployed_version(env: &Env, wasm_hash: soroban_sdk::BytesN<32>) {
+            axelar_soroban_std::interfaces::record_deployed_version::<Self>(env, wasm_hash, #code_schema_version)
+                .unwrap_or_else(|_| soroban_sdk::panic_with_error!(env, ContractError::MigrationNotAllowed));
+        }
+    };
+
+    let (upgrade, migratable) = if args.steps.is_empty() {
+        single_step_migration(&args)
+    } else {
+        versioned_migration(&args.steps)
+    };
+
+    let ensure_wasm_hash_approved = |wasm_hash: proc_macro2::TokenStream| {
+        upgradable_args.wasm_registry.then(|| {
+            quote! {
+                axelar_soroban_std::interfaces::ensure_wasm_hash_approved(env, &Self::contract_kind(env), #wasm_hash)
+                    .unwrap_or_else(|_| soroban_sdk::panic_with_error!(env, ContractError::UnapprovedWasmHash));
+            }
+        })
+    };
+    let ensure_new_wasm_hash_approved = ensure_wasm_hash_approved(quote! { &new_wasm_hash });
+    let ensure_previous_wasm_hash_approved =
+        ensure_wasm_hash_approved(quote! { &previous.wasm_hash }).map(|ensure| {
+            quote! {
+                if let Some(previous) = axelar_soroban_std::interfaces::previous_version(env) {
+                    #ensure
+                }
+            }
+        });
+
+    let wasm_registry_configurable = upgradable_args.wasm_registry.then(|| {
+        quote! {
+            use axelar_soroban_std::interfaces::WasmRegistryConfigurableInterface as _;
+
+            #[soroban_sdk::contractimpl]
+            impl axelar_soroban_std::interfaces::WasmRegistryConfigurableInterface for #name {
+                fn wasm_registry(env: &Env) -> Option<soroban_sdk::Address> {
+                    axelar_soroban_std::interfaces::wasm_registry(env)
+                }
+
+                fn set_wasm_registry(env: &Env, registry: soroban_sdk::Address) {
+                    axelar_soroban_std::interfaces::update_wasm_registry::<Self>(env, registry);
+                }
+            }
+        }
+    });
 
     quote! {
         use axelar_soroban_std::interfaces::{UpgradableInterface as _, MigratableInterface as _};
 
         #[soroban_sdk::contractimpl]
         impl axelar_soroban_std::interfaces::UpgradableInterface for #name {
-            fn version(env: &Env) -> soroban_sdk::String {
-                soroban_sdk::String::from_str(env, env!("CARGO_PKG_VERSION"))
-            }
+            #version
 
             fn upgrade(env: &Env, new_wasm_hash: soroban_sdk::BytesN<32>) {
-                axelar_soroban_std::interfaces::upgrade::<Self>(env, new_wasm_hash);
+                #ensure_new_wasm_hash_approved
+                #upgrade
+            }
+
+            fn rollback(env: &Env) {
+                #ensure_previous_wasm_hash_approved
+                axelar_soroban_std::interfaces::rollback::<Self>(env)
+                    .unwrap_or_else(|_| soroban_sdk::panic_with_error!(env, ContractError::MigrationNotAllowed));
             }
         }
 
+        #wasm_registry_configurable
+
         #[soroban_sdk::contractimpl]
         impl axelar_soroban_std::interfaces::MigratableInterface for #name {
-            type MigrationData = #migration_data;
             type Error = ContractError;
 
-            fn migrate(env: &Env, migration_data: #migration_data) -> Result<(), ContractError> {
-                axelar_soroban_std::interfaces::migrate::<Self>(env, || Self::run_migration(env, migration_data))
-                    .map_err(|_| ContractError::MigrationNotAllowed)
+            #migratable
+        }
+    }
+    .into()
+}
+
+fn single_step_migration(
+    args: &MigrationArgs,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let migration_data = args
+        .migration_data
+        .as_ref()
+        .map_or_else(|| quote! { () }, |ty| quote! { #ty });
+
+    let upgrade = quote! {
+        axelar_soroban_std::interfaces::upgrade::<Self>(env, new_wasm_hash);
+    };
+
+    let migratable = quote! {
+        type MigrationData = #migration_data;
+
+        fn migrate(env: &Env, migration_data: #migration_data) -> Result<(), ContractError> {
+            axelar_soroban_std::interfaces::migrate::<Self>(env, || Self::run_migration(env, migration_data))
+                .map_err(|_| ContractError::MigrationNotAllowed)
+        }
+
+        fn schema_version(env: &Env) -> u32 {
+            axelar_soroban_std::interfaces::schema_version(env, axelar_soroban_std::interfaces::INITIAL_SCHEMA_VERSION)
+        }
+    };
+
+    (upgrade, migratable)
+}
+
+fn versioned_schema_version(steps: &[Type]) -> u32 {
+    u32::try_from(steps.len() + 1).expect("too many migration steps")
+}
+
+/// The first step migrates from the initial schema version to the next one, so the code is at one version above the number of steps.
+fn versioned_migration(steps: &[Type]) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let schema_version = versioned_schema_version(steps);
+    let step_versions = (2..=schema_version).collect::<Vec<_>>();
+    let step_fns = step_versions
+        .iter()
+        .map(|version| format_ident!("run_migration_v{}", version));
+
+    let upgrade = quote! {
+        axelar_soroban_std::interfaces::upgrade_from_schema_version::<Self>(env, new_wasm_hash, #schema_version);
+    };
+
+    let migratable = quote! {
+        type MigrationData = soroban_sdk::Vec<soroban_sdk::Val>;
+
+        fn migrate(env: &Env, migration_data: soroban_sdk::Vec<soroban_sdk::Val>) -> Result<(), ContractError> {
+            axelar_soroban_std::interfaces::migrate_to_schema_version::<Self>(env, #schema_version, migration_data, |version, data| {
+                match version {
+                    #(#step_versions => Self::#step_fns(
+                        env,
+                        <#steps as soroban_sdk::TryFromVal<soroban_sdk::Env, soroban_sdk::Val>>::try_from_val(env, &data)
+                            .map_err(|_| axelar_soroban_std::interfaces::MigrationError::InvalidMigrationData)?,
+                    ),)*
+                    _ => unreachable!("migration steps only run up to the schema version of the contract"),
+                }
+
+                Ok(())
+            })
+            .map_err(|_| ContractError::MigrationNotAllowed)
+        }
+
+        fn schema_version(env: &Env) -> u32 {
+            axelar_soroban_std::interfaces::schema_version(env, #schema_version)
+        }
+    };
+
+    (upgrade, migratable)
+}
+
+/// Implements the Event and EventTestutils traits for a struct.
+///
+/// The first topic is the snake_case name of the struct without the `Event` suffix.
+/// It is followed by the fields annotated with `#[topic]`, while fields annotated with `#[data]` make up the event data.
+/// Every field must be annotated with either attribute, and both keep their declaration order.
+///
+/// The data is emitted as a tuple of the `#[data]` fields, or as void if there are none.
+/// `#[event_data(bare)]` emits a single `#[data]` field as is, and `#[event_data(vec)]` emits an empty vector
+/// for events without data, so existing events keep the data format that indexers expect.
+///
+/// # Example
+/// ```rust
+/// # mod test {
+/// use axelar_soroban_std::events::Event;
+/// use axelar_soroban_std_derive::IntoEvent;
+/// use soroban_sdk::{Address, Bytes, Env, String};
+///
+/// // emitted with topics ("message_sent", sender, destination_chain) and data (payload,)
+/// #[derive(Debug, PartialEq, Eq, IntoEvent)]
+/// pub struct MessageSentEvent {
+///     #[topic]
+///     pub sender: Address,
+///     #[topic]
+///     pub destination_chain: String,
+///     #[data]
+///     pub payload: Bytes,
+/// }
+///
+/// fn send(env: &Env, sender: Address, destination_chain: String, payload: Bytes) {
+///     MessageSentEvent {
+///         sender,
+///         destination_chain,
+///         payload,
+///     }
+///     .emit(env);
+/// }
+/// # }
+/// ```
+#[proc_macro_derive(IntoEvent, attributes(topic, data, event_data))]
+pub fn derive_into_event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let event_name = event_name(name);
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields.named.iter().collect::<Vec<_>>(),
+            Fields::Unit => Vec::new(),
+            Fields::Unnamed(_) => {
+                panic!("IntoEvent can only be derived for structs with named fields")
+            }
+        },
+        _ => panic!("IntoEvent can only be derived for structs"),
+    };
+
+    let (topic_fields, data_fields): (Vec<_>, Vec<_>) = fields.into_iter().partition(|field| {
+        let is_topic = field.attrs.iter().any(|attr| attr.path().is_ident("topic"));
+        let is_data = field.attrs.iter().any(|attr| attr.path().is_ident("data"));
+
+        assert!(
+            is_topic != is_data,
+            "field `{}` must be annotated with either #[topic] or #[data]",
+            field.ident.as_ref().expect("fields are named")
+        );
+
+        is_topic
+    });
+
+    let topic_idents = topic_fields.iter().map(|field| &field.ident);
+    let topic_types = topic_fields.iter().map(|field| &field.ty);
+    let data_idents = data_fields.iter().map(|field| &field.ident);
+    let data_types = data_fields.iter().map(|field| &field.ty);
+
+    let data_format = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("event_data"))
+        .map(|attr| attr.parse_args::<Ident>())
+        .transpose()
+        .unwrap_or_else(|e| panic!("{}", e))
+        .map(|format| format.to_string());
+
+    let data = match data_format.as_deref() {
+        None if data_fields.is_empty() => quote! { () },
+        None => quote! { (#(self.#data_idents.clone(),)*) },
+        Some("bare") => {
+            assert!(
+                data_fields.len() == 1,
+                "#[event_data(bare)] requires exactly one #[data] field"
+            );
+            quote! { #(self.#data_idents.clone())* }
+        }
+        Some("vec") => {
+            assert!(
+                data_fields.is_empty(),
+                "#[event_data(vec)] can only be used without #[data] fields"
+            );
+            quote! { soroban_sdk::Vec::<soroban_sdk::Val>::new(env) }
+        }
+        Some(format) => panic!("unknown event data format `{}`", format),
+    };
+
+    quote! {
+        impl axelar_soroban_std::events::Event for #name {
+            fn topics(&self, env: &soroban_sdk::Env) -> impl soroban_sdk::Topics + core::fmt::Debug {
+                (soroban_sdk::Symbol::new(env, #event_name), #(self.#topic_idents.clone(),)*)
+            }
+
+            #[allow(unused_variables, clippy::unused_unit)]
+            fn data(&self, env: &soroban_sdk::Env) -> impl soroban_sdk::IntoVal<soroban_sdk::Env, soroban_sdk::Val> + core::fmt::Debug {
+                #data
             }
         }
-    }.into()
+
+        #[cfg(any(test, feature = "testutils"))]
+        axelar_soroban_std::impl_event_testutils!(
+            #name,
+            (soroban_sdk::Symbol #(, #topic_types)*),
+            (#(#data_types),*)
+        );
+    }
+    .into()
+}
+
+fn event_name(name: &Ident) -> String {
+    let name = name.to_string();
+
+    to_snake_case(name.strip_suffix("Event").unwrap_or(&name))
+}
+
+/// Generates typed accessors for a contract storage key enum.
+///
+/// Each variant must be annotated with its storage class (`#[instance]`, `#[persistent]` or `#[temporary]`)
+/// and either the type of its value (`#[value(Type)]`) or `#[status]` for keys that only mark presence.
+/// Variants with named fields are stored as tuple variants, so the fields become the accessor arguments.
+///
+/// For a `#[value(T)]` variant `Foo`, the functions `foo`, `try_foo`, `set_foo` and `remove_foo` are generated,
+/// and for a `#[status]` variant `Foo`, the functions `is_foo`, `set_foo` and `remove_foo`.
+/// Reading an existing entry or writing one extends its TTL according to the [`ttl`] policy of its storage class.
+///
+/// [`ttl`]: ../axelar_soroban_std/ttl/index.html
+///
+/// # Example
+/// ```rust
+/// # mod test {
+/// use axelar_soroban_std_derive::contractstorage;
+/// use soroban_sdk::{contract, contractimpl, Address, Env, String};
+///
+/// #[contractstorage]
+/// #[derive(Clone, Debug)]
+/// enum DataKey {
+///     #[instance]
+///     #[value(u64)]
+///     Counter,
+///
+///     #[persistent]
+///     #[value(String)]
+///     Message { sender: Address },
+///
+///     #[temporary]
+///     #[status]
+///     Seen { sender: Address },
+/// }
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn send(env: &Env, sender: Address, message: String) {
+///         set_counter(env, &(try_counter(env).unwrap_or(0) + 1));
+///         set_message(env, &sender, &message);
+///         set_seen(env, &sender);
+///     }
+///
+///     pub fn message(env: &Env, sender: Address) -> Option<String> {
+///         try_message(env, &sender)
+///     }
+///
+///     pub fn seen(env: &Env, sender: Address) -> bool {
+///         is_seen(env, &sender)
+///     }
+/// }
+/// # }
+/// ```
+#[proc_macro_attribute]
+pub fn contractstorage(_attr: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ItemEnum);
+
+    storage::contractstorage(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            snake_case.push('_');
+        }
+        snake_case.push(c.to_ascii_lowercase());
+    }
+
+    snake_case
 }
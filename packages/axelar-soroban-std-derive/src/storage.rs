@@ -0,0 +1,245 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Error, Fields, Ident, ItemEnum, Type, Variant};
+
+use crate::to_snake_case;
+
+enum StorageClass {
+    Instance,
+    Persistent,
+    Temporary,
+}
+
+enum StorageValue {
+    Value(Type),
+    Status,
+}
+
+struct StorageVariant {
+    name: Ident,
+    class: StorageClass,
+    value: StorageValue,
+    field_names: Vec<Ident>,
+    field_types: Vec<Type>,
+}
+
+pub fn contractstorage(mut input: ItemEnum) -> Result<TokenStream, Error> {
+    let variants = input
+        .variants
+        .iter_mut()
+        .map(storage_variant)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let key_type = &input.ident;
+    let vis = &input.vis;
+    let accessors = variants
+        .iter()
+        .map(|variant| storage_accessors(key_type, vis, variant));
+
+    Ok(quote! {
+        #[soroban_sdk::contracttype]
+        #input
+
+        #(#accessors)*
+    })
+}
+
+/// Strips the storage attributes from the variant and turns its named fields into tuple fields,
+/// so the key is encoded the same way as an equivalent tuple variant.
+fn storage_variant(variant: &mut Variant) -> Result<StorageVariant, Error> {
+    let class = storage_class(variant)?;
+    let value = storage_value(variant)?;
+
+    variant
+        .attrs
+        .retain(|attr| !is_storage_attr(attr) && !is_value_attr(attr));
+
+    let (field_names, field_types) = match &variant.fields {
+        Fields::Unit => (Vec::new(), Vec::new()),
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                (
+                    field.ident.clone().expect("fields are named"),
+                    field.ty.clone(),
+                )
+            })
+            .unzip(),
+        Fields::Unnamed(_) => {
+            return Err(Error::new_spanned(
+                &variant.ident,
+                "storage keys must be unit variants or have named fields",
+            ))
+        }
+    };
+
+    if let Fields::Named(fields) = &mut variant.fields {
+        for field in fields.named.iter_mut() {
+            field.ident = None;
+            field.colon_token = None;
+        }
+        variant.fields = Fields::Unnamed(syn::FieldsUnnamed {
+            paren_token: syn::token::Paren::default(),
+            unnamed: fields.named.clone(),
+        });
+    }
+
+    Ok(StorageVariant {
+        name: variant.ident.clone(),
+        class,
+        value,
+        field_names,
+        field_types,
+    })
+}
+
+fn storage_class(variant: &Variant) -> Result<StorageClass, Error> {
+    let classes = variant
+        .attrs
+        .iter()
+        .filter(|attr| is_storage_attr(attr))
+        .collect::<Vec<_>>();
+
+    match classes.as_slice() {
+        [attr] if attr.path().is_ident("instance") => Ok(StorageClass::Instance),
+        [attr] if attr.path().is_ident("persistent") => Ok(StorageClass::Persistent),
+        [attr] if attr.path().is_ident("temporary") => Ok(StorageClass::Temporary),
+        _ => Err(Error::new_spanned(
+            &variant.ident,
+            "storage key must be annotated with exactly one of #[instance], #[persistent] or #[temporary]",
+        )),
+    }
+}
+
+fn storage_value(variant: &Variant) -> Result<StorageValue, Error> {
+    let values = variant
+        .attrs
+        .iter()
+        .filter(|attr| is_value_attr(attr))
+        .collect::<Vec<_>>();
+
+    match values.as_slice() {
+        [attr] if attr.path().is_ident("value") => attr.parse_args().map(StorageValue::Value),
+        [attr] if attr.path().is_ident("status") => Ok(StorageValue::Status),
+        _ => Err(Error::new_spanned(
+            &variant.ident,
+            "storage key must be annotated with exactly one of #[value(Type)] or #[status]",
+        )),
+    }
+}
+
+fn is_storage_attr(attr: &Attribute) -> bool {
+    ["instance", "persistent", "temporary"]
+        .iter()
+        .any(|class| attr.path().is_ident(class))
+}
+
+fn is_value_attr(attr: &Attribute) -> bool {
+    attr.path().is_ident("value") || attr.path().is_ident("status")
+}
+
+fn storage_accessors(
+    key_type: &Ident,
+    vis: &syn::Visibility,
+    variant: &StorageVariant,
+) -> TokenStream {
+    let StorageVariant {
+        name,
+        class,
+        value,
+        field_names,
+        field_types,
+    } = variant;
+
+    let fn_name = to_snake_case(&name.to_string());
+    let params = quote! { env: &soroban_sdk::Env #(, #field_names: &#field_types)* };
+    let key = if field_names.is_empty() {
+        quote! { #key_type::#name }
+    } else {
+        quote! { #key_type::#name(#(#field_names.clone()),*) }
+    };
+
+    let storage = match class {
+        StorageClass::Instance => quote! { env.storage().instance() },
+        StorageClass::Persistent => quote! { env.storage().persistent() },
+        StorageClass::Temporary => quote! { env.storage().temporary() },
+    };
+    let extend_ttl = match class {
+        StorageClass::Instance => quote! { axelar_soroban_std::ttl::extend_instance_ttl(env); },
+        StorageClass::Persistent => {
+            quote! { axelar_soroban_std::ttl::extend_persistent_ttl(env, &key); }
+        }
+        StorageClass::Temporary => {
+            quote! { axelar_soroban_std::ttl::extend_temporary_ttl(env, &key); }
+        }
+    };
+
+    match value {
+        StorageValue::Value(value_type) => {
+            let getter = format_ident!("{}", fn_name);
+            let try_getter = format_ident!("try_{}", fn_name);
+            let setter = format_ident!("set_{}", fn_name);
+            let remover = format_ident!("remove_{}", fn_name);
+            let not_found = format!("{fn_name} not found");
+
+            quote! {
+                #[allow(dead_code)]
+                #vis fn #try_getter(#params) -> Option<#value_type> {
+                    let key = #key;
+                    let value = #storage.get::<_, #value_type>(&key);
+                    if value.is_some() {
+                        #extend_ttl
+                    }
+                    value
+                }
+
+                #[allow(dead_code)]
+                #vis fn #getter(#params) -> #value_type {
+                    #try_getter(env #(, #field_names)*).expect(#not_found)
+                }
+
+                #[allow(dead_code)]
+                #vis fn #setter(#params, value: &#value_type) {
+                    let key = #key;
+                    #storage.set(&key, value);
+                    #extend_ttl
+                }
+
+                #[allow(dead_code)]
+                #vis fn #remover(#params) {
+                    #storage.remove(&#key);
+                }
+            }
+        }
+        StorageValue::Status => {
+            let getter = format_ident!("is_{}", fn_name);
+            let setter = format_ident!("set_{}", fn_name);
+            let remover = format_ident!("remove_{}", fn_name);
+
+            quote! {
+                #[allow(dead_code)]
+                #vis fn #getter(#params) -> bool {
+                    let key = #key;
+                    let is_set = #storage.has(&key);
+                    if is_set {
+                        #extend_ttl
+                    }
+                    is_set
+                }
+
+                #[allow(dead_code)]
+                #vis fn #setter(#params) {
+                    let key = #key;
+                    #storage.set(&key, &());
+                    #extend_ttl
+                }
+
+                #[allow(dead_code)]
+                #vis fn #remover(#params) {
+                    #storage.remove(&#key);
+                }
+            }
+        }
+    }
+}
@@ -34,7 +34,10 @@ mod operatable {
         assert_eq!(operator, client.operator());
 
         let new_operator = Address::generate(&env);
-        assert_invoke_auth_ok!(operator, client.try_transfer_operatorship(&new_operator));
+        assert_invoke_auth_ok!(operator, client.try_propose_operatorship(&new_operator));
+        assert_eq!(operator, client.operator());
+
+        assert_invoke_auth_ok!(new_operator, client.try_accept_operatorship());
         assert_eq!(new_operator, client.operator());
     }
 }
@@ -72,11 +75,85 @@ mod ownable {
         assert_eq!(owner, client.owner());
 
         let new_owner = Address::generate(&env);
-        assert_invoke_auth_ok!(owner, client.try_transfer_ownership(&new_owner));
+        assert_invoke_auth_ok!(owner, client.try_propose_ownership(&new_owner));
+        assert_eq!(owner, client.owner());
+
+        assert_invoke_auth_ok!(new_owner, client.try_accept_ownership());
         assert_eq!(new_owner, client.owner());
     }
 }
 
+mod pausable {
+    use axelar_soroban_std::{
+        assert_contract_err, assert_invoke_auth_err, assert_invoke_auth_ok,
+        interfaces::PausableClient, when_not_paused,
+    };
+    use axelar_soroban_std_derive::{Operatable, Ownable, Pausable};
+
+    use super::*;
+
+    #[contracterror]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+    #[repr(u32)]
+    pub enum ContractError {
+        ContractPaused = 1,
+    }
+
+    #[contract]
+    #[derive(Ownable, Operatable, Pausable)]
+    #[pausable(by = operator)]
+    pub struct Contract;
+
+    #[contractimpl]
+    impl Contract {
+        pub fn __constructor(env: &Env, owner: Address, operator: Address) {
+            axelar_soroban_std::interfaces::set_owner(env, &owner);
+            axelar_soroban_std::interfaces::set_operator(env, &operator);
+        }
+
+        pub fn ping(env: &Env) -> Result<u32, ContractError> {
+            when_not_paused!(env);
+
+            Ok(env.ledger().sequence())
+        }
+    }
+
+    #[test]
+    fn contract_pause_by_operator_succeeds() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let operator = Address::generate(&env);
+        let contract_id = env.register(Contract, (owner.clone(), operator.clone()));
+        let client = PausableClient::new(&env, &contract_id);
+        assert!(!client.paused());
+
+        assert_invoke_auth_err!(owner, client.try_pause());
+        assert_invoke_auth_ok!(operator, client.try_pause());
+        assert!(client.paused());
+
+        assert_invoke_auth_ok!(operator, client.try_unpause());
+        assert!(!client.paused());
+    }
+
+    #[test]
+    fn when_not_paused_guard_fails_if_paused() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let operator = Address::generate(&env);
+        let contract_id = env.register(Contract, (owner, operator.clone()));
+        let client = ContractClient::new(&env, &contract_id);
+        let pausable_client = PausableClient::new(&env, &contract_id);
+
+        assert!(client.try_ping().is_ok());
+
+        assert_invoke_auth_ok!(operator, pausable_client.try_pause());
+        assert_contract_err!(client.try_ping(), ContractError::ContractPaused);
+
+        assert_invoke_auth_ok!(operator, pausable_client.try_unpause());
+        assert!(client.try_ping().is_ok());
+    }
+}
+
 mod upgradable {
     use axelar_soroban_std::assert_invoke_auth_ok;
     use axelar_soroban_std_derive::{Ownable, Upgradable};
@@ -131,3 +208,240 @@ mod upgradable {
         assert_invoke_auth_ok!(owner, client.try_migrate(&()));
     }
 }
+
+mod into_event {
+    use axelar_soroban_std::events::Event;
+    use axelar_soroban_std_derive::IntoEvent;
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::{Bytes, String, TryFromVal, Val, Vec};
+
+    use super::*;
+
+    #[contract]
+    pub struct Contract;
+
+    #[derive(Debug, PartialEq, Eq, IntoEvent)]
+    pub struct NoDataEvent {
+        #[topic]
+        pub sender: Address,
+    }
+
+    #[derive(Debug, PartialEq, Eq, IntoEvent)]
+    #[event_data(vec)]
+    pub struct EmptyVecDataEvent {
+        #[topic]
+        pub sender: Address,
+    }
+
+    #[derive(Debug, PartialEq, Eq, IntoEvent)]
+    pub struct TupleDataEvent {
+        #[topic]
+        pub sender: Address,
+        #[data]
+        pub message: String,
+        #[data]
+        pub payload: Bytes,
+    }
+
+    #[derive(Debug, PartialEq, Eq, IntoEvent)]
+    #[event_data(bare)]
+    pub struct BareDataEvent {
+        #[topic]
+        pub sender: Address,
+        #[data]
+        pub payload: Bytes,
+    }
+
+    fn emitted_data(env: &Env, event: impl Event) -> Val {
+        let contract_id = env.register(Contract, ());
+        env.as_contract(&contract_id, || event.emit(env));
+
+        env.events().all().last().unwrap().2
+    }
+
+    #[test]
+    fn event_data_formats() {
+        let env = Env::default();
+        let sender = Address::generate(&env);
+        let payload = Bytes::from_array(&env, &[1, 2, 3]);
+
+        let data = emitted_data(
+            &env,
+            NoDataEvent {
+                sender: sender.clone(),
+            },
+        );
+        assert!(data.is_void());
+
+        let data = emitted_data(
+            &env,
+            EmptyVecDataEvent {
+                sender: sender.clone(),
+            },
+        );
+        assert!(Vec::<Val>::try_from_val(&env, &data).unwrap().is_empty());
+
+        let data = emitted_data(
+            &env,
+            TupleDataEvent {
+                sender: sender.clone(),
+                message: String::from_str(&env, "message"),
+                payload: payload.clone(),
+            },
+        );
+        assert_eq!(
+            <(String, Bytes)>::try_from_val(&env, &data).unwrap(),
+            (String::from_str(&env, "message"), payload.clone())
+        );
+
+        let data = emitted_data(
+            &env,
+            BareDataEvent {
+                sender,
+                payload: payload.clone(),
+            },
+        );
+        assert_eq!(Bytes::try_from_val(&env, &data).unwrap(), payload);
+    }
+}
+
+mod contractstorage {
+    use axelar_soroban_std::ttl::{
+        INSTANCE_TTL_EXTEND_TO, PERSISTENT_TTL_EXTEND_TO, TEMPORARY_TTL_EXTEND_TO,
+    };
+    use axelar_soroban_std_derive::contractstorage;
+    use soroban_sdk::testutils::storage::{Instance as _, Persistent as _, Temporary as _};
+    use soroban_sdk::{contracttype, String};
+
+    use super::*;
+
+    #[contract]
+    pub struct Contract;
+
+    #[contractstorage]
+    #[derive(Clone, Debug)]
+    enum DataKey {
+        #[instance]
+        #[value(u64)]
+        Counter,
+
+        #[persistent]
+        #[value(String)]
+        Message { sender: Address, nonce: u64 },
+
+        #[temporary]
+        #[status]
+        Seen { sender: Address },
+    }
+
+    /// The tuple variant encoding that the named field variants of `DataKey` must match.
+    #[contracttype]
+    enum TupleDataKey {
+        Message(Address, u64),
+        Seen(Address),
+    }
+
+    #[test]
+    fn value_accessors_store_and_remove_values() {
+        let env = Env::default();
+        let contract_id = env.register(Contract, ());
+        let sender = Address::generate(&env);
+        let value = String::from_str(&env, "message");
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(try_counter(&env), None);
+            assert_eq!(try_message(&env, &sender, &1), None);
+
+            set_counter(&env, &5);
+            set_message(&env, &sender, &1, &value);
+
+            assert_eq!(counter(&env), 5);
+            assert_eq!(message(&env, &sender, &1), value.clone());
+            assert_eq!(try_message(&env, &sender, &2), None);
+
+            remove_counter(&env);
+            remove_message(&env, &sender, &1);
+
+            assert_eq!(try_counter(&env), None);
+            assert_eq!(try_message(&env, &sender, &1), None);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "counter not found")]
+    fn value_getter_panics_if_missing() {
+        let env = Env::default();
+        let contract_id = env.register(Contract, ());
+
+        env.as_contract(&contract_id, || counter(&env));
+    }
+
+    #[test]
+    fn status_accessors_set_and_remove_status() {
+        let env = Env::default();
+        let contract_id = env.register(Contract, ());
+        let sender = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            assert!(!is_seen(&env, &sender));
+
+            set_seen(&env, &sender);
+            assert!(is_seen(&env, &sender));
+            assert!(!is_seen(&env, &Address::generate(&env)));
+
+            remove_seen(&env, &sender);
+            assert!(!is_seen(&env, &sender));
+        });
+    }
+
+    #[test]
+    fn named_fields_are_encoded_as_tuple_variants() {
+        let env = Env::default();
+        let contract_id = env.register(Contract, ());
+        let sender = Address::generate(&env);
+        let value = String::from_str(&env, "message");
+
+        env.as_contract(&contract_id, || {
+            set_message(&env, &sender, &1, &value);
+            set_seen(&env, &sender);
+
+            assert_eq!(
+                env.storage()
+                    .persistent()
+                    .get::<_, String>(&TupleDataKey::Message(sender.clone(), 1)),
+                Some(value)
+            );
+            assert!(env
+                .storage()
+                .temporary()
+                .has(&TupleDataKey::Seen(sender.clone())));
+        });
+    }
+
+    #[test]
+    fn setters_extend_ttl_by_storage_class() {
+        let env = Env::default();
+        let contract_id = env.register(Contract, ());
+        let sender = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            set_counter(&env, &1);
+            set_message(&env, &sender, &1, &String::from_str(&env, "message"));
+            set_seen(&env, &sender);
+
+            assert_eq!(env.storage().instance().get_ttl(), INSTANCE_TTL_EXTEND_TO);
+            assert_eq!(
+                env.storage()
+                    .persistent()
+                    .get_ttl(&TupleDataKey::Message(sender.clone(), 1)),
+                PERSISTENT_TTL_EXTEND_TO
+            );
+            assert_eq!(
+                env.storage()
+                    .temporary()
+                    .get_ttl(&TupleDataKey::Seen(sender.clone())),
+                TEMPORARY_TTL_EXTEND_TO
+            );
+        });
+    }
+}
@@ -60,7 +60,7 @@ mod testutils {
                 fn matches(&self, env: &soroban_sdk::Env, event: &(soroban_sdk::Address, soroban_sdk::Vec<soroban_sdk::Val>, soroban_sdk::Val)) -> bool {
                     use soroban_sdk::IntoVal;
 
-                    Self::standardized_fmt(env, event) == Self::standardized_fmt(env, &(event.0.clone(), self.topics(env).into_val(env), self.data(env).into_val(env)))
+                    Self::standardized_fmt(env, event) == Self::standardized_fmt(env, &(event.0.clone(), $crate::events::Event::topics(self, env).into_val(env), $crate::events::Event::data(self, env).into_val(env)))
                 }
 
                 #[allow(unused_assignments)]
@@ -80,7 +80,12 @@ mod testutils {
                         i += 1;
                     )*
 
-                    let data = soroban_sdk::Vec::<soroban_sdk::Val>::try_from_val(env, data).expect("data should be defined as a vector-compatible type");
+                    // events without data emit void, and events with a bare data value emit it outside of a vector
+                    let data = if data.is_void() {
+                        soroban_sdk::Vec::<soroban_sdk::Val>::new(env)
+                    } else {
+                        soroban_sdk::Vec::<soroban_sdk::Val>::try_from_val(env, data).unwrap_or_else(|_| soroban_sdk::vec![env, *data])
+                    };
 
                     let mut data_output: std::vec::Vec<std::string::String> = std::vec![];
 
@@ -0,0 +1,17 @@
+use soroban_sdk::{panic_with_error, Env};
+
+/// Runs `execute` only if `validation` succeeded, and panics with the error of the failing step otherwise.
+///
+/// This is the validate-then-dispatch step shared by the entrypoints of executable apps,
+/// e.g. the ones generated by `impl_axelar_executable!` and `impl_interchain_token_executable!`.
+pub fn execute_validated<V, E>(
+    env: &Env,
+    validation: Result<(), V>,
+    execute: impl FnOnce() -> Result<(), E>,
+) where
+    V: Into<soroban_sdk::Error>,
+    E: Into<soroban_sdk::Error>,
+{
+    validation.unwrap_or_else(|err| panic_with_error!(env, err));
+    execute().unwrap_or_else(|err| panic_with_error!(env, err));
+}
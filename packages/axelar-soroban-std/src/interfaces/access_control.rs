@@ -0,0 +1,393 @@
+use crate::ensure;
+use crate::events::Event;
+use crate::interfaces::{storage, OwnableInterface};
+use crate::ttl::extend_persistent_ttl;
+use axelar_soroban_std_derive::IntoEvent;
+use soroban_sdk::{contractclient, Address, Env, Symbol};
+
+#[contractclient(name = "AccessControlClient")]
+pub trait AccessControlInterface: OwnableInterface {
+    /// Returns whether `account` has been granted `role`.
+    fn has_role(env: &Env, role: Symbol, account: Address) -> bool;
+
+    /// Returns the role that administers `role`. If no admin role is set, `role` is administered by the contract's owner.
+    fn role_admin(env: &Env, role: Symbol) -> Option<Symbol>;
+
+    /// Grants `role` to `account`. `caller` must be authorized and hold the admin role of `role`.
+    fn grant_role(env: &Env, caller: Address, role: Symbol, account: Address);
+
+    /// Revokes `role` from `account`. `caller` must be authorized and hold the admin role of `role`.
+    fn revoke_role(env: &Env, caller: Address, role: Symbol, account: Address);
+
+    /// Revokes `role` from the authorized `account` itself.
+    fn renounce_role(env: &Env, account: Address, role: Symbol);
+
+    /// Sets the role that administers `role`. Pass `None` to hand the administration back to the owner.
+    fn set_role_admin(env: &Env, role: Symbol, admin_role: Option<Symbol>);
+}
+
+/// Default implementation of the [AccessControlInterface] trait.
+///
+/// Role memberships are kept in persistent storage, one entry per role and account, and their TTL is extended when checked.
+pub fn has_role(env: &Env, role: &Symbol, account: &Address) -> bool {
+    let key = storage::access_control::DataKey::Interfaces_Role(role.clone(), account.clone());
+    let has_role = env.storage().persistent().has(&key);
+
+    if has_role {
+        extend_persistent_ttl(env, &key);
+    }
+
+    has_role
+}
+
+/// Default implementation of the [AccessControlInterface] trait.
+pub fn role_admin(env: &Env, role: &Symbol) -> Option<Symbol> {
+    env.storage()
+        .instance()
+        .get(&storage::access_control::DataKey::Interfaces_RoleAdmin(
+            role.clone(),
+        ))
+}
+
+/// Default implementation of the [AccessControlInterface] trait. Ensures the caller is authorized and allowed to administer the role,
+/// and emits an event if the role was granted.
+pub fn grant_role<T: OwnableInterface>(
+    env: &Env,
+    caller: Address,
+    role: Symbol,
+    account: Address,
+) -> Result<(), AccessControlError> {
+    ensure_role_admin::<T>(env, &caller, &role)?;
+
+    if !has_role(env, &role, &account) {
+        set_role(env, &role, &account);
+
+        RoleGrantedEvent {
+            role,
+            account,
+            sender: caller,
+        }
+        .emit(env);
+    }
+
+    Ok(())
+}
+
+/// Default implementation of the [AccessControlInterface] trait. Ensures the caller is authorized and allowed to administer the role,
+/// and emits an event if the role was revoked.
+pub fn revoke_role<T: OwnableInterface>(
+    env: &Env,
+    caller: Address,
+    role: Symbol,
+    account: Address,
+) -> Result<(), AccessControlError> {
+    ensure_role_admin::<T>(env, &caller, &role)?;
+
+    remove_role(env, role, account, caller);
+
+    Ok(())
+}
+
+/// Default implementation of the [AccessControlInterface] trait. Ensures the account is authorized
+/// and emits an event if the role was revoked.
+pub fn renounce_role(env: &Env, account: Address, role: Symbol) {
+    account.require_auth();
+
+    remove_role(env, role, account.clone(), account);
+}
+
+/// Default implementation of the [AccessControlInterface] trait. Ensures the owner is authorized and emits an event after the change.
+pub fn set_role_admin<T: OwnableInterface>(env: &Env, role: Symbol, admin_role: Option<Symbol>) {
+    T::owner(env).require_auth();
+
+    let key = storage::access_control::DataKey::Interfaces_RoleAdmin(role.clone());
+    let previous_admin_role = role_admin(env, &role);
+
+    match &admin_role {
+        Some(admin_role) => env.storage().instance().set(&key, admin_role),
+        None => env.storage().instance().remove(&key),
+    }
+
+    RoleAdminChangedEvent {
+        role,
+        previous_admin_role,
+        new_admin_role: admin_role,
+    }
+    .emit(env);
+}
+
+/// Default implementation accompanying the [AccessControlInterface] trait. This should never be part of a contract interface,
+/// but allows contracts internally to grant roles, e.g. during construction.
+pub fn set_role(env: &Env, role: &Symbol, account: &Address) {
+    let key = storage::access_control::DataKey::Interfaces_Role(role.clone(), account.clone());
+
+    env.storage().persistent().set(&key, &());
+    extend_persistent_ttl(env, &key);
+}
+
+/// Default implementation accompanying the [AccessControlInterface] trait. This should never be part of a contract interface,
+/// but allows contracts internally to set the role that administers `role`, e.g. during construction.
+pub fn init_role_admin(env: &Env, role: &Symbol, admin_role: &Symbol) {
+    env.storage().instance().set(
+        &storage::access_control::DataKey::Interfaces_RoleAdmin(role.clone()),
+        admin_role,
+    );
+}
+
+/// Ensures `caller` is authorized and holds `role`.
+///
+/// A `ContractError` error type must be defined in scope, and have a `MissingRole` variant.
+#[macro_export]
+macro_rules! only_role {
+    ($env:expr, $caller:expr, $role:expr $(,)?) => {
+        $caller.require_auth();
+
+        $crate::ensure!(
+            $crate::interfaces::has_role($env, &$role, &$caller),
+            ContractError::MissingRole
+        );
+    };
+}
+
+fn ensure_role_admin<T: OwnableInterface>(
+    env: &Env,
+    caller: &Address,
+    role: &Symbol,
+) -> Result<(), AccessControlError> {
+    caller.require_auth();
+
+    let is_admin = role_admin(env, role).map_or_else(
+        || *caller == T::owner(env),
+        |admin_role| has_role(env, &admin_role, caller),
+    );
+    ensure!(is_admin, AccessControlError::MissingRole);
+
+    Ok(())
+}
+
+fn remove_role(env: &Env, role: Symbol, account: Address, sender: Address) {
+    if !has_role(env, &role, &account) {
+        return;
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&storage::access_control::DataKey::Interfaces_Role(
+            role.clone(),
+            account.clone(),
+        ));
+
+    RoleRevokedEvent {
+        role,
+        account,
+        sender,
+    }
+    .emit(env);
+}
+
+#[derive(Debug)]
+pub enum AccessControlError {
+    MissingRole,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct RoleGrantedEvent {
+    #[topic]
+    pub role: Symbol,
+    #[topic]
+    pub account: Address,
+    #[topic]
+    pub sender: Address,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct RoleRevokedEvent {
+    #[topic]
+    pub role: Symbol,
+    #[topic]
+    pub account: Address,
+    #[topic]
+    pub sender: Address,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+pub struct RoleAdminChangedEvent {
+    #[topic]
+    pub role: Symbol,
+    #[data]
+    pub previous_admin_role: Option<Symbol>,
+    #[data]
+    pub new_admin_role: Option<Symbol>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::interfaces::testdata::Contract;
+    use crate::interfaces::{
+        storage, AccessControlClient, RoleAdminChangedEvent, RoleGrantedEvent, RoleRevokedEvent,
+    };
+    use crate::{assert_invoke_auth_err, assert_invoke_auth_ok, events};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Address, Env, Symbol};
+
+    fn prepare_client(env: &Env, owner: Address) -> AccessControlClient {
+        let operator = Address::generate(env);
+        let contract_id = env.register(Contract, (Some(owner), Some(operator)));
+        AccessControlClient::new(env, &contract_id)
+    }
+
+    #[test]
+    fn has_role_returns_false_by_default() {
+        let env = Env::default();
+        let client = prepare_client(&env, Address::generate(&env));
+
+        let role = Symbol::new(&env, "pauser");
+        assert!(!client.has_role(&role, &Address::generate(&env)));
+        assert_eq!(client.role_admin(&role), None);
+    }
+
+    #[test]
+    fn grant_role_fails_if_caller_is_not_owner() {
+        let env = Env::default();
+        let client = prepare_client(&env, Address::generate(&env));
+
+        let user = Address::generate(&env);
+        let role = Symbol::new(&env, "pauser");
+        assert_invoke_auth_err!(user, client.try_grant_role(&user, &role, &user));
+        assert!(!client.has_role(&role, &user));
+    }
+
+    #[test]
+    fn grant_role_fails_if_caller_is_not_authenticated() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, owner.clone());
+
+        let user = Address::generate(&env);
+        let role = Symbol::new(&env, "pauser");
+        assert_invoke_auth_err!(user, client.try_grant_role(&owner, &role, &user));
+    }
+
+    #[test]
+    fn grant_role_succeeds_if_caller_is_owner() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, owner.clone());
+
+        let user = Address::generate(&env);
+        let role = Symbol::new(&env, "pauser");
+        assert_invoke_auth_ok!(owner, client.try_grant_role(&owner, &role, &user));
+
+        goldie::assert!(events::fmt_last_emitted_event::<RoleGrantedEvent>(&env));
+
+        assert!(client.has_role(&role, &user));
+    }
+
+    #[test]
+    fn grant_role_stores_membership_in_persistent_storage() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, owner.clone());
+
+        let user = Address::generate(&env);
+        let role = Symbol::new(&env, "pauser");
+        assert_invoke_auth_ok!(owner, client.try_grant_role(&owner, &role, &user));
+
+        let key = storage::access_control::DataKey::Interfaces_Role(role, user);
+        env.as_contract(&client.address, || {
+            assert!(env.storage().persistent().has(&key));
+            assert!(!env.storage().instance().has(&key));
+        });
+    }
+
+    #[test]
+    fn revoke_role_fails_if_caller_is_not_role_admin() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, owner.clone());
+
+        let user = Address::generate(&env);
+        let role = Symbol::new(&env, "pauser");
+        assert_invoke_auth_ok!(owner, client.try_grant_role(&owner, &role, &user));
+
+        assert_invoke_auth_err!(user, client.try_revoke_role(&user, &role, &user));
+        assert!(client.has_role(&role, &user));
+    }
+
+    #[test]
+    fn revoke_role_succeeds_if_caller_is_owner() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, owner.clone());
+
+        let user = Address::generate(&env);
+        let role = Symbol::new(&env, "pauser");
+        assert_invoke_auth_ok!(owner, client.try_grant_role(&owner, &role, &user));
+        assert_invoke_auth_ok!(owner, client.try_revoke_role(&owner, &role, &user));
+
+        goldie::assert!(events::fmt_last_emitted_event::<RoleRevokedEvent>(&env));
+
+        assert!(!client.has_role(&role, &user));
+    }
+
+    #[test]
+    fn renounce_role_succeeds_if_caller_is_account() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, owner.clone());
+
+        let user = Address::generate(&env);
+        let role = Symbol::new(&env, "pauser");
+        assert_invoke_auth_ok!(owner, client.try_grant_role(&owner, &role, &user));
+
+        assert_invoke_auth_err!(owner, client.try_renounce_role(&user, &role));
+        assert_invoke_auth_ok!(user, client.try_renounce_role(&user, &role));
+
+        assert!(!client.has_role(&role, &user));
+    }
+
+    #[test]
+    fn set_role_admin_fails_if_caller_is_not_owner() {
+        let env = Env::default();
+        let client = prepare_client(&env, Address::generate(&env));
+
+        let user = Address::generate(&env);
+        let role = Symbol::new(&env, "pauser");
+        let admin_role = Some(Symbol::new(&env, "pauser_admin"));
+        assert_invoke_auth_err!(user, client.try_set_role_admin(&role, &admin_role));
+    }
+
+    #[test]
+    fn role_admin_can_grant_and_revoke_role() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, owner.clone());
+
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let role = Symbol::new(&env, "pauser");
+        let admin_role = Symbol::new(&env, "pauser_admin");
+        let some_admin_role = Some(admin_role.clone());
+
+        assert_invoke_auth_ok!(owner, client.try_set_role_admin(&role, &some_admin_role));
+
+        goldie::assert!(events::fmt_last_emitted_event::<RoleAdminChangedEvent>(
+            &env
+        ));
+
+        assert_eq!(client.role_admin(&role), some_admin_role);
+
+        assert_invoke_auth_ok!(owner, client.try_grant_role(&owner, &admin_role, &admin));
+
+        // the owner no longer administers the role once an admin role is set
+        assert_invoke_auth_err!(owner, client.try_grant_role(&owner, &role, &user));
+
+        assert_invoke_auth_ok!(admin, client.try_grant_role(&admin, &role, &user));
+        assert!(client.has_role(&role, &user));
+
+        assert_invoke_auth_ok!(admin, client.try_revoke_role(&admin, &role, &user));
+        assert!(!client.has_role(&role, &user));
+    }
+}
@@ -1,30 +1,49 @@
+mod access_control;
 mod operatable;
 mod ownable;
+mod pausable;
 #[cfg(test)]
 mod testdata;
+mod ttl_configurable;
 mod upgradable;
+mod wasm_registry;
 
+pub use access_control::*;
 pub use operatable::*;
 pub use ownable::*;
+pub use pausable::*;
+pub use ttl_configurable::*;
 pub use upgradable::*;
+pub use wasm_registry::*;
 
 /// This submodule encapsulates data keys for the separate interfaces. These keys break naming conventions on purpose.
 /// If a contract implements a contract type that would result in a collision with a key defined here,
 /// the linter will complain about it. So as long as contracts follow regular naming conventions,
 /// there is no risk of collisions.
 mod storage {
-    #![allow(non_camel_case_types)]
+    #![allow(non_camel_case_types, clippy::enum_variant_names)]
 
     // add a separate module for each interface with a dedicated data key.
     // Using a single enum could lead to unintentionally breaks of unrelated interfaces,
     // because the key serialization is variant order dependent.
 
+    pub mod access_control {
+        use soroban_sdk::{contracttype, Address, Symbol};
+
+        #[contracttype]
+        pub enum DataKey {
+            Interfaces_Role(Symbol, Address),
+            Interfaces_RoleAdmin(Symbol),
+        }
+    }
+
     pub mod operator {
         use soroban_sdk::contracttype;
 
         #[contracttype]
         pub enum DataKey {
             Interfaces_Operator,
+            Interfaces_PendingOperator,
         }
     }
 
@@ -34,6 +53,34 @@ mod storage {
         #[contracttype]
         pub enum DataKey {
             Interfaces_Owner,
+            Interfaces_PendingOwner,
+        }
+    }
+
+    pub mod paused {
+        use soroban_sdk::contracttype;
+
+        #[contracttype]
+        pub enum DataKey {
+            Interfaces_Paused,
+        }
+    }
+
+    pub mod ttl_config {
+        use soroban_sdk::contracttype;
+
+        #[contracttype]
+        pub enum DataKey {
+            Interfaces_TtlConfig,
+        }
+    }
+
+    pub mod wasm_registry {
+        use soroban_sdk::contracttype;
+
+        #[contracttype]
+        pub enum DataKey {
+            Interfaces_WasmRegistry,
         }
     }
 
@@ -43,6 +90,10 @@ mod storage {
         #[contracttype]
         pub enum DataKey {
             Interfaces_Migrating,
+            Interfaces_SchemaVersion,
+            Interfaces_UpgradeWasmHash,
+            Interfaces_VersionHistoryLen,
+            Interfaces_VersionHistoryEntry(u32),
         }
     }
 }
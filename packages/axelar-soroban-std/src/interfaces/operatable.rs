@@ -1,17 +1,24 @@
 use crate::events::Event;
-#[cfg(any(test, feature = "testutils"))]
-use crate::impl_event_testutils;
 use crate::interfaces::storage;
-use core::fmt::Debug;
-use soroban_sdk::{contractclient, Address, Env, IntoVal, Symbol, Topics, Val, Vec};
+use axelar_soroban_std_derive::IntoEvent;
+use soroban_sdk::{contractclient, Address, Env};
 
 #[contractclient(name = "OperatableClient")]
 pub trait OperatableInterface {
     /// Returns the address of the contract's operator.
     fn operator(env: &Env) -> Address;
 
-    /// Transfers operatorship of the contract to a new address.
-    fn transfer_operatorship(env: &Env, new_operator: Address);
+    /// Returns the address proposed to become the contract's operator, if any.
+    fn pending_operator(env: &Env) -> Option<Address>;
+
+    /// Proposes a new operator. Operatorship is only transferred once the proposed operator calls [accept_operatorship](Self::accept_operatorship).
+    fn propose_operatorship(env: &Env, new_operator: Address);
+
+    /// Accepts a pending operatorship proposal. Must be called by the proposed operator.
+    fn accept_operatorship(env: &Env);
+
+    /// Cancels a pending operatorship proposal.
+    fn cancel_operatorship_proposal(env: &Env);
 }
 
 /// Default implementation of the [OperatableInterface] trait.
@@ -22,55 +29,111 @@ pub fn operator(env: &Env) -> Address {
         .expect("operator must be set during contract construction")
 }
 
-/// Default implementation of the [OperatableInterface] trait. Ensures the current operator is authorized and emits an event after the transfer.
-pub fn transfer_operatorship<T: OperatableInterface>(env: &Env, new_operator: Address) {
+/// Default implementation of the [OperatableInterface] trait.
+pub fn pending_operator(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&storage::operator::DataKey::Interfaces_PendingOperator)
+}
+
+/// Default implementation of the [OperatableInterface] trait. Ensures the current operator is authorized and emits an event after the proposal.
+/// A previous proposal is overwritten.
+pub fn propose_operatorship<T: OperatableInterface>(env: &Env, new_operator: Address) {
     let current_operator = T::operator(env);
     current_operator.require_auth();
 
+    env.storage().instance().set(
+        &storage::operator::DataKey::Interfaces_PendingOperator,
+        &new_operator,
+    );
+
+    OperatorshipProposedEvent {
+        operator: current_operator,
+        pending_operator: new_operator,
+    }
+    .emit(env);
+}
+
+/// Default implementation of the [OperatableInterface] trait. Ensures the pending operator is authorized and emits an event after the transfer.
+pub fn accept_operatorship<T: OperatableInterface>(env: &Env) {
+    let new_operator = pending_operator(env).expect("no pending operator");
+    new_operator.require_auth();
+
+    let previous_operator = T::operator(env);
     set_operator(env, &new_operator);
 
     OperatorshipTransferredEvent {
-        previous_operator: current_operator,
+        previous_operator,
         new_operator,
     }
     .emit(env);
 }
 
+/// Default implementation of the [OperatableInterface] trait. Ensures the current operator is authorized and emits an event after the cancellation.
+pub fn cancel_operatorship_proposal<T: OperatableInterface>(env: &Env) {
+    let current_operator = T::operator(env);
+    current_operator.require_auth();
+
+    let pending_operator = pending_operator(env).expect("no pending operator");
+    remove_pending_operator(env);
+
+    OperatorshipProposalCancelledEvent {
+        operator: current_operator,
+        pending_operator,
+    }
+    .emit(env);
+}
+
 /// Default implementation accompanying the [OperatableInterface] trait. This should never be part of a contract interface,
 /// but allows contracts internally to set the operator.
 pub fn set_operator(env: &Env, operator: &Address) {
     env.storage()
         .instance()
         .set(&storage::operator::DataKey::Interfaces_Operator, operator);
+
+    remove_pending_operator(env);
+}
+
+fn remove_pending_operator(env: &Env) {
+    env.storage()
+        .instance()
+        .remove(&storage::operator::DataKey::Interfaces_PendingOperator);
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
 pub struct OperatorshipTransferredEvent {
+    #[topic]
     pub previous_operator: Address,
+    #[topic]
     pub new_operator: Address,
 }
 
-impl Event for OperatorshipTransferredEvent {
-    fn topics(&self, env: &Env) -> impl Topics + Debug {
-        (
-            Symbol::new(env, "operatorship_transferred"),
-            self.previous_operator.to_val(),
-            self.new_operator.to_val(),
-        )
-    }
-
-    fn data(&self, env: &Env) -> impl IntoVal<Env, Val> + Debug {
-        Vec::<Val>::new(env)
-    }
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct OperatorshipProposedEvent {
+    #[topic]
+    pub operator: Address,
+    #[topic]
+    pub pending_operator: Address,
 }
 
-#[cfg(any(test, feature = "testutils"))]
-impl_event_testutils!(OperatorshipTransferredEvent, (Symbol, Address, Address), ());
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct OperatorshipProposalCancelledEvent {
+    #[topic]
+    pub operator: Address,
+    #[topic]
+    pub pending_operator: Address,
+}
 
 #[cfg(test)]
 mod test {
     use crate::interfaces::testdata::Contract;
-    use crate::interfaces::{OperatableClient, OperatorshipTransferredEvent};
+    use crate::interfaces::{
+        OperatableClient, OperatorshipProposalCancelledEvent, OperatorshipProposedEvent,
+        OperatorshipTransferredEvent,
+    };
     use crate::{assert_invoke_auth_err, assert_invoke_auth_ok, events};
     use soroban_sdk::testutils::Address as _;
     use soroban_sdk::{Address, Env};
@@ -99,31 +162,99 @@ mod test {
     }
 
     #[test]
-    fn transfer_operatorship_fails_if_caller_is_not_operator() {
+    fn propose_operatorship_fails_if_caller_is_not_operator() {
         let env = Env::default();
         let operator = Address::generate(&env);
         let client = prepare_client(&env, Some(operator));
 
         let new_operator = Address::generate(&env);
-        assert_invoke_auth_err!(
-            new_operator,
-            client.try_transfer_operatorship(&new_operator)
-        );
+        assert_invoke_auth_err!(new_operator, client.try_propose_operatorship(&new_operator));
+        assert_eq!(client.pending_operator(), None);
+    }
+
+    #[test]
+    fn propose_operatorship_succeeds_if_caller_is_operator() {
+        let env = Env::default();
+        let operator = Address::generate(&env);
+        let client = prepare_client(&env, Some(operator.clone()));
+
+        let new_operator = Address::generate(&env);
+        assert_invoke_auth_ok!(operator, client.try_propose_operatorship(&new_operator));
+
+        goldie::assert!(events::fmt_last_emitted_event::<OperatorshipProposedEvent>(
+            &env
+        ));
+
+        assert_eq!(client.operator(), operator);
+        assert_eq!(client.pending_operator(), Some(new_operator));
     }
 
     #[test]
-    fn transfer_operatorship_succeeds_if_caller_is_operator() {
+    fn accept_operatorship_fails_if_caller_is_not_pending_operator() {
         let env = Env::default();
         let operator = Address::generate(&env);
         let client = prepare_client(&env, Some(operator.clone()));
 
+        let new_operator = Address::generate(&env);
+        assert_invoke_auth_ok!(operator, client.try_propose_operatorship(&new_operator));
+
+        assert_invoke_auth_err!(operator, client.try_accept_operatorship());
         assert_eq!(client.operator(), operator);
+    }
+
+    #[test]
+    fn accept_operatorship_fails_if_no_operatorship_proposed() {
+        let env = Env::default();
+        let operator = Address::generate(&env);
+        let client = prepare_client(&env, Some(operator.clone()));
+
+        assert_invoke_auth_err!(operator, client.try_accept_operatorship());
+    }
+
+    #[test]
+    fn accept_operatorship_succeeds_if_caller_is_pending_operator() {
+        let env = Env::default();
+        let operator = Address::generate(&env);
+        let client = prepare_client(&env, Some(operator.clone()));
 
         let new_operator = Address::generate(&env);
-        assert_invoke_auth_ok!(operator, client.try_transfer_operatorship(&new_operator));
+        assert_invoke_auth_ok!(operator, client.try_propose_operatorship(&new_operator));
+        assert_invoke_auth_ok!(new_operator, client.try_accept_operatorship());
 
         goldie::assert!(events::fmt_last_emitted_event::<OperatorshipTransferredEvent>(&env));
 
         assert_eq!(client.operator(), new_operator);
+        assert_eq!(client.pending_operator(), None);
+    }
+
+    #[test]
+    fn cancel_operatorship_proposal_fails_if_caller_is_not_operator() {
+        let env = Env::default();
+        let operator = Address::generate(&env);
+        let client = prepare_client(&env, Some(operator.clone()));
+
+        let new_operator = Address::generate(&env);
+        assert_invoke_auth_ok!(operator, client.try_propose_operatorship(&new_operator));
+
+        assert_invoke_auth_err!(new_operator, client.try_cancel_operatorship_proposal());
+        assert_eq!(client.pending_operator(), Some(new_operator));
+    }
+
+    #[test]
+    fn cancel_operatorship_proposal_succeeds_if_caller_is_operator() {
+        let env = Env::default();
+        let operator = Address::generate(&env);
+        let client = prepare_client(&env, Some(operator.clone()));
+
+        let new_operator = Address::generate(&env);
+        assert_invoke_auth_ok!(operator, client.try_propose_operatorship(&new_operator));
+        assert_invoke_auth_ok!(operator, client.try_cancel_operatorship_proposal());
+
+        goldie::assert!(events::fmt_last_emitted_event::<
+            OperatorshipProposalCancelledEvent,
+        >(&env));
+
+        assert_eq!(client.pending_operator(), None);
+        assert_invoke_auth_err!(new_operator, client.try_accept_operatorship());
     }
 }
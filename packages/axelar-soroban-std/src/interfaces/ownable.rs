@@ -1,17 +1,24 @@
 use crate::events::Event;
-#[cfg(any(test, feature = "testutils"))]
-use crate::impl_event_testutils;
 use crate::interfaces::storage;
-use core::fmt::Debug;
-use soroban_sdk::{contractclient, Address, Env, IntoVal, Symbol, Topics, Val, Vec};
+use axelar_soroban_std_derive::IntoEvent;
+use soroban_sdk::{contractclient, Address, Env};
 
 #[contractclient(name = "OwnableClient")]
 pub trait OwnableInterface {
     /// Returns the address of the contract's owner.
     fn owner(env: &Env) -> Address;
 
-    /// Transfers ownership of the contract to a new address.
-    fn transfer_ownership(env: &Env, new_owner: Address);
+    /// Returns the address proposed to become the contract's owner, if any.
+    fn pending_owner(env: &Env) -> Option<Address>;
+
+    /// Proposes a new owner. Ownership is only transferred once the proposed owner calls [accept_ownership](Self::accept_ownership).
+    fn propose_ownership(env: &Env, new_owner: Address);
+
+    /// Accepts a pending ownership proposal. Must be called by the proposed owner.
+    fn accept_ownership(env: &Env);
+
+    /// Cancels a pending ownership proposal.
+    fn cancel_ownership_proposal(env: &Env);
 }
 
 /// Default implementation of the [OwnableInterface] trait.
@@ -22,55 +29,111 @@ pub fn owner(env: &Env) -> Address {
         .expect("owner must be set during contract construction")
 }
 
-/// Default implementation of the [OwnableInterface] trait. Ensures the current owner is authorized and emits an event after the transfer.
-pub fn transfer_ownership<T: OwnableInterface>(env: &Env, new_owner: Address) {
+/// Default implementation of the [OwnableInterface] trait.
+pub fn pending_owner(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&storage::owner::DataKey::Interfaces_PendingOwner)
+}
+
+/// Default implementation of the [OwnableInterface] trait. Ensures the current owner is authorized and emits an event after the proposal.
+/// A previous proposal is overwritten.
+pub fn propose_ownership<T: OwnableInterface>(env: &Env, new_owner: Address) {
     let current_owner = T::owner(env);
     current_owner.require_auth();
 
+    env.storage().instance().set(
+        &storage::owner::DataKey::Interfaces_PendingOwner,
+        &new_owner,
+    );
+
+    OwnershipProposedEvent {
+        owner: current_owner,
+        pending_owner: new_owner,
+    }
+    .emit(env);
+}
+
+/// Default implementation of the [OwnableInterface] trait. Ensures the pending owner is authorized and emits an event after the transfer.
+pub fn accept_ownership<T: OwnableInterface>(env: &Env) {
+    let new_owner = pending_owner(env).expect("no pending owner");
+    new_owner.require_auth();
+
+    let previous_owner = T::owner(env);
     set_owner(env, &new_owner);
 
     OwnershipTransferredEvent {
-        previous_owner: current_owner,
+        previous_owner,
         new_owner,
     }
     .emit(env);
 }
 
+/// Default implementation of the [OwnableInterface] trait. Ensures the current owner is authorized and emits an event after the cancellation.
+pub fn cancel_ownership_proposal<T: OwnableInterface>(env: &Env) {
+    let current_owner = T::owner(env);
+    current_owner.require_auth();
+
+    let pending_owner = pending_owner(env).expect("no pending owner");
+    remove_pending_owner(env);
+
+    OwnershipProposalCancelledEvent {
+        owner: current_owner,
+        pending_owner,
+    }
+    .emit(env);
+}
+
 /// Default implementation accompanying the [OwnableInterface] trait. This should never be part of a contract interface,
 /// but allows contracts internally to set the owner.
 pub fn set_owner(env: &Env, owner: &Address) {
     env.storage()
         .instance()
         .set(&storage::owner::DataKey::Interfaces_Owner, owner);
+
+    remove_pending_owner(env);
+}
+
+fn remove_pending_owner(env: &Env) {
+    env.storage()
+        .instance()
+        .remove(&storage::owner::DataKey::Interfaces_PendingOwner);
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
 pub struct OwnershipTransferredEvent {
+    #[topic]
     pub previous_owner: Address,
+    #[topic]
     pub new_owner: Address,
 }
 
-impl Event for OwnershipTransferredEvent {
-    fn topics(&self, env: &Env) -> impl Topics + Debug {
-        (
-            Symbol::new(env, "ownership_transferred"),
-            self.previous_owner.to_val(),
-            self.new_owner.to_val(),
-        )
-    }
-
-    fn data(&self, env: &Env) -> impl IntoVal<Env, Val> + Debug {
-        Vec::<Val>::new(env)
-    }
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct OwnershipProposedEvent {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub pending_owner: Address,
 }
 
-#[cfg(any(test, feature = "testutils"))]
-impl_event_testutils!(OwnershipTransferredEvent, (Symbol, Address, Address), ());
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct OwnershipProposalCancelledEvent {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub pending_owner: Address,
+}
 
 #[cfg(test)]
 mod test {
     use crate::interfaces::testdata::Contract;
-    use crate::interfaces::{OwnableClient, OwnershipTransferredEvent};
+    use crate::interfaces::{
+        OwnableClient, OwnershipProposalCancelledEvent, OwnershipProposedEvent,
+        OwnershipTransferredEvent,
+    };
     use crate::{assert_invoke_auth_err, assert_invoke_auth_ok, events};
     use soroban_sdk::testutils::Address as _;
     use soroban_sdk::{Address, Env};
@@ -99,30 +162,101 @@ mod test {
     }
 
     #[test]
-    fn transfer_ownership_fails_if_caller_is_not_owner() {
+    fn propose_ownership_fails_if_caller_is_not_owner() {
         let env = Env::default();
         let owner = Address::generate(&env);
         let client = prepare_client(&env, Some(owner));
 
         let new_owner = Address::generate(&env);
-        assert_invoke_auth_err!(new_owner, client.try_transfer_ownership(&new_owner));
+        assert_invoke_auth_err!(new_owner, client.try_propose_ownership(&new_owner));
+        assert_eq!(client.pending_owner(), None);
+    }
+
+    #[test]
+    fn propose_ownership_succeeds_if_caller_is_owner() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, Some(owner.clone()));
+
+        let new_owner = Address::generate(&env);
+        assert_invoke_auth_ok!(owner, client.try_propose_ownership(&new_owner));
+
+        goldie::assert!(events::fmt_last_emitted_event::<OwnershipProposedEvent>(
+            &env
+        ));
+
+        assert_eq!(client.owner(), owner);
+        assert_eq!(client.pending_owner(), Some(new_owner));
     }
 
     #[test]
-    fn transfer_ownership_succeeds_if_caller_is_owner() {
+    fn accept_ownership_fails_if_caller_is_not_pending_owner() {
         let env = Env::default();
         let owner = Address::generate(&env);
         let client = prepare_client(&env, Some(owner.clone()));
 
+        let new_owner = Address::generate(&env);
+        assert_invoke_auth_ok!(owner, client.try_propose_ownership(&new_owner));
+
+        assert_invoke_auth_err!(owner, client.try_accept_ownership());
         assert_eq!(client.owner(), owner);
+    }
+
+    #[test]
+    fn accept_ownership_fails_if_no_ownership_proposed() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, Some(owner.clone()));
+
+        assert_invoke_auth_err!(owner, client.try_accept_ownership());
+    }
+
+    #[test]
+    fn accept_ownership_succeeds_if_caller_is_pending_owner() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, Some(owner.clone()));
 
         let new_owner = Address::generate(&env);
-        assert_invoke_auth_ok!(owner, client.try_transfer_ownership(&new_owner));
+        assert_invoke_auth_ok!(owner, client.try_propose_ownership(&new_owner));
+        assert_invoke_auth_ok!(new_owner, client.try_accept_ownership());
 
         goldie::assert!(events::fmt_last_emitted_event::<OwnershipTransferredEvent>(
             &env
         ));
 
         assert_eq!(client.owner(), new_owner);
+        assert_eq!(client.pending_owner(), None);
+    }
+
+    #[test]
+    fn cancel_ownership_proposal_fails_if_caller_is_not_owner() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, Some(owner.clone()));
+
+        let new_owner = Address::generate(&env);
+        assert_invoke_auth_ok!(owner, client.try_propose_ownership(&new_owner));
+
+        assert_invoke_auth_err!(new_owner, client.try_cancel_ownership_proposal());
+        assert_eq!(client.pending_owner(), Some(new_owner));
+    }
+
+    #[test]
+    fn cancel_ownership_proposal_succeeds_if_caller_is_owner() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, Some(owner.clone()));
+
+        let new_owner = Address::generate(&env);
+        assert_invoke_auth_ok!(owner, client.try_propose_ownership(&new_owner));
+        assert_invoke_auth_ok!(owner, client.try_cancel_ownership_proposal());
+
+        goldie::assert!(events::fmt_last_emitted_event::<
+            OwnershipProposalCancelledEvent,
+        >(&env));
+
+        assert_eq!(client.pending_owner(), None);
+        assert_invoke_auth_err!(new_owner, client.try_accept_ownership());
     }
 }
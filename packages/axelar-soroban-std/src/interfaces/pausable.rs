@@ -0,0 +1,142 @@
+use crate::events::Event;
+use crate::interfaces::storage;
+use axelar_soroban_std_derive::IntoEvent;
+use soroban_sdk::{contractclient, Address, Env};
+
+#[contractclient(name = "PausableClient")]
+pub trait PausableInterface {
+    /// Returns whether the contract is currently paused.
+    fn paused(env: &Env) -> bool;
+
+    /// Pauses the contract. Entrypoints guarded by [when_not_paused!](crate::when_not_paused) will fail until the contract is unpaused.
+    fn pause(env: &Env);
+
+    /// Unpauses the contract.
+    fn unpause(env: &Env);
+}
+
+/// Default implementation of the [PausableInterface] trait.
+pub fn paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .has(&storage::paused::DataKey::Interfaces_Paused)
+}
+
+/// Default implementation of the [PausableInterface] trait. Ensures the given authority (usually the owner or operator)
+/// is authorized and emits an event after pausing the contract.
+pub fn pause(env: &Env, authority: Address) {
+    authority.require_auth();
+
+    env.storage()
+        .instance()
+        .set(&storage::paused::DataKey::Interfaces_Paused, &());
+
+    PausedEvent {}.emit(env);
+}
+
+/// Default implementation of the [PausableInterface] trait. Ensures the given authority (usually the owner or operator)
+/// is authorized and emits an event after unpausing the contract.
+pub fn unpause(env: &Env, authority: Address) {
+    authority.require_auth();
+
+    env.storage()
+        .instance()
+        .remove(&storage::paused::DataKey::Interfaces_Paused);
+
+    UnpausedEvent {}.emit(env);
+}
+
+/// Return with a `ContractError::ContractPaused` error if the contract is paused.
+///
+/// A `ContractError` error type must be defined in scope, and have a `ContractPaused` variant.
+#[macro_export]
+macro_rules! when_not_paused {
+    ($env:expr $(,)?) => {
+        $crate::ensure!(
+            !$crate::interfaces::paused($env),
+            ContractError::ContractPaused
+        );
+    };
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct PausedEvent {}
+
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+#[event_data(vec)]
+pub struct UnpausedEvent {}
+
+#[cfg(test)]
+mod test {
+    use crate::interfaces::testdata::Contract;
+    use crate::interfaces::{PausableClient, PausedEvent, UnpausedEvent};
+    use crate::{assert_invoke_auth_err, assert_invoke_auth_ok, events};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Address, Env};
+
+    fn prepare_client(env: &Env, owner: Option<Address>) -> PausableClient {
+        let operator = Address::generate(env);
+        let contract_id = env.register(Contract, (owner, operator));
+        PausableClient::new(env, &contract_id)
+    }
+
+    #[test]
+    fn paused_returns_false_by_default() {
+        let env = Env::default();
+        let client = prepare_client(&env, Some(Address::generate(&env)));
+
+        assert!(!client.paused());
+    }
+
+    #[test]
+    fn pause_fails_if_caller_is_not_owner() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, Some(owner));
+
+        let user = Address::generate(&env);
+        assert_invoke_auth_err!(user, client.try_pause());
+        assert!(!client.paused());
+    }
+
+    #[test]
+    fn pause_succeeds_if_caller_is_owner() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, Some(owner.clone()));
+
+        assert_invoke_auth_ok!(owner, client.try_pause());
+
+        goldie::assert!(events::fmt_last_emitted_event::<PausedEvent>(&env));
+
+        assert!(client.paused());
+    }
+
+    #[test]
+    fn unpause_fails_if_caller_is_not_owner() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, Some(owner.clone()));
+
+        assert_invoke_auth_ok!(owner, client.try_pause());
+
+        let user = Address::generate(&env);
+        assert_invoke_auth_err!(user, client.try_unpause());
+        assert!(client.paused());
+    }
+
+    #[test]
+    fn unpause_succeeds_if_caller_is_owner() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, Some(owner.clone()));
+
+        assert_invoke_auth_ok!(owner, client.try_pause());
+        assert_invoke_auth_ok!(owner, client.try_unpause());
+
+        goldie::assert!(events::fmt_last_emitted_event::<UnpausedEvent>(&env));
+
+        assert!(!client.paused());
+    }
+}
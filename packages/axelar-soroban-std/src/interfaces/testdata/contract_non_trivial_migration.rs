@@ -1,6 +1,3 @@
-// this is only needed in this crate itself, any crate that imports this one doesn't have to do this manual import resolution
-use crate as axelar_soroban_std;
-
 use crate::interfaces::testdata::contract_trivial_migration::DataKey;
 use crate::interfaces::{operatable, ownable, MigratableInterface};
 use axelar_soroban_std_derive::{Ownable, Upgradable};
@@ -1,10 +1,13 @@
 use crate::interfaces::{
-    operatable, ownable, upgradable, MigratableInterface, OperatableInterface, OwnableInterface,
-    UpgradableInterface,
+    access_control, operatable, ownable, pausable, ttl_configurable, upgradable,
+    AccessControlInterface, MigratableInterface, OperatableInterface, OwnableInterface,
+    PausableInterface, TtlConfigurableInterface, UpgradableInterface, VersionHistoryEntry,
 };
+use crate::ttl::TtlConfig;
 use soroban_sdk::testutils::arbitrary::std;
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, Address, BytesN, Env, String,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, Address, BytesN, Env,
+    String, Symbol, Vec,
 };
 
 #[contract]
@@ -42,6 +45,10 @@ impl MigratableInterface for Contract {
         upgradable::migrate::<Self>(env, || Self::run_migration(env, migration_data))
             .map_err(|_| TrivialContractError::SomeFailure)
     }
+
+    fn schema_version(env: &Env) -> u32 {
+        upgradable::schema_version(env, upgradable::INITIAL_SCHEMA_VERSION)
+    }
 }
 
 #[contractimpl]
@@ -50,8 +57,20 @@ impl OwnableInterface for Contract {
         ownable::owner(env)
     }
 
-    fn transfer_ownership(env: &Env, new_owner: Address) {
-        ownable::transfer_ownership::<Self>(env, new_owner);
+    fn pending_owner(env: &Env) -> Option<Address> {
+        ownable::pending_owner(env)
+    }
+
+    fn propose_ownership(env: &Env, new_owner: Address) {
+        ownable::propose_ownership::<Self>(env, new_owner);
+    }
+
+    fn accept_ownership(env: &Env) {
+        ownable::accept_ownership::<Self>(env);
+    }
+
+    fn cancel_ownership_proposal(env: &Env) {
+        ownable::cancel_ownership_proposal::<Self>(env);
     }
 }
 
@@ -61,8 +80,76 @@ impl OperatableInterface for Contract {
         operatable::operator(env)
     }
 
-    fn transfer_operatorship(env: &Env, new_operator: Address) {
-        operatable::transfer_operatorship::<Self>(env, new_operator);
+    fn pending_operator(env: &Env) -> Option<Address> {
+        operatable::pending_operator(env)
+    }
+
+    fn propose_operatorship(env: &Env, new_operator: Address) {
+        operatable::propose_operatorship::<Self>(env, new_operator);
+    }
+
+    fn accept_operatorship(env: &Env) {
+        operatable::accept_operatorship::<Self>(env);
+    }
+
+    fn cancel_operatorship_proposal(env: &Env) {
+        operatable::cancel_operatorship_proposal::<Self>(env);
+    }
+}
+
+#[contractimpl]
+impl PausableInterface for Contract {
+    fn paused(env: &Env) -> bool {
+        pausable::paused(env)
+    }
+
+    fn pause(env: &Env) {
+        pausable::pause(env, Self::owner(env));
+    }
+
+    fn unpause(env: &Env) {
+        pausable::unpause(env, Self::owner(env));
+    }
+}
+
+#[contractimpl]
+impl AccessControlInterface for Contract {
+    fn has_role(env: &Env, role: Symbol, account: Address) -> bool {
+        access_control::has_role(env, &role, &account)
+    }
+
+    fn role_admin(env: &Env, role: Symbol) -> Option<Symbol> {
+        access_control::role_admin(env, &role)
+    }
+
+    fn grant_role(env: &Env, caller: Address, role: Symbol, account: Address) {
+        access_control::grant_role::<Self>(env, caller, role, account)
+            .unwrap_or_else(|_| panic_with_error!(env, TrivialContractError::MissingRole));
+    }
+
+    fn revoke_role(env: &Env, caller: Address, role: Symbol, account: Address) {
+        access_control::revoke_role::<Self>(env, caller, role, account)
+            .unwrap_or_else(|_| panic_with_error!(env, TrivialContractError::MissingRole));
+    }
+
+    fn renounce_role(env: &Env, account: Address, role: Symbol) {
+        access_control::renounce_role(env, account, role);
+    }
+
+    fn set_role_admin(env: &Env, role: Symbol, admin_role: Option<Symbol>) {
+        access_control::set_role_admin::<Self>(env, role, admin_role);
+    }
+}
+
+#[contractimpl]
+impl TtlConfigurableInterface for Contract {
+    fn ttl_config(env: &Env) -> TtlConfig {
+        ttl_configurable::ttl_config(env)
+    }
+
+    fn set_ttl_config(env: &Env, config: TtlConfig) {
+        ttl_configurable::set_ttl_config::<Self>(env, config)
+            .unwrap_or_else(|_| panic_with_error!(env, TrivialContractError::InvalidTtlConfig));
     }
 }
 
@@ -72,9 +159,31 @@ impl UpgradableInterface for Contract {
         String::from_str(env, "0.1.0")
     }
 
+    fn contract_kind(env: &Env) -> String {
+        String::from_str(env, "contract")
+    }
+
     fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) {
         upgradable::upgrade::<Self>(env, new_wasm_hash);
     }
+
+    fn version_history(env: &Env) -> Vec<VersionHistoryEntry> {
+        upgradable::version_history(env)
+    }
+
+    fn record_deployed_version(env: &Env, wasm_hash: BytesN<32>) {
+        upgradable::record_deployed_version::<Self>(
+            env,
+            wasm_hash,
+            upgradable::INITIAL_SCHEMA_VERSION,
+        )
+        .unwrap_or_else(|_| panic_with_error!(env, TrivialContractError::SomeFailure));
+    }
+
+    fn rollback(env: &Env) {
+        upgradable::rollback::<Self>(env)
+            .unwrap_or_else(|_| panic_with_error!(env, TrivialContractError::SomeFailure));
+    }
 }
 
 #[contracttype]
@@ -87,6 +196,8 @@ pub enum DataKey {
 #[derive(Debug)]
 pub enum TrivialContractError {
     SomeFailure = 1,
+    MissingRole = 2,
+    InvalidTtlConfig = 3,
 }
 
 mod test {
@@ -0,0 +1,58 @@
+use crate::interfaces::testdata::contract_trivial_migration::DataKey;
+use crate::interfaces::{ownable, MigratableInterface};
+use axelar_soroban_std_derive::{Ownable, Upgradable};
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, String, Vec};
+
+#[derive(Upgradable, Ownable)]
+#[migratable(steps = [String, u32])]
+#[contract]
+pub struct ContractVersioned;
+
+#[contracttype]
+enum VersionedDataKey {
+    AppliedSteps,
+}
+
+#[contractimpl]
+impl ContractVersioned {
+    pub fn __constructor(env: Env, owner: Address) {
+        ownable::set_owner(&env, &owner);
+    }
+
+    pub fn migration_data(env: &Env) -> Option<String> {
+        env.storage().instance().get(&DataKey::Data)
+    }
+
+    pub fn applied_steps(env: &Env) -> Vec<u32> {
+        env.storage()
+            .instance()
+            .get(&VersionedDataKey::AppliedSteps)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn run_migration_v2(env: &Env, migration_data: String) {
+        env.storage()
+            .instance()
+            .set(&DataKey::Data, &migration_data);
+        Self::record_step(env, 2);
+    }
+
+    fn run_migration_v3(env: &Env, _migration_data: u32) {
+        Self::record_step(env, 3);
+    }
+
+    fn record_step(env: &Env, version: u32) {
+        let mut steps = Self::applied_steps(env);
+        steps.push_back(version);
+        env.storage()
+            .instance()
+            .set(&VersionedDataKey::AppliedSteps, &steps);
+    }
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    MigrationNotAllowed = 1,
+}
@@ -0,0 +1,54 @@
+use crate::interfaces::{
+    ownable, set_wasm_registry, ApprovedWasm, MigratableInterface, WasmRegistryInterface,
+};
+use axelar_soroban_std_derive::{Ownable, Upgradable};
+use soroban_sdk::{contract, contracterror, contractimpl, Address, BytesN, Env, String};
+
+#[derive(Upgradable, Ownable)]
+#[upgradable(wasm_registry)]
+#[contract]
+pub struct ContractWithWasmRegistry;
+
+#[contractimpl]
+impl ContractWithWasmRegistry {
+    pub fn __constructor(env: Env, owner: Address, registry: Option<Address>) {
+        ownable::set_owner(&env, &owner);
+
+        if let Some(registry) = registry {
+            set_wasm_registry(&env, &registry);
+        }
+    }
+
+    const fn run_migration(_env: &Env, _migration_data: ()) {}
+}
+
+#[contract]
+pub struct WasmRegistry;
+
+#[contractimpl]
+impl WasmRegistry {
+    pub fn register(env: Env, wasm_hash: BytesN<32>, contract_kind: String, version: String) {
+        env.storage().instance().set(
+            &wasm_hash,
+            &ApprovedWasm {
+                contract_kind,
+                version,
+            },
+        );
+    }
+}
+
+#[contractimpl]
+impl WasmRegistryInterface for WasmRegistry {
+    fn approved_wasm(env: &Env, wasm_hash: BytesN<32>) -> Option<ApprovedWasm> {
+        env.storage().instance().get(&wasm_hash)
+    }
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    MigrationNotAllowed = 1,
+    UnapprovedWasmHash = 2,
+}
@@ -1,5 +1,14 @@
 mod contract_non_trivial_migration;
 mod contract_trivial_migration;
+mod contract_versioned_migration;
+mod contract_wasm_registry;
 
 pub use contract_non_trivial_migration::{ContractNonTrivialClient, MigrationData};
-pub use contract_trivial_migration::{Contract, ContractClient};
+pub use contract_trivial_migration::{Contract, ContractClient, DataKey, TrivialContractError};
+pub use contract_versioned_migration::{
+    ContractError as VersionedContractError, ContractVersioned, ContractVersionedClient,
+};
+pub use contract_wasm_registry::{
+    ContractError as WasmRegistryContractError, ContractWithWasmRegistry,
+    ContractWithWasmRegistryClient, WasmRegistry, WasmRegistryClient,
+};
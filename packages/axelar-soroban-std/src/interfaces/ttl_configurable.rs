@@ -0,0 +1,189 @@
+use crate::ensure;
+use crate::events::Event;
+use crate::interfaces::{storage, OwnableInterface};
+use crate::ttl::{TtlConfig, TtlPolicy};
+use axelar_soroban_std_derive::IntoEvent;
+use soroban_sdk::{contractclient, Env};
+
+#[contractclient(name = "TtlConfigurableClient")]
+pub trait TtlConfigurableInterface: OwnableInterface {
+    /// Returns the TTL policies used to extend the contract's storage entries.
+    fn ttl_config(env: &Env) -> TtlConfig;
+
+    /// Replaces the TTL policies of the contract. Only the owner can update them.
+    fn set_ttl_config(env: &Env, config: TtlConfig);
+}
+
+/// Default implementation of the [TtlConfigurableInterface] trait. Falls back to [TtlConfig::default] if no config is stored.
+pub fn ttl_config(env: &Env) -> TtlConfig {
+    env.storage()
+        .instance()
+        .get(&storage::ttl_config::DataKey::Interfaces_TtlConfig)
+        .unwrap_or_else(|| TtlConfig::default(env))
+}
+
+/// Default implementation of the [TtlConfigurableInterface] trait. Ensures the owner is authorized and emits an event after the change.
+pub fn set_ttl_config<T: OwnableInterface>(
+    env: &Env,
+    config: TtlConfig,
+) -> Result<(), TtlConfigError> {
+    T::owner(env).require_auth();
+
+    store_ttl_config(env, &config)?;
+
+    TtlConfigSetEvent { config }.emit(env);
+
+    Ok(())
+}
+
+/// Default implementation accompanying the [TtlConfigurableInterface] trait. This should never be part of a contract interface,
+/// but allows contracts internally to set the TTL config, e.g. during construction.
+pub fn store_ttl_config(env: &Env, config: &TtlConfig) -> Result<(), TtlConfigError> {
+    let max_ttl = env.storage().max_ttl();
+
+    ensure!(
+        [config.instance, config.persistent, config.temporary]
+            .into_iter()
+            .chain(config.key_overrides.values())
+            .all(|policy| is_valid_policy(policy, max_ttl)),
+        TtlConfigError::InvalidTtlConfig
+    );
+
+    env.storage()
+        .instance()
+        .set(&storage::ttl_config::DataKey::Interfaces_TtlConfig, config);
+
+    Ok(())
+}
+
+/// A policy that extends entries to 0 ledgers would let them expire right away.
+const fn is_valid_policy(policy: TtlPolicy, max_ttl: u32) -> bool {
+    policy.extend_to > 0 && policy.threshold <= policy.extend_to && policy.extend_to <= max_ttl
+}
+
+#[derive(Debug)]
+pub enum TtlConfigError {
+    InvalidTtlConfig,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+pub struct TtlConfigSetEvent {
+    #[data]
+    pub config: TtlConfig,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::interfaces::testdata::{Contract, TrivialContractError};
+    use crate::interfaces::{TtlConfigSetEvent, TtlConfigurableClient};
+    use crate::ttl::{extend_persistent_ttl, TtlConfig, TtlPolicy};
+    use crate::{assert_invoke_auth_err, assert_invoke_auth_ok, events};
+    use soroban_sdk::testutils::storage::Persistent as _;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+    #[contracttype]
+    enum DataKey {
+        Balance(Address),
+        TrustedChain(Symbol),
+    }
+
+    fn prepare_client(env: &Env, owner: Address) -> TtlConfigurableClient {
+        let operator = Address::generate(env);
+        let contract_id = env.register(Contract, (Some(owner), Some(operator)));
+        TtlConfigurableClient::new(env, &contract_id)
+    }
+
+    fn custom_config(env: &Env) -> TtlConfig {
+        let mut config = TtlConfig::default(env);
+        config.persistent = TtlPolicy::new(10_000, 10_000);
+        config
+            .key_overrides
+            .set(Symbol::new(env, "Balance"), TtlPolicy::new(20_000, 20_000));
+        config
+    }
+
+    #[test]
+    fn ttl_config_is_default_if_not_set() {
+        let env = Env::default();
+        let client = prepare_client(&env, Address::generate(&env));
+
+        assert_eq!(client.ttl_config(), TtlConfig::default(&env));
+    }
+
+    #[test]
+    fn set_ttl_config_fails_if_caller_is_not_owner() {
+        let env = Env::default();
+        let client = prepare_client(&env, Address::generate(&env));
+
+        let user = Address::generate(&env);
+        assert_invoke_auth_err!(user, client.try_set_ttl_config(&custom_config(&env)));
+    }
+
+    #[test]
+    fn set_ttl_config_succeeds_if_caller_is_owner() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, owner.clone());
+
+        let config = custom_config(&env);
+        assert_invoke_auth_ok!(owner, client.try_set_ttl_config(&config));
+
+        goldie::assert!(events::fmt_last_emitted_event::<TtlConfigSetEvent>(&env));
+
+        assert_eq!(client.ttl_config(), config);
+    }
+
+    #[test]
+    fn set_ttl_config_fails_with_invalid_policy() {
+        let env = Env::default();
+        let client = prepare_client(&env, Address::generate(&env));
+
+        let mut config = TtlConfig::default(&env);
+        config.instance = TtlPolicy::new(1000, 100);
+        assert_eq!(
+            client.mock_all_auths().try_set_ttl_config(&config),
+            Err(Ok(TrivialContractError::InvalidTtlConfig.into()))
+        );
+
+        let mut config = TtlConfig::default(&env);
+        config.key_overrides.set(
+            Symbol::new(&env, "Balance"),
+            TtlPolicy::new(100, env.storage().max_ttl() + 1),
+        );
+        assert_eq!(
+            client.mock_all_auths().try_set_ttl_config(&config),
+            Err(Ok(TrivialContractError::InvalidTtlConfig.into()))
+        );
+
+        let mut config = TtlConfig::default(&env);
+        config.temporary = TtlPolicy::new(0, 0);
+        assert_eq!(
+            client.mock_all_auths().try_set_ttl_config(&config),
+            Err(Ok(TrivialContractError::InvalidTtlConfig.into()))
+        );
+    }
+
+    #[test]
+    fn extend_persistent_ttl_uses_key_overrides() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_client(&env, owner.clone());
+
+        assert_invoke_auth_ok!(owner, client.try_set_ttl_config(&custom_config(&env)));
+
+        let balance = DataKey::Balance(Address::generate(&env));
+        let trusted_chain = DataKey::TrustedChain(Symbol::new(&env, "ethereum"));
+
+        env.as_contract(&client.address, || {
+            env.storage().persistent().set(&balance, &1);
+            env.storage().persistent().set(&trusted_chain, &());
+
+            extend_persistent_ttl(&env, &balance);
+            extend_persistent_ttl(&env, &trusted_chain);
+
+            assert_eq!(env.storage().persistent().get_ttl(&balance), 20_000);
+            assert_eq!(env.storage().persistent().get_ttl(&trusted_chain), 10_000);
+        });
+    }
+}
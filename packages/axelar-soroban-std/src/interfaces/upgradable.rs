@@ -1,20 +1,46 @@
 use crate::ensure;
 use crate::events::Event;
-#[cfg(any(test, feature = "testutils"))]
-use crate::impl_event_testutils;
 use crate::interfaces::{storage, OwnableInterface};
-use core::fmt::Debug;
-use soroban_sdk::{
-    contractclient, symbol_short, BytesN, Env, FromVal, IntoVal, String, Topics, Val,
-};
+use crate::ttl::extend_persistent_ttl;
+use axelar_soroban_std_derive::IntoEvent;
+use soroban_sdk::{contractclient, contracttype, BytesN, Env, FromVal, String, Val, Vec};
+
+/// Schema version of contracts that have never run a versioned migration step.
+pub const INITIAL_SCHEMA_VERSION: u32 = 1;
 
 #[contractclient(name = "UpgradableClient")]
 pub trait UpgradableInterface: OwnableInterface {
     /// Returns the current version of the contract.
     fn version(env: &Env) -> String;
 
+    /// Returns the kind of the contract, i.e. its crate name. Audited WASM hashes are approved per contract kind.
+    fn contract_kind(env: &Env) -> String;
+
     /// Upgrades the contract to a new WASM hash.
     fn upgrade(env: &Env, new_wasm_hash: BytesN<32>);
+
+    /// Returns all completed upgrades of the contract, oldest first.
+    fn version_history(env: &Env) -> Vec<VersionHistoryEntry>;
+
+    /// Records the WASM hash the contract was deployed with as the first version history entry,
+    /// so the first upgrade can be rolled back. Only allowed before the first upgrade.
+    fn record_deployed_version(env: &Env, wasm_hash: BytesN<32>);
+
+    /// Upgrades the contract back to the WASM hash of the previous entry in the version history.
+    /// The contract then needs to be migrated like after a regular upgrade.
+    /// Rolling back to code with a different schema version than the current contract state is not allowed.
+    fn rollback(env: &Env);
+}
+
+/// A completed upgrade of a contract.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionHistoryEntry {
+    pub version: String,
+    pub wasm_hash: BytesN<32>,
+    pub ledger: u32,
+    pub timestamp: u64,
+    pub schema_version: u32,
 }
 
 pub trait MigratableInterface: UpgradableInterface {
@@ -25,6 +51,9 @@ pub trait MigratableInterface: UpgradableInterface {
 
     /// Migrates contract state after upgrading the contract code.
     fn migrate(env: &Env, migration_data: Self::MigrationData) -> Result<(), Self::Error>;
+
+    /// Returns the version of the schema the contract state has been migrated to.
+    fn schema_version(env: &Env) -> u32;
 }
 
 /// This function checks that the caller can authenticate as the owner of the contract,
@@ -32,8 +61,119 @@ pub trait MigratableInterface: UpgradableInterface {
 pub fn upgrade<T: OwnableInterface>(env: &Env, new_wasm_hash: BytesN<32>) {
     T::owner(env).require_auth();
 
-    env.deployer().update_current_contract_wasm(new_wasm_hash);
-    start_migration(env);
+    update_wasm(env, new_wasm_hash);
+}
+
+/// Default implementation of the [UpgradableInterface] trait.
+/// Upgrades are only recorded once their migration completes, and only by contract code that keeps a version history.
+/// The history is append-only and reads one persistent entry per recorded upgrade.
+pub fn version_history(env: &Env) -> Vec<VersionHistoryEntry> {
+    let mut history = Vec::new(env);
+
+    for index in 0..version_history_len(env) {
+        if let Some(entry) = version_history_entry(env, index) {
+            history.push_back(entry);
+        }
+    }
+
+    history
+}
+
+/// Returns the number of entries in the version history.
+pub fn version_history_len(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&storage::migrating::DataKey::Interfaces_VersionHistoryLen)
+        .unwrap_or(0)
+}
+
+/// Returns the version history entry at `index`, oldest first.
+pub fn version_history_entry(env: &Env, index: u32) -> Option<VersionHistoryEntry> {
+    let key = storage::migrating::DataKey::Interfaces_VersionHistoryEntry(index);
+    let entry = env.storage().persistent().get(&key);
+
+    if entry.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+
+    entry
+}
+
+/// Returns the second to last version history entry, which [rollback] upgrades the contract back to.
+pub fn previous_version(env: &Env) -> Option<VersionHistoryEntry> {
+    version_history_len(env)
+        .checked_sub(2)
+        .and_then(|index| version_history_entry(env, index))
+}
+
+/// This function checks that the caller can authenticate as the owner of the contract,
+/// then records the current contract code, deployed from `wasm_hash`, as the first version history entry.
+/// The contract code can't read its own WASM hash, so this has to be recorded before the first upgrade to be able to roll it back.
+/// `code_schema_version` is the schema version of the current contract code.
+pub fn record_deployed_version<T: UpgradableInterface>(
+    env: &Env,
+    wasm_hash: BytesN<32>,
+    code_schema_version: u32,
+) -> Result<(), MigrationError> {
+    T::owner(env).require_auth();
+
+    ensure_is_not_migrating(env)?;
+    ensure!(version_history_len(env) == 0, MigrationError::NotAllowed);
+
+    push_version_history_entry(
+        env,
+        VersionHistoryEntry {
+            version: T::version(env),
+            wasm_hash,
+            ledger: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+            schema_version: schema_version(env, code_schema_version),
+        },
+    );
+
+    Ok(())
+}
+
+/// This function checks that the caller can authenticate as the owner of the contract,
+/// then upgrades the contract to the WASM hash of the second to last version history entry and prepares it for migration.
+/// The previous code must be at the same schema version as the contract state, because it can't migrate the state back.
+/// The rollback itself is appended to the version history once the migration completes.
+/// Rolling back while a migration is pending is not allowed.
+pub fn rollback<T: OwnableInterface>(env: &Env) -> Result<(), MigrationError> {
+    T::owner(env).require_auth();
+
+    ensure_is_not_migrating(env)?;
+
+    let previous = previous_version(env).ok_or(MigrationError::NoPreviousVersion)?;
+
+    ensure!(
+        previous.schema_version == current_schema_version(env),
+        MigrationError::SchemaVersionMismatch
+    );
+
+    RolledBackEvent {
+        version: previous.version,
+        wasm_hash: previous.wasm_hash.clone(),
+    }
+    .emit(env);
+
+    update_wasm(env, previous.wasm_hash);
+
+    Ok(())
+}
+
+/// Same as [upgrade], but first records the schema version of the current contract code if the state does not have one yet,
+/// so the new code knows which migration steps it needs to run.
+pub fn upgrade_from_schema_version<T: OwnableInterface>(
+    env: &Env,
+    new_wasm_hash: BytesN<32>,
+    schema_version: u32,
+) {
+    if stored_schema_version(env).is_none() {
+        set_schema_version(env, schema_version);
+    }
+
+    upgrade::<T>(env, new_wasm_hash);
 }
 
 /// This function checks that the caller can authenticate as the owner of the contract,
@@ -47,24 +187,96 @@ pub fn migrate<T: UpgradableInterface>(
     T::owner(env).require_auth();
 
     ensure_is_migrating(env)?;
+    ensure_is_not_downgrade(env, INITIAL_SCHEMA_VERSION)?;
 
     custom_migration();
-    complete_migration(env);
+    complete_migration::<T>(env);
 
-    UpgradedEvent {
-        version: T::version(env),
+    Ok(())
+}
+
+/// This function checks that the caller can authenticate as the owner of the contract,
+/// then runs all migration steps from the stored schema version up to `schema_version` in order.
+/// `migration_data` must contain one entry per step, which is passed to `run_step` together with the version the step migrates to.
+/// Migrating to a lower schema version than the stored one is not allowed.
+/// Like [migrate], this can only be run once, after the [upgrade] function has been called.
+pub fn migrate_to_schema_version<T: UpgradableInterface>(
+    env: &Env,
+    schema_version: u32,
+    migration_data: Vec<Val>,
+    mut run_step: impl FnMut(u32, Val) -> Result<(), MigrationError>,
+) -> Result<(), MigrationError> {
+    T::owner(env).require_auth();
+
+    ensure_is_migrating(env)?;
+    let current_version = ensure_is_not_downgrade(env, schema_version)?;
+
+    ensure!(
+        migration_data.len() == schema_version - current_version,
+        MigrationError::InvalidMigrationData
+    );
+
+    for (version, data) in (current_version + 1..=schema_version).zip(migration_data) {
+        run_step(version, data)?;
     }
-    .emit(env);
+
+    set_schema_version(env, schema_version);
+    complete_migration::<T>(env);
 
     Ok(())
 }
 
+/// Default implementation of [MigratableInterface::schema_version] for contracts whose current code
+/// is at `code_schema_version`. The state only stores a version once it has been migrated or upgraded.
+pub fn schema_version(env: &Env, code_schema_version: u32) -> u32 {
+    stored_schema_version(env).unwrap_or(code_schema_version)
+}
+
+fn stored_schema_version(env: &Env) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&storage::migrating::DataKey::Interfaces_SchemaVersion)
+}
+
+/// Returns the schema version of the contract state, as seen by migrations.
+fn current_schema_version(env: &Env) -> u32 {
+    stored_schema_version(env).unwrap_or(INITIAL_SCHEMA_VERSION)
+}
+
+fn set_schema_version(env: &Env, schema_version: u32) {
+    env.storage().instance().set(
+        &storage::migrating::DataKey::Interfaces_SchemaVersion,
+        &schema_version,
+    );
+}
+
+fn update_wasm(env: &Env, new_wasm_hash: BytesN<32>) {
+    env.storage().instance().set(
+        &storage::migrating::DataKey::Interfaces_UpgradeWasmHash,
+        &new_wasm_hash,
+    );
+
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
+    start_migration(env);
+}
+
 fn start_migration(env: &Env) {
     env.storage()
         .instance()
         .set(&storage::migrating::DataKey::Interfaces_Migrating, &());
 }
 
+fn ensure_is_not_migrating(env: &Env) -> Result<(), MigrationError> {
+    ensure!(
+        !env.storage()
+            .instance()
+            .has(&storage::migrating::DataKey::Interfaces_Migrating),
+        MigrationError::NotAllowed
+    );
+
+    Ok(())
+}
+
 fn ensure_is_migrating(env: &Env) -> Result<(), MigrationError> {
     ensure!(
         env.storage()
@@ -76,43 +288,102 @@ fn ensure_is_migrating(env: &Env) -> Result<(), MigrationError> {
     Ok(())
 }
 
-fn complete_migration(env: &Env) {
+/// Returns the schema version the contract state is currently at.
+fn ensure_is_not_downgrade(env: &Env, schema_version: u32) -> Result<u32, MigrationError> {
+    let current_version = current_schema_version(env);
+
+    ensure!(
+        current_version <= schema_version,
+        MigrationError::LowerSchemaVersion
+    );
+
+    Ok(current_version)
+}
+
+fn complete_migration<T: UpgradableInterface>(env: &Env) {
     env.storage()
         .instance()
         .remove(&storage::migrating::DataKey::Interfaces_Migrating);
+
+    let version = T::version(env);
+    record_version(env, version.clone());
+
+    UpgradedEvent { version }.emit(env);
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct UpgradedEvent {
-    version: String,
+fn record_version(env: &Env, version: String) {
+    let Some(wasm_hash) = env
+        .storage()
+        .instance()
+        .get(&storage::migrating::DataKey::Interfaces_UpgradeWasmHash)
+    else {
+        return;
+    };
+
+    env.storage()
+        .instance()
+        .remove(&storage::migrating::DataKey::Interfaces_UpgradeWasmHash);
+
+    push_version_history_entry(
+        env,
+        VersionHistoryEntry {
+            version,
+            wasm_hash,
+            ledger: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+            schema_version: current_schema_version(env),
+        },
+    );
 }
 
-impl Event for UpgradedEvent {
-    fn topics(&self, _env: &Env) -> impl Topics + Debug {
-        (symbol_short!("upgraded"),)
-    }
+fn push_version_history_entry(env: &Env, entry: VersionHistoryEntry) {
+    let index = version_history_len(env);
+    let key = storage::migrating::DataKey::Interfaces_VersionHistoryEntry(index);
 
-    fn data(&self, _env: &Env) -> impl IntoVal<Env, Val> + Debug {
-        (self.version.to_val(),)
-    }
+    env.storage().persistent().set(&key, &entry);
+    extend_persistent_ttl(env, &key);
+
+    env.storage().instance().set(
+        &storage::migrating::DataKey::Interfaces_VersionHistoryLen,
+        &(index + 1),
+    );
 }
 
-#[cfg(any(test, feature = "testutils"))]
-impl_event_testutils!(UpgradedEvent, (soroban_sdk::Symbol), (String));
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+pub struct RolledBackEvent {
+    #[data]
+    version: String,
+    #[data]
+    wasm_hash: BytesN<32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+pub struct UpgradedEvent {
+    #[data]
+    version: String,
+}
 
 pub enum MigrationError {
     NotAllowed,
+    LowerSchemaVersion,
+    InvalidMigrationData,
+    NoPreviousVersion,
+    SchemaVersionMismatch,
 }
 
 #[cfg(test)]
 mod test {
-    use crate::interfaces::upgradable::UpgradedEvent;
+    use crate::interfaces::upgradable::{RolledBackEvent, UpgradedEvent, VersionHistoryEntry};
     use crate::{assert_invoke_auth_err, assert_invoke_auth_ok, events};
 
-    use crate::interfaces::testdata::{ContractClient, ContractNonTrivialClient, MigrationData};
-    use crate::interfaces::{testdata, upgradable};
-    use soroban_sdk::testutils::Address as _;
-    use soroban_sdk::{Address, BytesN, Env, String};
+    use crate::assert_contract_err;
+    use crate::interfaces::testdata::{
+        ContractClient, ContractNonTrivialClient, ContractVersionedClient, MigrationData,
+        TrivialContractError, VersionedContractError,
+    };
+    use crate::interfaces::{storage, testdata, upgradable};
+    use soroban_sdk::testutils::{Address as _, BytesN as _};
+    use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, String, Val, Vec};
 
     const WASM: &[u8] = include_bytes!("testdata/contract_trivial_migration.wasm");
     const WASM_NON_TRIVIAL: &[u8] = include_bytes!("testdata/contract_non_trivial_migration.wasm");
@@ -264,4 +535,371 @@ mod test {
         assert_invoke_auth_ok!(owner, client.try_migrate(&()));
         assert_invoke_auth_err!(owner, client.try_migrate(&()));
     }
+
+    fn prepare_versioned_client(env: &Env, owner: &Address) -> ContractVersionedClient<'static> {
+        let contract_id = env.register(testdata::ContractVersioned, (owner,));
+        ContractVersionedClient::new(env, &contract_id)
+    }
+
+    fn start_versioned_migration(env: &Env, contract_id: &Address, schema_version: Option<u32>) {
+        env.as_contract(contract_id, || {
+            if let Some(schema_version) = schema_version {
+                upgradable::set_schema_version(env, schema_version);
+            }
+            upgradable::start_migration(env);
+        });
+    }
+
+    fn steps_data(env: &Env) -> Vec<Val> {
+        vec![
+            env,
+            String::from_str(env, "migrated_v2").into_val(env),
+            Val::from(3u32),
+        ]
+    }
+
+    #[test]
+    fn schema_version_of_new_contract_is_code_schema_version() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+
+        let client = prepare_versioned_client(&env, &owner);
+        assert_eq!(client.schema_version(), 3);
+
+        let (client, _) = prepare_client_and_bytecode(&env, Some(owner));
+        assert_eq!(client.schema_version(), upgradable::INITIAL_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn upgrade_records_code_schema_version() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_versioned_client(&env, &owner);
+        let hash = env.deployer().upload_contract_wasm(WASM);
+
+        assert_invoke_auth_ok!(owner, client.try_upgrade(&hash));
+
+        env.as_contract(&client.address, || {
+            assert_eq!(upgradable::stored_schema_version(&env), Some(3));
+        });
+    }
+
+    #[test]
+    fn versioned_migrate_runs_all_steps_from_initial_schema_version() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_versioned_client(&env, &owner);
+
+        start_versioned_migration(&env, &client.address, None);
+
+        assert_invoke_auth_ok!(owner, client.try_migrate(&steps_data(&env)));
+
+        assert_eq!(client.applied_steps(), vec![&env, 2, 3]);
+        assert_eq!(
+            client.migration_data(),
+            Some(String::from_str(&env, "migrated_v2"))
+        );
+        assert_eq!(client.schema_version(), 3);
+    }
+
+    #[test]
+    fn versioned_migrate_only_runs_steps_after_stored_schema_version() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_versioned_client(&env, &owner);
+
+        start_versioned_migration(&env, &client.address, Some(2));
+
+        assert_invoke_auth_ok!(owner, client.try_migrate(&vec![&env, Val::from(3u32)]));
+
+        assert_eq!(client.applied_steps(), vec![&env, 3]);
+        assert!(client.migration_data().is_none());
+        assert_eq!(client.schema_version(), 3);
+    }
+
+    #[test]
+    fn versioned_migrate_without_steps_succeeds_at_same_schema_version() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_versioned_client(&env, &owner);
+
+        start_versioned_migration(&env, &client.address, Some(3));
+
+        assert_invoke_auth_ok!(owner, client.try_migrate(&Vec::<Val>::new(&env)));
+
+        assert!(client.applied_steps().is_empty());
+        goldie::assert!(events::fmt_last_emitted_event::<UpgradedEvent>(&env))
+    }
+
+    #[test]
+    fn versioned_migrate_fails_if_stored_schema_version_is_higher() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_versioned_client(&env, &owner);
+
+        start_versioned_migration(&env, &client.address, Some(4));
+
+        assert_contract_err!(
+            client.mock_all_auths().try_migrate(&Vec::<Val>::new(&env)),
+            VersionedContractError::MigrationNotAllowed
+        );
+    }
+
+    #[test]
+    fn versioned_migrate_fails_if_step_data_does_not_match_steps() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_versioned_client(&env, &owner);
+
+        start_versioned_migration(&env, &client.address, Some(2));
+
+        assert_contract_err!(
+            client.mock_all_auths().try_migrate(&steps_data(&env)),
+            VersionedContractError::MigrationNotAllowed
+        );
+        assert_contract_err!(
+            client
+                .mock_all_auths()
+                .try_migrate(&vec![&env, String::from_str(&env, "v3").into_val(&env)]),
+            VersionedContractError::MigrationNotAllowed
+        );
+    }
+
+    fn simulate_upgrade(env: &Env, contract_id: &Address, wasm_hash: &BytesN<32>) {
+        env.as_contract(contract_id, || {
+            env.storage().instance().set(
+                &storage::migrating::DataKey::Interfaces_UpgradeWasmHash,
+                wasm_hash,
+            );
+            upgradable::start_migration(env);
+        });
+    }
+
+    #[test]
+    fn version_history_is_empty_if_never_upgraded() {
+        let env = Env::default();
+        let (client, _) = prepare_client_and_bytecode(&env, Some(Address::generate(&env)));
+
+        assert!(client.version_history().is_empty());
+    }
+
+    #[test]
+    fn migrate_appends_upgrade_to_version_history() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let (client, hash) = prepare_client_and_bytecode(&env, Some(owner.clone()));
+
+        simulate_upgrade(&env, &client.address, &hash);
+        assert!(client.version_history().is_empty());
+
+        assert_invoke_auth_ok!(owner, client.try_migrate(&()));
+
+        assert_eq!(
+            client.version_history(),
+            vec![
+                &env,
+                VersionHistoryEntry {
+                    version: client.version(),
+                    wasm_hash: hash,
+                    ledger: env.ledger().sequence(),
+                    timestamp: env.ledger().timestamp(),
+                    schema_version: upgradable::INITIAL_SCHEMA_VERSION,
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn version_history_keeps_all_entries() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let (client, _) = prepare_client_and_bytecode(&env, Some(owner.clone()));
+
+        let hashes: std::vec::Vec<_> = (0..20).map(|_| BytesN::random(&env)).collect();
+        for hash in &hashes {
+            simulate_upgrade(&env, &client.address, hash);
+            assert_invoke_auth_ok!(owner, client.try_migrate(&()));
+        }
+
+        let history: std::vec::Vec<_> = client
+            .version_history()
+            .iter()
+            .map(|entry| entry.wasm_hash)
+            .collect();
+        assert_eq!(history, hashes);
+
+        env.as_contract(&client.address, || {
+            assert_eq!(upgradable::version_history_len(&env), 20);
+            assert_eq!(
+                upgradable::previous_version(&env).unwrap().wasm_hash,
+                hashes[18]
+            );
+        });
+    }
+
+    #[test]
+    fn record_deployed_version_adds_first_version_history_entry() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let (client, hash) = prepare_client_and_bytecode(&env, Some(owner.clone()));
+
+        assert_invoke_auth_err!(
+            Address::generate(&env),
+            client.try_record_deployed_version(&hash)
+        );
+        assert_invoke_auth_ok!(owner, client.try_record_deployed_version(&hash));
+
+        assert_eq!(
+            client.version_history(),
+            vec![
+                &env,
+                VersionHistoryEntry {
+                    version: client.version(),
+                    wasm_hash: hash,
+                    ledger: env.ledger().sequence(),
+                    timestamp: env.ledger().timestamp(),
+                    schema_version: upgradable::INITIAL_SCHEMA_VERSION,
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn record_deployed_version_records_code_schema_version() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let client = prepare_versioned_client(&env, &owner);
+
+        let hash = BytesN::random(&env);
+
+        assert_invoke_auth_ok!(owner, client.try_record_deployed_version(&hash));
+
+        assert_eq!(client.version_history().get(0).unwrap().schema_version, 3);
+    }
+
+    #[test]
+    fn record_deployed_version_fails_after_first_upgrade() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let (client, hash) = prepare_client_and_bytecode(&env, Some(owner.clone()));
+
+        simulate_upgrade(&env, &client.address, &hash);
+        assert_eq!(
+            client.mock_all_auths().try_record_deployed_version(&hash),
+            Err(Ok(TrivialContractError::SomeFailure.into()))
+        );
+
+        assert_invoke_auth_ok!(owner, client.try_migrate(&()));
+        assert_eq!(
+            client.mock_all_auths().try_record_deployed_version(&hash),
+            Err(Ok(TrivialContractError::SomeFailure.into()))
+        );
+    }
+
+    #[test]
+    fn rollback_fails_without_previous_version() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let (client, hash) = prepare_client_and_bytecode(&env, Some(owner.clone()));
+
+        assert_eq!(
+            client.mock_all_auths().try_rollback(),
+            Err(Ok(TrivialContractError::SomeFailure.into()))
+        );
+
+        simulate_upgrade(&env, &client.address, &hash);
+        assert_invoke_auth_ok!(owner, client.try_migrate(&()));
+
+        assert_eq!(
+            client.mock_all_auths().try_rollback(),
+            Err(Ok(TrivialContractError::SomeFailure.into()))
+        );
+    }
+
+    #[test]
+    fn rollback_fails_if_schema_version_changed() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let (client, hash) = prepare_client_and_bytecode(&env, Some(owner.clone()));
+
+        assert_invoke_auth_ok!(owner, client.try_record_deployed_version(&hash));
+        simulate_upgrade(&env, &client.address, &BytesN::random(&env));
+        assert_invoke_auth_ok!(owner, client.try_migrate(&()));
+
+        // the new code migrated the state to a schema the deployed code doesn't know
+        env.as_contract(&client.address, || {
+            upgradable::set_schema_version(&env, 2);
+        });
+
+        assert_eq!(
+            client.mock_all_auths().try_rollback(),
+            Err(Ok(TrivialContractError::SomeFailure.into()))
+        );
+    }
+
+    #[test]
+    fn rollback_of_first_upgrade_returns_to_deployed_version() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let (client, hash) = prepare_client_and_bytecode(&env, Some(owner.clone()));
+
+        assert_invoke_auth_ok!(owner, client.try_record_deployed_version(&hash));
+        simulate_upgrade(&env, &client.address, &BytesN::random(&env));
+        assert_invoke_auth_ok!(owner, client.try_migrate(&()));
+
+        assert_invoke_auth_ok!(owner, client.try_rollback());
+        goldie::assert!(events::fmt_last_emitted_event::<RolledBackEvent>(&env));
+    }
+
+    #[test]
+    fn rollback_fails_during_pending_migration() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let (client, hash) = prepare_client_and_bytecode(&env, Some(owner.clone()));
+
+        assert_invoke_auth_ok!(owner, client.try_record_deployed_version(&hash));
+        simulate_upgrade(&env, &client.address, &BytesN::random(&env));
+        assert_invoke_auth_ok!(owner, client.try_migrate(&()));
+
+        simulate_upgrade(&env, &client.address, &BytesN::random(&env));
+        assert_eq!(
+            client.mock_all_auths().try_rollback(),
+            Err(Ok(TrivialContractError::SomeFailure.into()))
+        );
+    }
+
+    #[test]
+    fn rollback_fails_if_called_by_non_owner() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let (client, _) = prepare_client_and_bytecode(&env, Some(owner));
+
+        assert_invoke_auth_err!(Address::generate(&env), client.try_rollback());
+    }
+
+    #[test]
+    fn rollback_upgrades_to_previous_version_and_requires_migration() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let (client, hash) = prepare_client_and_bytecode(&env, Some(owner.clone()));
+
+        simulate_upgrade(&env, &client.address, &hash);
+        assert_invoke_auth_ok!(owner, client.try_migrate(&()));
+        simulate_upgrade(&env, &client.address, &BytesN::random(&env));
+        assert_invoke_auth_ok!(owner, client.try_migrate(&()));
+        env.as_contract(&client.address, || {
+            env.storage().instance().remove(&testdata::DataKey::Data);
+        });
+
+        assert_invoke_auth_ok!(owner, client.try_rollback());
+        goldie::assert!(events::fmt_last_emitted_event::<RolledBackEvent>(&env));
+
+        // the previous code still needs to be migrated before the rollback is complete
+        assert!(client.migration_data().is_none());
+        assert_invoke_auth_ok!(owner, client.try_migrate(&()));
+        assert_eq!(
+            client.migration_data(),
+            Some(String::from_str(&env, "migrated"))
+        );
+    }
 }
@@ -0,0 +1,249 @@
+use crate::ensure;
+use crate::events::Event;
+use crate::interfaces::{storage, OwnableInterface};
+use axelar_soroban_std_derive::IntoEvent;
+use soroban_sdk::{contractclient, contracttype, Address, BytesN, Env, String};
+
+#[contractclient(name = "WasmRegistryClient")]
+pub trait WasmRegistryInterface {
+    /// Returns the contract kind and version a WASM hash was approved for, if it has been approved.
+    fn approved_wasm(env: &Env, wasm_hash: BytesN<32>) -> Option<ApprovedWasm>;
+}
+
+#[contractclient(name = "WasmRegistryConfigurableClient")]
+pub trait WasmRegistryConfigurableInterface: OwnableInterface {
+    /// Returns the registry that upgrades of the contract are checked against, if one is set.
+    fn wasm_registry(env: &Env) -> Option<Address>;
+
+    /// Sets the registry that upgrades of the contract are checked against.
+    fn set_wasm_registry(env: &Env, registry: Address);
+}
+
+/// The contract kind and version an audited WASM hash was registered for.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApprovedWasm {
+    pub contract_kind: String,
+    pub version: String,
+}
+
+/// Returns the registry that upgrades of the contract are checked against, if one is set.
+pub fn wasm_registry(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&storage::wasm_registry::DataKey::Interfaces_WasmRegistry)
+}
+
+/// Default implementation accompanying the [WasmRegistryInterface] trait. This should never be part of a contract interface,
+/// but allows contracts internally to set the registry, e.g. during construction.
+pub fn set_wasm_registry(env: &Env, registry: &Address) {
+    env.storage().instance().set(
+        &storage::wasm_registry::DataKey::Interfaces_WasmRegistry,
+        registry,
+    );
+}
+
+/// Default implementation of the [WasmRegistryConfigurableInterface] trait. Ensures the owner is authorized and emits an event after the change.
+pub fn update_wasm_registry<T: OwnableInterface>(env: &Env, registry: Address) {
+    T::owner(env).require_auth();
+
+    set_wasm_registry(env, &registry);
+
+    WasmRegistrySetEvent { registry }.emit(env);
+}
+
+/// Checks that the registry of the contract approved the WASM hash for `contract_kind`. Fails if no registry is set.
+pub fn ensure_wasm_hash_approved(
+    env: &Env,
+    contract_kind: &String,
+    wasm_hash: &BytesN<32>,
+) -> Result<(), WasmRegistryError> {
+    let registry = wasm_registry(env).ok_or(WasmRegistryError::RegistryNotSet)?;
+
+    let approved = WasmRegistryClient::new(env, &registry).approved_wasm(wasm_hash);
+    ensure!(
+        approved.is_some_and(|approved| approved.contract_kind == *contract_kind),
+        WasmRegistryError::UnapprovedWasmHash
+    );
+
+    Ok(())
+}
+
+#[derive(Debug)]
+pub enum WasmRegistryError {
+    RegistryNotSet,
+    UnapprovedWasmHash,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, IntoEvent)]
+pub struct WasmRegistrySetEvent {
+    #[data]
+    pub registry: Address,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::interfaces::testdata::{
+        ContractWithWasmRegistry, ContractWithWasmRegistryClient, WasmRegistry, WasmRegistryClient,
+        WasmRegistryContractError,
+    };
+    use crate::interfaces::{
+        storage, VersionHistoryEntry, WasmRegistrySetEvent, INITIAL_SCHEMA_VERSION,
+    };
+    use crate::{assert_invoke_auth_err, assert_invoke_auth_ok, events};
+    use soroban_sdk::testutils::{Address as _, BytesN as _};
+    use soroban_sdk::{Address, BytesN, Env, String};
+
+    const WASM: &[u8] = include_bytes!("testdata/contract_trivial_migration.wasm");
+
+    struct TestFixture<'a> {
+        env: Env,
+        owner: Address,
+        client: ContractWithWasmRegistryClient<'a>,
+        registry: WasmRegistryClient<'a>,
+        hash: BytesN<32>,
+    }
+
+    fn setup<'a>() -> TestFixture<'a> {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let owner = Address::generate(&env);
+        let registry = WasmRegistryClient::new(&env, &env.register(WasmRegistry, ()));
+        let contract_id = env.register(
+            ContractWithWasmRegistry,
+            (owner.clone(), Some(registry.address.clone())),
+        );
+        let client = ContractWithWasmRegistryClient::new(&env, &contract_id);
+        let hash = env.deployer().upload_contract_wasm(WASM);
+
+        TestFixture {
+            env,
+            owner,
+            client,
+            registry,
+            hash,
+        }
+    }
+
+    fn register(fixture: &TestFixture, contract_kind: &str) {
+        fixture.registry.register(
+            &fixture.hash,
+            &String::from_str(&fixture.env, contract_kind),
+            &String::from_str(&fixture.env, "0.2.0"),
+        );
+    }
+
+    #[test]
+    fn upgrade_fails_if_registry_not_set() {
+        let TestFixture { env, hash, .. } = setup();
+
+        let contract_id = env.register(
+            ContractWithWasmRegistry,
+            (Address::generate(&env), None::<Address>),
+        );
+        let client = ContractWithWasmRegistryClient::new(&env, &contract_id);
+
+        assert_eq!(
+            client.try_upgrade(&hash),
+            Err(Ok(WasmRegistryContractError::UnapprovedWasmHash.into()))
+        );
+    }
+
+    #[test]
+    fn upgrade_fails_if_wasm_hash_not_approved() {
+        let TestFixture { client, hash, .. } = setup();
+
+        assert_eq!(
+            client.try_upgrade(&hash),
+            Err(Ok(WasmRegistryContractError::UnapprovedWasmHash.into()))
+        );
+    }
+
+    #[test]
+    fn upgrade_fails_if_wasm_hash_approved_for_other_contract_kind() {
+        let fixture = setup();
+        register(&fixture, "interchain-token");
+
+        assert_eq!(
+            fixture.client.try_upgrade(&fixture.hash),
+            Err(Ok(WasmRegistryContractError::UnapprovedWasmHash.into()))
+        );
+    }
+
+    #[test]
+    fn upgrade_succeeds_if_wasm_hash_approved() {
+        let fixture = setup();
+        register(&fixture, env!("CARGO_PKG_NAME"));
+
+        assert!(fixture.client.try_upgrade(&fixture.hash).is_ok());
+    }
+
+    fn set_version_history(fixture: &TestFixture, previous_wasm_hash: &BytesN<32>) {
+        let entry = |wasm_hash: BytesN<32>| VersionHistoryEntry {
+            version: String::from_str(&fixture.env, "0.1.0"),
+            wasm_hash,
+            ledger: 0,
+            timestamp: 0,
+            schema_version: INITIAL_SCHEMA_VERSION,
+        };
+
+        fixture.env.as_contract(&fixture.client.address, || {
+            fixture.env.storage().persistent().set(
+                &storage::migrating::DataKey::Interfaces_VersionHistoryEntry(0),
+                &entry(previous_wasm_hash.clone()),
+            );
+            fixture.env.storage().persistent().set(
+                &storage::migrating::DataKey::Interfaces_VersionHistoryEntry(1),
+                &entry(BytesN::random(&fixture.env)),
+            );
+            fixture.env.storage().instance().set(
+                &storage::migrating::DataKey::Interfaces_VersionHistoryLen,
+                &2u32,
+            );
+        });
+    }
+
+    #[test]
+    fn rollback_fails_if_previous_wasm_hash_not_approved() {
+        let fixture = setup();
+        set_version_history(&fixture, &fixture.hash);
+
+        assert_eq!(
+            fixture.client.try_rollback(),
+            Err(Ok(WasmRegistryContractError::UnapprovedWasmHash.into()))
+        );
+    }
+
+    #[test]
+    fn rollback_succeeds_if_previous_wasm_hash_approved() {
+        let fixture = setup();
+        set_version_history(&fixture, &fixture.hash);
+        register(&fixture, env!("CARGO_PKG_NAME"));
+
+        assert!(fixture.client.try_rollback().is_ok());
+    }
+
+    #[test]
+    fn set_wasm_registry_fails_if_caller_is_not_owner() {
+        let TestFixture { env, client, .. } = setup();
+
+        assert_invoke_auth_err!(
+            Address::generate(&env),
+            client.try_set_wasm_registry(&Address::generate(&env))
+        );
+    }
+
+    #[test]
+    fn set_wasm_registry_succeeds_if_caller_is_owner() {
+        let TestFixture {
+            env, owner, client, ..
+        } = setup();
+        let registry = Address::generate(&env);
+
+        assert_invoke_auth_ok!(owner, client.try_set_wasm_registry(&registry));
+
+        assert_eq!(client.wasm_registry(), Some(registry));
+        goldie::assert!(events::fmt_last_emitted_event::<WasmRegistrySetEvent>(&env));
+    }
+}
@@ -1,5 +1,8 @@
 #![no_std]
 
+// allows the derive macros to refer to this crate by name from within the crate itself
+extern crate self as axelar_soroban_std;
+
 // required by goldie
 #[cfg(any(test, feature = "testutils"))]
 extern crate std;
@@ -20,6 +23,8 @@ pub mod ttl;
 
 pub mod events;
 
+pub mod executable;
+
 pub mod interfaces;
 
 pub mod address;
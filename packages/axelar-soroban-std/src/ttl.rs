@@ -1,23 +1,123 @@
-use soroban_sdk::{Env, IntoVal, Val};
+use soroban_sdk::{contracttype, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec};
+
+use crate::interfaces::ttl_config;
 
 pub const LEDGERS_PER_DAY: u32 = (24 * 3600) / 5;
 pub const INSTANCE_TTL_THRESHOLD: u32 = 14 * LEDGERS_PER_DAY;
 pub const INSTANCE_TTL_EXTEND_TO: u32 = 60 * LEDGERS_PER_DAY;
 pub const PERSISTENT_TTL_THRESHOLD: u32 = 14 * LEDGERS_PER_DAY;
 pub const PERSISTENT_TTL_EXTEND_TO: u32 = 60 * LEDGERS_PER_DAY;
+pub const TEMPORARY_TTL_THRESHOLD: u32 = LEDGERS_PER_DAY;
+pub const TEMPORARY_TTL_EXTEND_TO: u32 = 7 * LEDGERS_PER_DAY;
+
+/// Once the TTL of an entry falls below `threshold` ledgers, it is extended to `extend_to` ledgers.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TtlPolicy {
+    pub threshold: u32,
+    pub extend_to: u32,
+}
+
+impl TtlPolicy {
+    pub const fn new(threshold: u32, extend_to: u32) -> Self {
+        Self {
+            threshold,
+            extend_to,
+        }
+    }
+}
+
+/// The TTL policies of a contract per storage type.
+///
+/// `key_overrides` replaces the persistent or temporary policy for all keys of a class,
+/// i.e. the name of the `DataKey` variant (e.g. `Balance`) or the key itself if it is a symbol.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TtlConfig {
+    pub instance: TtlPolicy,
+    pub persistent: TtlPolicy,
+    pub temporary: TtlPolicy,
+    pub key_overrides: Map<Symbol, TtlPolicy>,
+}
+
+impl TtlConfig {
+    /// The policies used by contracts that have not stored a custom config, assuming 5 second ledgers.
+    pub fn default(env: &Env) -> Self {
+        Self {
+            instance: TtlPolicy::new(INSTANCE_TTL_THRESHOLD, INSTANCE_TTL_EXTEND_TO),
+            persistent: TtlPolicy::new(PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO),
+            temporary: TtlPolicy::new(TEMPORARY_TTL_THRESHOLD, TEMPORARY_TTL_EXTEND_TO),
+            key_overrides: Map::new(env),
+        }
+    }
+
+    /// Reads the TTL config of the current contract, so it can be reused to extend several entries.
+    ///
+    /// Contracts don't store a config on deployment, so this returns [TtlConfig::default] until a config is stored
+    /// via [crate::interfaces::store_ttl_config] or the owner sets one.
+    pub fn load(env: &Env) -> Self {
+        ttl_config(env)
+    }
+
+    /// Extends the TTL of the contract instance if it falls below the threshold.
+    pub fn extend_instance_ttl(&self, env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(self.instance.threshold, self.instance.extend_to);
+    }
+
+    /// Extends the TTL of a persistent storage entry if it falls below the threshold.
+    pub fn extend_persistent_ttl<K>(&self, env: &Env, key: &K)
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let policy = self.policy_for(env, key, self.persistent);
+
+        env.storage()
+            .persistent()
+            .extend_ttl(key, policy.threshold, policy.extend_to);
+    }
+
+    /// Extends the TTL of a temporary storage entry if it falls below the threshold.
+    pub fn extend_temporary_ttl<K>(&self, env: &Env, key: &K)
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let policy = self.policy_for(env, key, self.temporary);
+
+        env.storage()
+            .temporary()
+            .extend_ttl(key, policy.threshold, policy.extend_to);
+    }
+
+    fn policy_for<K>(&self, env: &Env, key: &K, default: TtlPolicy) -> TtlPolicy
+    where
+        K: IntoVal<Env, Val>,
+    {
+        if self.key_overrides.is_empty() {
+            return default;
+        }
+
+        key_class(env, key.into_val(env))
+            .and_then(|class| self.key_overrides.get(class))
+            .unwrap_or(default)
+    }
+}
 
 /// Extends the TTL of the contract instance if it falls below the threshold.
 ///
+/// This reads the TTL config, so use [TtlConfig::load] instead to extend several entries.
+///
 /// # Arguments
 /// * `env` - The environment reference
 pub fn extend_instance_ttl(env: &Env) {
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_TTL_EXTEND_TO);
+    TtlConfig::load(env).extend_instance_ttl(env);
 }
 
 /// Extends the TTL of a persistent storage entry if it falls below the threshold.
 ///
+/// This reads the TTL config, so use [TtlConfig::load] instead to extend several entries.
+///
 /// # Arguments
 /// * `env` - The environment reference
 /// * `key` - The storage key to extend TTL for
@@ -28,7 +128,32 @@ pub fn extend_persistent_ttl<K>(env: &Env, key: &K)
 where
     K: IntoVal<Env, Val>,
 {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+    TtlConfig::load(env).extend_persistent_ttl(env, key);
+}
+
+/// Extends the TTL of a temporary storage entry if it falls below the threshold.
+///
+/// This reads the TTL config, so use [TtlConfig::load] instead to extend several entries.
+///
+/// # Arguments
+/// * `env` - The environment reference
+/// * `key` - The storage key to extend TTL for
+///
+/// # Type Parameters
+/// * `K` - The key type that implements IntoVal<Env, Val>
+pub fn extend_temporary_ttl<K>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    TtlConfig::load(env).extend_temporary_ttl(env, key);
+}
+
+/// Enum keys are encoded as a vector starting with the variant name, so that name is used as the class of the key.
+fn key_class(env: &Env, key: Val) -> Option<Symbol> {
+    Symbol::try_from_val(env, &key).ok().or_else(|| {
+        Vec::<Val>::try_from_val(env, &key)
+            .ok()
+            .and_then(|key| key.first())
+            .and_then(|class| Symbol::try_from_val(env, &class).ok())
+    })
 }